@@ -0,0 +1,396 @@
+/// Implements the quadratic extension Fq12 = Fq6[w] / (w^2 - v) of a BLS12
+/// curve, with the sparse multiplications used by the Miller loop for both
+/// M-type and D-type twists.
+macro_rules! bls12_fq12_impl {
+    () => {
+        /// An element of Fq12, represented by c0 + c1 * w.
+        #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+        pub struct Fq12 {
+            pub c0: Fq6,
+            pub c1: Fq6,
+        }
+
+        impl ::std::fmt::Display for Fq12 {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                write!(f, "Fq12({} + {} * w)", self.c0, self.c1)
+            }
+        }
+
+        impl Rand for Fq12 {
+            fn rand<R: Rng + ?Sized>(rng: &mut R) -> Self {
+                Fq12 {
+                    c0: rng.gen(),
+                    c1: rng.gen(),
+                }
+            }
+        }
+
+        impl ::rand::distributions::Distribution<Fq12> for ::rand::distributions::Standard {
+            fn sample<R: ::rand::Rng + ?Sized>(&self, rng: &mut R) -> Fq12 {
+                Fq12::rand(rng)
+            }
+        }
+
+        impl Fq12 {
+            pub fn conjugate(&mut self) {
+                self.c1.negate();
+            }
+
+            pub fn mul_by_014(&mut self, c0: &Fq2, c1: &Fq2, c4: &Fq2) {
+                let mut aa = self.c0;
+                aa.mul_by_01(c0, c1);
+                let mut bb = self.c1;
+                bb.mul_by_1(c4);
+                let mut o = *c1;
+                o.add_assign(c4);
+                self.c1.add_assign(&self.c0);
+                self.c1.mul_by_01(c0, &o);
+                self.c1.sub_assign(&aa);
+                self.c1.sub_assign(&bb);
+                self.c0 = bb;
+                self.c0.mul_by_nonresidue();
+                self.c0.add_assign(&aa);
+            }
+
+            pub fn mul_by_034(&mut self, c0: &Fq2, c3: &Fq2, c4: &Fq2) {
+                let mut a = self.c0;
+                a.c0.mul_assign(c0);
+                a.c1.mul_assign(c0);
+                a.c2.mul_assign(c0);
+                let mut b = self.c1;
+                b.mul_by_01(c3, c4);
+                let mut t0 = *c0;
+                t0.add_assign(c3);
+                self.c1.add_assign(&self.c0);
+                self.c1.mul_by_01(&t0, c4);
+                self.c1.sub_assign(&a);
+                self.c1.sub_assign(&b);
+                self.c0 = b;
+                self.c0.mul_by_nonresidue();
+                self.c0.add_assign(&a);
+            }
+
+            /// Squares an element of the cyclotomic subgroup, of order dividing
+            /// q^4 - q^2 + 1, with the method of Granger and Scott,
+            /// https://eprint.iacr.org/2009/565.
+            pub fn cyclotomic_square(&mut self) {
+                // Squares a + b * t in Fq4 = Fq2[t] / (t^2 - ξ), with t = w^3.
+                fn fq4_square(a: &Fq2, b: &Fq2) -> (Fq2, Fq2) {
+                    let mut t0 = *a;
+                    t0.square();
+                    let mut t1 = *b;
+                    t1.square();
+                    let mut c0 = t1;
+                    c0.mul_by_nonresidue();
+                    c0.add_assign(&t0);
+                    let mut c1 = *a;
+                    c1.add_assign(b);
+                    c1.square();
+                    c1.sub_assign(&t0);
+                    c1.sub_assign(&t1);
+                    (c0, c1)
+                }
+
+                // z = 3 t - 2 z
+                fn sub_triple(t: &Fq2, z: &mut Fq2) {
+                    z.negate();
+                    add_triple(t, z);
+                }
+
+                // z = 3 t + 2 z
+                fn add_triple(t: &Fq2, z: &mut Fq2) {
+                    z.add_assign(t);
+                    z.double();
+                    z.add_assign(t);
+                }
+
+                let (t0, t1) = fq4_square(&self.c0.c0, &self.c1.c1);
+                let (t2, t3) = fq4_square(&self.c1.c0, &self.c0.c2);
+                let (t4, mut t5) = fq4_square(&self.c0.c1, &self.c1.c2);
+                t5.mul_by_nonresidue();
+
+                sub_triple(&t0, &mut self.c0.c0);
+                add_triple(&t1, &mut self.c1.c1);
+                sub_triple(&t2, &mut self.c0.c1);
+                add_triple(&t3, &mut self.c1.c2);
+                add_triple(&t5, &mut self.c1.c0);
+                sub_triple(&t4, &mut self.c0.c2);
+            }
+
+            /// Squares an element of the cyclotomic subgroup in the compressed
+            /// form of Karabina, https://eprint.iacr.org/2010/542, which only
+            /// updates the coefficients c0.c1, c0.c2, c1.c0 and c1.c2. The
+            /// coefficients c0.c0 and c1.c1 are left stale until the element
+            /// goes through `decompress` or `batch_decompress`.
+            pub fn compressed_square(&mut self) {
+                let g1 = self.c0.c1;
+                let g2 = self.c0.c2;
+                let g3 = self.c1.c0;
+                let g5 = self.c1.c2;
+
+                let mut g1g1 = g1;
+                g1g1.square();
+                let mut g2g2 = g2;
+                g2g2.square();
+                let mut g3g3 = g3;
+                g3g3.square();
+                let mut g5g5 = g5;
+                g5g5.square();
+
+                // 2 g1 g5
+                let mut g1g5 = g1;
+                g1g5.add_assign(&g5);
+                g1g5.square();
+                g1g5.sub_assign(&g1g1);
+                g1g5.sub_assign(&g5g5);
+
+                // 2 g2 g3
+                let mut g2g3 = g2;
+                g2g3.add_assign(&g3);
+                g2g3.square();
+                g2g3.sub_assign(&g2g2);
+                g2g3.sub_assign(&g3g3);
+
+                // g1' = 3 (g3^2 + ξ g2^2) - 2 g1
+                let mut t = g2g2;
+                t.mul_by_nonresidue();
+                t.add_assign(&g3g3);
+                self.c0.c1.negate();
+                self.c0.c1.add_assign(&t);
+                self.c0.c1.double();
+                self.c0.c1.add_assign(&t);
+
+                // g2' = 3 (g1^2 + ξ g5^2) - 2 g2
+                let mut t = g5g5;
+                t.mul_by_nonresidue();
+                t.add_assign(&g1g1);
+                self.c0.c2.negate();
+                self.c0.c2.add_assign(&t);
+                self.c0.c2.double();
+                self.c0.c2.add_assign(&t);
+
+                // g3' = 3 ξ (2 g1 g5) + 2 g3
+                let mut t = g1g5;
+                t.mul_by_nonresidue();
+                self.c1.c0.add_assign(&t);
+                self.c1.c0.double();
+                self.c1.c0.add_assign(&t);
+
+                // g5' = 3 (2 g2 g3) + 2 g5
+                self.c1.c2.add_assign(&g2g3);
+                self.c1.c2.double();
+                self.c1.c2.add_assign(&g2g3);
+            }
+
+            /// Recovers the coefficients c0.c0 and c1.c1 of an element of the
+            /// cyclotomic subgroup from its compressed form.
+            pub fn decompress(&mut self) {
+                Fq12::batch_decompress(::std::slice::from_mut(self));
+            }
+
+            /// Recovers the coefficients c0.c0 and c1.c1 of elements of the
+            /// cyclotomic subgroup from their compressed form, sharing a single
+            /// inversion in Fq2 between all of them.
+            pub fn batch_decompress(v: &mut [Fq12]) {
+                // g4 = (ξ g5^2 + 3 g1^2 - 2 g2) / 4 g3, or 2 g1 g5 / g2 when
+                // g3 = 0. Both g2 and g3 vanish only for the identity, where
+                // g4 = 0.
+                let mut num = Vec::with_capacity(v.len());
+                let mut den = Vec::with_capacity(v.len());
+                for f in v.iter() {
+                    if !f.c1.c0.is_zero() {
+                        let mut g1g1 = f.c0.c1;
+                        g1g1.square();
+                        let mut n = f.c1.c2;
+                        n.square();
+                        n.mul_by_nonresidue();
+                        n.add_assign(&g1g1);
+                        n.add_assign(&g1g1);
+                        n.add_assign(&g1g1);
+                        n.sub_assign(&f.c0.c2);
+                        n.sub_assign(&f.c0.c2);
+                        let mut d = f.c1.c0;
+                        d.double();
+                        d.double();
+                        num.push(n);
+                        den.push(d);
+                    } else if !f.c0.c2.is_zero() {
+                        let mut n = f.c0.c1;
+                        n.mul_assign(&f.c1.c2);
+                        n.double();
+                        num.push(n);
+                        den.push(f.c0.c2);
+                    } else {
+                        num.push(Fq2::zero());
+                        den.push(Fq2::one());
+                    }
+                }
+
+                // Montgomery's trick: invert the product of the denominators,
+                // then peel off each inverse from the back.
+                let mut prod = Vec::with_capacity(den.len());
+                let mut acc = Fq2::one();
+                for d in &den {
+                    prod.push(acc);
+                    acc.mul_assign(d);
+                }
+                let mut acc = acc.inverse().unwrap();
+                for i in (0..v.len()).rev() {
+                    let mut inv = acc;
+                    inv.mul_assign(&prod[i]);
+                    acc.mul_assign(&den[i]);
+                    num[i].mul_assign(&inv);
+                }
+
+                // g0 = ξ (2 g4^2 + g3 g5 - 3 g1 g2) + 1
+                for (f, g4) in v.iter_mut().zip(num.into_iter()) {
+                    let mut g1g2 = f.c0.c1;
+                    g1g2.mul_assign(&f.c0.c2);
+                    let mut g3g5 = f.c1.c0;
+                    g3g5.mul_assign(&f.c1.c2);
+                    let mut g0 = g4;
+                    g0.square();
+                    g0.double();
+                    g0.add_assign(&g3g5);
+                    g0.sub_assign(&g1g2);
+                    g0.sub_assign(&g1g2);
+                    g0.sub_assign(&g1g2);
+                    g0.mul_by_nonresidue();
+                    g0.add_assign(&Fq2::one());
+
+                    f.c0.c0 = g0;
+                    f.c1.c1 = g4;
+                }
+            }
+
+            /// Exponentiates an element of the cyclotomic subgroup by `exp`,
+            /// given as little-endian limbs. The successive squares are taken
+            /// in compressed form, and only those used by the product are
+            /// decompressed, together.
+            pub fn cyclotomic_exp<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+                let exp = exp.as_ref();
+                let bit = |i: usize| (exp[i / 64] >> (i % 64)) & 1 == 1;
+                let num_bits = match (0..64 * exp.len()).rev().find(|&i| bit(i)) {
+                    Some(i) => i + 1,
+                    None => return Fq12::one(),
+                };
+
+                let mut res = if bit(0) { *self } else { Fq12::one() };
+                let mut squares = vec![];
+                let mut f = *self;
+                for i in 1..num_bits {
+                    f.compressed_square();
+                    if bit(i) {
+                        squares.push(f);
+                    }
+                }
+
+                Fq12::batch_decompress(&mut squares);
+                for s in &squares {
+                    res.mul_assign(s);
+                }
+                res
+            }
+        }
+
+        impl Field for Fq12 {
+            fn zero() -> Self {
+                Fq12 {
+                    c0: Fq6::zero(),
+                    c1: Fq6::zero(),
+                }
+            }
+
+            fn one() -> Self {
+                Fq12 {
+                    c0: Fq6::one(),
+                    c1: Fq6::zero(),
+                }
+            }
+
+            fn is_zero(&self) -> bool {
+                self.c0.is_zero() && self.c1.is_zero()
+            }
+
+            fn double(&mut self) {
+                self.c0.double();
+                self.c1.double();
+            }
+
+            fn negate(&mut self) {
+                self.c0.negate();
+                self.c1.negate();
+            }
+
+            fn add_assign(&mut self, other: &Self) {
+                self.c0.add_assign(&other.c0);
+                self.c1.add_assign(&other.c1);
+            }
+
+            fn sub_assign(&mut self, other: &Self) {
+                self.c0.sub_assign(&other.c0);
+                self.c1.sub_assign(&other.c1);
+            }
+
+            fn frobenius_map(&mut self, power: usize) {
+                self.c0.frobenius_map(power);
+                self.c1.frobenius_map(power);
+
+                self.c1.c0.mul_assign(&FROBENIUS_COEFF_FQ12_C1[power % 12]);
+                self.c1.c1.mul_assign(&FROBENIUS_COEFF_FQ12_C1[power % 12]);
+                self.c1.c2.mul_assign(&FROBENIUS_COEFF_FQ12_C1[power % 12]);
+            }
+
+            fn square(&mut self) {
+                let mut ab = self.c0;
+                ab.mul_assign(&self.c1);
+                let mut c0c1 = self.c0;
+                c0c1.add_assign(&self.c1);
+                let mut c0 = self.c1;
+                c0.mul_by_nonresidue();
+                c0.add_assign(&self.c0);
+                c0.mul_assign(&c0c1);
+                c0.sub_assign(&ab);
+                self.c1 = ab;
+                self.c1.add_assign(&ab);
+                ab.mul_by_nonresidue();
+                c0.sub_assign(&ab);
+                self.c0 = c0;
+            }
+
+            fn mul_assign(&mut self, other: &Self) {
+                let mut aa = self.c0;
+                aa.mul_assign(&other.c0);
+                let mut bb = self.c1;
+                bb.mul_assign(&other.c1);
+                let mut o = other.c0;
+                o.add_assign(&other.c1);
+                self.c1.add_assign(&self.c0);
+                self.c1.mul_assign(&o);
+                self.c1.sub_assign(&aa);
+                self.c1.sub_assign(&bb);
+                self.c0 = bb;
+                self.c0.mul_by_nonresidue();
+                self.c0.add_assign(&aa);
+            }
+
+            fn inverse(&self) -> Option<Self> {
+                let mut c0s = self.c0;
+                c0s.square();
+                let mut c1s = self.c1;
+                c1s.square();
+                c1s.mul_by_nonresidue();
+                c0s.sub_assign(&c1s);
+
+                c0s.inverse().map(|t| {
+                    let mut tmp = Fq12 { c0: t, c1: t };
+                    tmp.c0.mul_assign(&self.c0);
+                    tmp.c1.mul_assign(&self.c1);
+                    tmp.c1.negate();
+
+                    tmp
+                })
+            }
+        }
+    };
+}
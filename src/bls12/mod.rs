@@ -0,0 +1,439 @@
+//! Shared implementation of the BLS12 family of pairing-friendly curves.
+//!
+//! A BLS12 curve is described by its parameter x, from which the field
+//! moduli, the curve equations and the group orders follow. Instantiating a
+//! curve of the family requires:
+//!
+//! * an `fq` module deriving the base field `Fq` from its modulus, and
+//!   defining the generators `G1_GENERATOR_X/Y`, `G2_GENERATOR_X/Y_C0/C1`,
+//!   the coefficients `B_COEFF` and `B_COEFF_FQ2`, the Frobenius
+//!   coefficients of the extension tower and the coefficients
+//!   `PSI_COEFF_X/Y` of the endomorphism ψ of G2;
+//! * an `fr` module deriving the scalar field `Fr`;
+//! * an `fq2` module providing the quadratic extension `Fq2`, whose
+//!   `mul_by_nonresidue` multiplies by the nonresidue ξ used to build Fq6;
+//! * an implementation of `Bls12Parameters` for the engine type.
+//!
+//! The remaining extension fields, the groups and the engine are then
+//! implemented by the `bls12_fq6_impl!`, `bls12_fq12_impl!`,
+//! `bls12_g1_impl!`, `bls12_g2_impl!` and `bls12_engine_impl!` macros.
+//! See the `bls12_381` and `bls12_377` modules for examples.
+
+#[macro_use]
+mod ec;
+#[macro_use]
+mod fq12;
+#[macro_use]
+mod fq6;
+
+/// The type of the sextic twist E' of a BLS12 curve E that G2 is defined on.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TwistType {
+    /// Multiplicative twist, y^2 = x^3 + b * ξ.
+    M,
+    /// Divisive twist, y^2 = x^3 + b / ξ.
+    D,
+}
+
+/// The parameters of a curve of the BLS12 family.
+pub trait Bls12Parameters {
+    /// The absolute value of the parameter x.
+    const X: u64;
+    /// Whether the parameter x is negative.
+    const X_IS_NEGATIVE: bool;
+    /// The type of the twist E' that G2 is defined on.
+    const TWIST_TYPE: TwistType;
+}
+
+/// Implements `Engine` and its target group `Gt` for a BLS12 curve,
+/// computing the optimal ate pairing with the Miller loop over x and the
+/// final exponentiation of https://eprint.iacr.org/2020/875, which does not
+/// depend on the parity of x.
+macro_rules! bls12_engine_impl {
+    ($engine:ident) => {
+        gt_impl!($engine, Fq12);
+        g2_prepared_impl!();
+
+        impl ScalarEngine for $engine {
+            type Fr = Fr;
+        }
+
+        impl Engine for $engine {
+            type G1 = G1;
+            type G1Affine = G1Affine;
+            type G2 = G2;
+            type G2Affine = G2Affine;
+            type Fq = Fq;
+            type Fqe = Fq2;
+            type Fqk = Fq12;
+            type Gt = Gt;
+
+            fn miller_loop<'a, I>(i: I) -> Self::Fqk
+            where
+                I: IntoIterator<
+                    Item = &'a (
+                        &'a <Self::G1Affine as CurveAffine>::Prepared,
+                        &'a <Self::G2Affine as CurveAffine>::Prepared,
+                    ),
+                >,
+            {
+                // Shares the squarings of the loop between the pairs `i`, which the
+                // `parallel` feature splits into chunks across threads.
+                fn multi_miller_loop(i: &[(&G1Prepared, &G2Prepared)]) -> Fq12 {
+                    let mut pairs = vec![];
+                    for &(p, q) in i {
+                        if !p.is_zero() && !q.is_zero() {
+                            pairs.push((p, q.coeffs.iter()));
+                        }
+                    }
+
+                    // Twisting isomorphism from E to E'
+                    fn ell(f: &mut Fq12, coeffs: &(Fq2, Fq2, Fq2), p: &G1Affine) {
+                        let mut c0 = coeffs.0;
+                        let mut c1 = coeffs.1;
+
+                        c0.c0.mul_assign(&p.y);
+                        c0.c1.mul_assign(&p.y);
+
+                        c1.c0.mul_assign(&p.x);
+                        c1.c1.mul_assign(&p.x);
+
+                        // Sparse multiplication in Fq12, the lines of an M-type twist
+                        // touch the coefficients of 1, v and v * w, those of a D-type
+                        // twist the coefficients of 1, w and v * w.
+                        match <$engine as ::bls12::Bls12Parameters>::TWIST_TYPE {
+                            ::bls12::TwistType::M => f.mul_by_014(&coeffs.2, &c1, &c0),
+                            ::bls12::TwistType::D => f.mul_by_034(&c0, &c1, &coeffs.2),
+                        }
+                    }
+
+                    let mut f = Fq12::one();
+
+                    let mut found_one = false;
+                    for i in BitIterator::new(&[<$engine as ::bls12::Bls12Parameters>::X]) {
+                        if !found_one {
+                            found_one = i;
+                            continue;
+                        }
+
+                        f.square();
+
+                        for &mut (p, ref mut coeffs) in &mut pairs {
+                            ell(&mut f, coeffs.next().unwrap(), &p.0);
+                        }
+
+                        if i {
+                            for &mut (p, ref mut coeffs) in &mut pairs {
+                                ell(&mut f, coeffs.next().unwrap(), &p.0);
+                            }
+                        }
+                    }
+
+                    if <$engine as ::bls12::Bls12Parameters>::X_IS_NEGATIVE {
+                        f.conjugate();
+                    }
+
+                    f
+                }
+
+                let pairs: Vec<_> = i.into_iter().map(|&(p, q)| (p, q)).collect();
+
+                #[cfg(feature = "parallel")]
+                let f = ::parallel::miller_loop(&pairs, multi_miller_loop);
+                #[cfg(not(feature = "parallel"))]
+                let f = multi_miller_loop(&pairs);
+
+                f
+            }
+
+            fn final_exponentiation(r: &Fq12) -> Option<Fq12> {
+                let mut f1 = *r;
+                f1.conjugate();
+
+                match r.inverse() {
+                    Some(mut f2) => {
+                        let mut r = f1;
+                        r.mul_assign(&f2);
+                        f2 = r;
+                        r.frobenius_map(2);
+                        r.mul_assign(&f2);
+
+                        fn exp_by_x(f: &mut Fq12) {
+                            *f = f.cyclotomic_exp(&[<$engine as ::bls12::Bls12Parameters>::X]);
+                            if <$engine as ::bls12::Bls12Parameters>::X_IS_NEGATIVE {
+                                f.conjugate();
+                            }
+                        }
+
+                        // Hard part of the final exponentiation, following
+                        // https://eprint.iacr.org/2020/875 which does not need x to be even.
+                        let mut y0 = r;
+                        y0.cyclotomic_square();
+                        let mut y1 = r;
+                        exp_by_x(&mut y1);
+                        let mut y2 = r;
+                        y2.conjugate();
+                        y1.mul_assign(&y2);
+                        y2 = y1;
+                        exp_by_x(&mut y2);
+                        y1.conjugate();
+                        y1.mul_assign(&y2);
+                        y2 = y1;
+                        exp_by_x(&mut y2);
+                        y1.frobenius_map(1);
+                        y1.mul_assign(&y2);
+                        r.mul_assign(&y0);
+                        y0 = y1;
+                        exp_by_x(&mut y0);
+                        y2 = y0;
+                        exp_by_x(&mut y2);
+                        y0 = y1;
+                        y0.frobenius_map(2);
+                        y1.conjugate();
+                        y1.mul_assign(&y2);
+                        y1.mul_assign(&y0);
+                        r.mul_assign(&y1);
+
+                        Some(r)
+                    }
+                    None => None,
+                }
+            }
+        }
+
+        impl G2Prepared {
+            pub fn is_zero(&self) -> bool {
+                self.infinity
+            }
+
+            /// Checks that the first line coefficients are those of `q`, rather
+            /// than preparing `q` again. The line tangent at `q` determines `q`,
+            /// as its coefficients are 4y, -6x^2 and 6x^3 - 4y^2, but the later
+            /// lines are not checked, so this catches coefficients paired with
+            /// the wrong point but not corrupted ones.
+            pub fn is_prepared_from(&self, q: &G2Affine) -> bool {
+                if self.infinity || q.is_zero() {
+                    return self.infinity == q.is_zero();
+                }
+                self.coeffs.first() == G2Prepared::prepare(*q, true).coeffs.first()
+            }
+
+            /// The number of lines of a prepared point other than the point at
+            /// infinity, a doubling for every bit of x but the leading one and
+            /// an addition for every set bit among them.
+            fn num_lines() -> usize {
+                let mut found_one = false;
+                let mut n = 0;
+                for i in BitIterator::new([<$engine as ::bls12::Bls12Parameters>::X]) {
+                    if found_one {
+                        n += if i { 2 } else { 1 };
+                    }
+                    found_one |= i;
+                }
+                n
+            }
+
+            pub fn from_affine(q: G2Affine) -> Self {
+                G2Prepared::prepare(q, false)
+            }
+
+            /// Computes the line coefficients of `q`, stopping after the first
+            /// doubling step if `first_line` is set.
+            fn prepare(q: G2Affine, first_line: bool) -> Self {
+                if q.is_zero() {
+                    return G2Prepared {
+                        coeffs: vec![],
+                        infinity: true,
+                    };
+                }
+
+                fn doubling_step(r: &mut G2) -> (Fq2, Fq2, Fq2) {
+                    // Adaptation of Algorithm 26, https://eprint.iacr.org/2010/354.pdf
+                    let mut tmp0 = r.x;
+                    tmp0.square();
+
+                    let mut tmp1 = r.y;
+                    tmp1.square();
+
+                    let mut tmp2 = tmp1;
+                    tmp2.square();
+
+                    let mut tmp3 = tmp1;
+                    tmp3.add_assign(&r.x);
+                    tmp3.square();
+                    tmp3.sub_assign(&tmp0);
+                    tmp3.sub_assign(&tmp2);
+                    tmp3.double();
+
+                    let mut tmp4 = tmp0;
+                    tmp4.double();
+                    tmp4.add_assign(&tmp0);
+
+                    let mut tmp6 = r.x;
+                    tmp6.add_assign(&tmp4);
+
+                    let mut tmp5 = tmp4;
+                    tmp5.square();
+
+                    let mut zsquared = r.z;
+                    zsquared.square();
+
+                    r.x = tmp5;
+                    r.x.sub_assign(&tmp3);
+                    r.x.sub_assign(&tmp3);
+
+                    r.z.add_assign(&r.y);
+                    r.z.square();
+                    r.z.sub_assign(&tmp1);
+                    r.z.sub_assign(&zsquared);
+
+                    r.y = tmp3;
+                    r.y.sub_assign(&r.x);
+                    r.y.mul_assign(&tmp4);
+
+                    tmp2.double();
+                    tmp2.double();
+                    tmp2.double();
+
+                    r.y.sub_assign(&tmp2);
+
+                    tmp3 = tmp4;
+                    tmp3.mul_assign(&zsquared);
+                    tmp3.double();
+                    tmp3.negate();
+
+                    tmp6.square();
+                    tmp6.sub_assign(&tmp0);
+                    tmp6.sub_assign(&tmp5);
+
+                    tmp1.double();
+                    tmp1.double();
+
+                    tmp6.sub_assign(&tmp1);
+
+                    tmp0 = r.z;
+                    tmp0.mul_assign(&zsquared);
+                    tmp0.double();
+
+                    (tmp0, tmp3, tmp6)
+                }
+
+                fn addition_step(r: &mut G2, q: &G2Affine) -> (Fq2, Fq2, Fq2) {
+                    // Adaptation of Algorithm 27, https://eprint.iacr.org/2010/354.pdf
+                    let mut zsquared = r.z;
+                    zsquared.square();
+
+                    let mut ysquared = q.y;
+                    ysquared.square();
+
+                    let mut t0 = zsquared;
+                    t0.mul_assign(&q.x);
+
+                    let mut t1 = q.y;
+                    t1.add_assign(&r.z);
+                    t1.square();
+                    t1.sub_assign(&ysquared);
+                    t1.sub_assign(&zsquared);
+                    t1.mul_assign(&zsquared);
+
+                    let mut t2 = t0;
+                    t2.sub_assign(&r.x);
+
+                    let mut t3 = t2;
+                    t3.square();
+
+                    let mut t4 = t3;
+                    t4.double();
+                    t4.double();
+
+                    let mut t5 = t4;
+                    t5.mul_assign(&t2);
+
+                    let mut t6 = t1;
+                    t6.sub_assign(&r.y);
+                    t6.sub_assign(&r.y);
+
+                    let mut t9 = t6;
+                    t9.mul_assign(&q.x);
+
+                    let mut t7 = t4;
+                    t7.mul_assign(&r.x);
+
+                    r.x = t6;
+                    r.x.square();
+                    r.x.sub_assign(&t5);
+                    r.x.sub_assign(&t7);
+                    r.x.sub_assign(&t7);
+
+                    r.z.add_assign(&t2);
+                    r.z.square();
+                    r.z.sub_assign(&zsquared);
+                    r.z.sub_assign(&t3);
+
+                    let mut t10 = q.y;
+                    t10.add_assign(&r.z);
+
+                    let mut t8 = t7;
+                    t8.sub_assign(&r.x);
+                    t8.mul_assign(&t6);
+
+                    t0 = r.y;
+                    t0.mul_assign(&t5);
+                    t0.double();
+
+                    r.y = t8;
+                    r.y.sub_assign(&t0);
+
+                    t10.square();
+                    t10.sub_assign(&ysquared);
+
+                    let mut ztsquared = r.z;
+                    ztsquared.square();
+
+                    t10.sub_assign(&ztsquared);
+
+                    t9.double();
+                    t9.sub_assign(&t10);
+
+                    t10 = r.z;
+                    t10.double();
+
+                    t6.negate();
+
+                    t1 = t6;
+                    t1.double();
+
+                    (t10, t1, t9)
+                }
+
+                let mut coeffs = vec![];
+                let mut r: G2 = q.into();
+
+                let mut found_one = false;
+                for i in BitIterator::new([<$engine as ::bls12::Bls12Parameters>::X]) {
+                    if !found_one {
+                        found_one = i;
+                        continue;
+                    }
+
+                    coeffs.push(doubling_step(&mut r));
+                    if first_line {
+                        return G2Prepared {
+                            coeffs,
+                            infinity: false,
+                        };
+                    }
+
+                    if i {
+                        coeffs.push(addition_step(&mut r, &q));
+                    }
+                }
+
+                G2Prepared {
+                    coeffs,
+                    infinity: false,
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,148 @@
+pub mod g1 {
+    use super::super::{Bls12_377, Fq, FqRepr, Fr, FrRepr};
+    use super::g2::G2Affine;
+    use ff::{Rand, BitIterator, Field, PrimeField, PrimeFieldRepr, SqrtField};
+    use rand::Rng;
+    use std::fmt;
+    use {CurveAffine, CurveProjective, EncodedPoint, Engine, GroupDecodingError};
+
+    // G1 cofactor = (x - 1)^2 / 3  = 30631250834960419227450344600217059328
+    bls12_g1_impl!(Bls12_377, 48, [0x0, 0x170b5d4430000000]);
+
+    #[test]
+    fn g1_generator() {
+        let g1 = G1Affine::one();
+
+        assert!(g1.is_on_curve());
+        assert!(g1.is_torsion_free());
+        assert_eq!(G1::one().into_affine(), g1);
+    }
+
+    #[test]
+    fn g1_test_is_valid() {
+        // Reject point not on the curve
+        {
+            let mut p = G1Affine::one();
+            p.y.add_assign(&Fq::one());
+            assert!(!p.is_on_curve());
+
+            match G1Uncompressed::from_affine(p).into_affine() {
+                Err(GroupDecodingError::NotOnCurve) => {}
+                _ => panic!("should have rejected the point"),
+            }
+        }
+
+        // Reject point in an invalid subgroup
+        {
+            let mut x = Fq::zero();
+            let p = loop {
+                if let Some(p) = G1Affine::get_point_from_x(x, false) {
+                    break p;
+                }
+                x.add_assign(&Fq::one());
+            };
+            assert!(p.is_on_curve());
+            assert!(!p.is_torsion_free());
+
+            match G1Uncompressed::from_affine(p).into_affine() {
+                Err(GroupDecodingError::NotInSubgroup) => {}
+                _ => panic!("should have rejected the point"),
+            }
+            match G1Compressed::from_affine(p).into_affine() {
+                Err(GroupDecodingError::NotInSubgroup) => {}
+                _ => panic!("should have rejected the point"),
+            }
+
+            let p = G1Affine::from(p.scale_by_cofactor());
+            assert!(p.is_torsion_free());
+        }
+    }
+
+    #[test]
+    fn g1_curve_tests() {
+        ::tests::curve::curve_tests::<G1>();
+    }
+}
+
+pub mod g2 {
+    use super::super::{Bls12_377, Fq, Fq2, FqRepr, Fr, FrRepr};
+    use super::g1::G1Affine;
+    use ff::{Rand, BitIterator, Field, PrimeField, PrimeFieldRepr, SqrtField};
+    use rand::Rng;
+    use std::fmt;
+    use {CurveAffine, CurveProjective, EncodedPoint, Engine, GroupDecodingError};
+
+    // G2 cofactor = (x^8 - 4 x^7 + 5 x^6 - 4 x^4 + 6 x^3 - 4 x^2 - 4 x + 13) / 9
+    // 0x26ba558ae9562addd88d99a6f6a829fbb36b00e1dcc40c8c505634fae2e189d693e8c36676bd09a0f3622fba094800452217cc900000000000000000000001
+    bls12_g2_impl!(
+        Bls12_377,
+        48,
+        [
+            0x0000000000000001,
+            0x452217cc90000000,
+            0xa0f3622fba094800,
+            0xd693e8c36676bd09,
+            0x8c505634fae2e189,
+            0xfbb36b00e1dcc40c,
+            0xddd88d99a6f6a829,
+            0x26ba558ae9562a,
+        ]
+    );
+
+    #[test]
+    fn g2_generator() {
+        let g2 = G2Affine::one();
+
+        assert!(g2.is_on_curve());
+        assert!(g2.is_torsion_free());
+        assert_eq!(G2::one().into_affine(), g2);
+    }
+
+    #[test]
+    fn g2_test_is_valid() {
+        // Reject point not on the curve
+        {
+            let mut p = G2Affine::one();
+            p.y.add_assign(&Fq2::one());
+            assert!(!p.is_on_curve());
+
+            match G2Uncompressed::from_affine(p).into_affine() {
+                Err(GroupDecodingError::NotOnCurve) => {}
+                _ => panic!("should have rejected the point"),
+            }
+        }
+
+        // Reject point in an invalid subgroup
+        {
+            let mut x = Fq2::zero();
+            let p = loop {
+                if let Some(p) = G2Affine::get_point_from_x(x, false) {
+                    break p;
+                }
+                x.add_assign(&Fq2::one());
+            };
+            assert!(p.is_on_curve());
+            assert!(!p.is_torsion_free());
+
+            match G2Uncompressed::from_affine(p).into_affine() {
+                Err(GroupDecodingError::NotInSubgroup) => {}
+                _ => panic!("should have rejected the point"),
+            }
+            match G2Compressed::from_affine(p).into_affine() {
+                Err(GroupDecodingError::NotInSubgroup) => {}
+                _ => panic!("should have rejected the point"),
+            }
+
+            let p = G2Affine::from(p.scale_by_cofactor());
+            assert!(p.is_torsion_free());
+        }
+    }
+
+    #[test]
+    fn g2_curve_tests() {
+        ::tests::curve::curve_tests::<G2>();
+    }
+}
+
+pub use self::g1::*;
+pub use self::g2::*;
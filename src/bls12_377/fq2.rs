@@ -0,0 +1,351 @@
+use super::fq::{FROBENIUS_COEFF_FQ2_C1, Fq};
+use ff::{Field, Rand, SqrtField};
+use rand::{Rng};
+
+use std::cmp::Ordering;
+
+/// An element of Fq2, represented by c0 + c1 * u, where u^2 = -5.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Fq2 {
+    pub c0: Fq,
+    pub c1: Fq,
+}
+
+impl ::std::fmt::Display for Fq2 {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "Fq2({} + {} * u)", self.c0, self.c1)
+    }
+}
+
+/// `Fq2` elements are ordered lexicographically.
+impl Ord for Fq2 {
+    #[inline(always)]
+    fn cmp(&self, other: &Fq2) -> Ordering {
+        match self.c1.cmp(&other.c1) {
+            Ordering::Greater => Ordering::Greater,
+            Ordering::Less => Ordering::Less,
+            Ordering::Equal => self.c0.cmp(&other.c0),
+        }
+    }
+}
+
+impl PartialOrd for Fq2 {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Fq2) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Multiply an element of Fq by the quadratic nonresidue -5.
+#[inline(always)]
+fn mul_fq_by_nonresidue(fe: &mut Fq) {
+    let t0 = *fe;
+    fe.double();
+    fe.double();
+    fe.add_assign(&t0);
+    fe.negate();
+}
+
+impl Fq2 {
+    /// Multiply this element by the cubic and quadratic nonresidue u.
+    pub fn mul_by_nonresidue(&mut self) {
+        let t0 = self.c0;
+        self.c0 = self.c1;
+        mul_fq_by_nonresidue(&mut self.c0);
+        self.c1 = t0;
+    }
+
+    /// Norm of Fq2 as extension field in u over Fq
+    pub fn norm(&self) -> Fq {
+        let mut t0 = self.c0;
+        let mut t1 = self.c1;
+        t0.square();
+        t1.square();
+        mul_fq_by_nonresidue(&mut t1);
+        t0.sub_assign(&t1);
+
+        t0
+    }
+}
+
+impl Rand for Fq2 {
+    fn rand<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Fq2 {
+            c0: rng.gen(),
+            c1: rng.gen(),
+        }
+    }
+}
+
+impl ::rand::distributions::Distribution<Fq2> for ::rand::distributions::Standard {
+    fn sample<R: ::rand::Rng + ?Sized>(&self, rng: &mut R) -> Fq2 {
+        Fq2::rand(rng)
+    }
+}
+
+impl Field for Fq2 {
+    fn zero() -> Self {
+        Fq2 {
+            c0: Fq::zero(),
+            c1: Fq::zero(),
+        }
+    }
+
+    fn one() -> Self {
+        Fq2 {
+            c0: Fq::one(),
+            c1: Fq::zero(),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero()
+    }
+
+    fn square(&mut self) {
+        // (c0 + c1 * u)^2 = (c0^2 - 5 * c1^2) + 2 * c0 * c1 * u
+        let mut ab = self.c0;
+        ab.mul_assign(&self.c1);
+        let mut c0c1 = self.c0;
+        c0c1.add_assign(&self.c1);
+        let mut c0 = self.c1;
+        mul_fq_by_nonresidue(&mut c0);
+        c0.add_assign(&self.c0);
+        c0.mul_assign(&c0c1);
+        c0.sub_assign(&ab);
+        self.c1 = ab;
+        self.c1.add_assign(&ab);
+        mul_fq_by_nonresidue(&mut ab);
+        c0.sub_assign(&ab);
+        self.c0 = c0;
+    }
+
+    fn double(&mut self) {
+        self.c0.double();
+        self.c1.double();
+    }
+
+    fn negate(&mut self) {
+        self.c0.negate();
+        self.c1.negate();
+    }
+
+    fn add_assign(&mut self, other: &Self) {
+        self.c0.add_assign(&other.c0);
+        self.c1.add_assign(&other.c1);
+    }
+
+    fn sub_assign(&mut self, other: &Self) {
+        self.c0.sub_assign(&other.c0);
+        self.c1.sub_assign(&other.c1);
+    }
+
+    fn mul_assign(&mut self, other: &Self) {
+        let mut aa = self.c0;
+        aa.mul_assign(&other.c0);
+        let mut bb = self.c1;
+        bb.mul_assign(&other.c1);
+        let mut o = other.c0;
+        o.add_assign(&other.c1);
+        self.c1.add_assign(&self.c0);
+        self.c1.mul_assign(&o);
+        self.c1.sub_assign(&aa);
+        self.c1.sub_assign(&bb);
+        mul_fq_by_nonresidue(&mut bb);
+        self.c0 = aa;
+        self.c0.add_assign(&bb);
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        self.norm().inverse().map(|t| {
+            let mut tmp = Fq2 {
+                c0: self.c0,
+                c1: self.c1,
+            };
+            tmp.c0.mul_assign(&t);
+            tmp.c1.mul_assign(&t);
+            tmp.c1.negate();
+
+            tmp
+        })
+    }
+
+    fn frobenius_map(&mut self, power: usize) {
+        self.c1.mul_assign(&FROBENIUS_COEFF_FQ2_C1[power % 2]);
+    }
+}
+
+impl SqrtField for Fq2 {
+    fn legendre(&self) -> ::ff::LegendreSymbol {
+        self.norm().legendre()
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        // Complex method, Algorithm 8 of https://eprint.iacr.org/2012/685.pdf
+        // adapted to u^2 = -5 since q = 1 mod 4.
+
+        if self.is_zero() {
+            return Some(Self::zero());
+        }
+
+        if self.c1.is_zero() {
+            // The square root is either in Fq or is a multiple of u.
+            return match self.c0.sqrt() {
+                Some(c0) => Some(Fq2 { c0, c1: Fq::zero() }),
+                None => {
+                    let mut c1 = self.c0;
+                    let mut nqr = Fq::one();
+                    mul_fq_by_nonresidue(&mut nqr);
+                    c1.mul_assign(&nqr.inverse().unwrap());
+                    c1.sqrt().map(|c1| Fq2 { c0: Fq::zero(), c1 })
+                }
+            };
+        }
+
+        self.norm().sqrt().and_then(|alpha| {
+            let mut two_inv = Fq::one();
+            two_inv.double();
+            let two_inv = two_inv.inverse().unwrap();
+
+            let mut delta = self.c0;
+            delta.add_assign(&alpha);
+            delta.mul_assign(&two_inv);
+            if delta.legendre() == ::ff::LegendreSymbol::QuadraticNonResidue {
+                delta = self.c0;
+                delta.sub_assign(&alpha);
+                delta.mul_assign(&two_inv);
+            }
+
+            delta.sqrt().map(|c0| {
+                let mut c1 = c0;
+                c1.double();
+                c1 = c1.inverse().unwrap();
+                c1.mul_assign(&self.c1);
+
+                Fq2 { c0, c1 }
+            })
+        })
+    }
+}
+
+#[test]
+fn test_fq2_ordering() {
+    let mut a = Fq2 {
+        c0: Fq::zero(),
+        c1: Fq::zero(),
+    };
+
+    let mut b = a.clone();
+
+    assert!(a.cmp(&b) == Ordering::Equal);
+    b.c0.add_assign(&Fq::one());
+    assert!(a.cmp(&b) == Ordering::Less);
+    a.c0.add_assign(&Fq::one());
+    assert!(a.cmp(&b) == Ordering::Equal);
+    b.c1.add_assign(&Fq::one());
+    assert!(a.cmp(&b) == Ordering::Less);
+    a.c0.add_assign(&Fq::one());
+    assert!(a.cmp(&b) == Ordering::Less);
+    a.c1.add_assign(&Fq::one());
+    assert!(a.cmp(&b) == Ordering::Greater);
+    b.c0.add_assign(&Fq::one());
+    assert!(a.cmp(&b) == Ordering::Equal);
+}
+
+#[test]
+fn test_fq2_basics() {
+    assert_eq!(
+        Fq2 {
+            c0: Fq::zero(),
+            c1: Fq::zero(),
+        },
+        Fq2::zero()
+    );
+    assert_eq!(
+        Fq2 {
+            c0: Fq::one(),
+            c1: Fq::zero(),
+        },
+        Fq2::one()
+    );
+    assert!(Fq2::zero().is_zero());
+    assert!(!Fq2::one().is_zero());
+    assert!(
+        !Fq2 {
+            c0: Fq::zero(),
+            c1: Fq::one(),
+        }.is_zero()
+    );
+}
+
+#[test]
+fn test_fq2_squaring() {
+    use super::fq::FqRepr;
+    use ff::PrimeField;
+
+    let mut a = Fq2 {
+        c0: Fq::one(),
+        c1: Fq::one(),
+    }; // u + 1
+    a.square();
+
+    let mut c0 = Fq::from_repr(FqRepr::from(4)).unwrap();
+    c0.negate();
+    assert_eq!(
+        a,
+        Fq2 {
+            c0: c0,
+            c1: Fq::from_repr(FqRepr::from(2)).unwrap(),
+        }
+    ); // 2u - 4
+}
+
+#[test]
+fn test_fq2_legendre() {
+    use ff::LegendreSymbol::*;
+
+    assert_eq!(Zero, Fq2::zero().legendre());
+    // u^2 = -5
+    let mut m5 = Fq2::one();
+    m5.mul_by_nonresidue();
+    m5.mul_by_nonresidue();
+    assert_eq!(QuadraticResidue, m5.legendre());
+    let mut u = Fq2::one();
+    u.mul_by_nonresidue();
+    assert_eq!(QuadraticNonResidue, u.legendre());
+}
+
+#[cfg(test)]
+use rand::SeedableRng;
+
+#[cfg(test)]
+use rand_xorshift::XorShiftRng;
+
+#[test]
+fn test_fq2_mul_nonresidue() {
+    let mut rng = XorShiftRng::seed_from_u64(0x5dbe62598d313d76);
+
+    let nqr = Fq2 {
+        c0: Fq::zero(),
+        c1: Fq::one(),
+    };
+
+    for _ in 0..1000 {
+        let mut a = Fq2::rand(&mut rng);
+        let mut b = a;
+        a.mul_by_nonresidue();
+        b.mul_assign(&nqr);
+
+        assert_eq!(a, b);
+    }
+}
+
+#[test]
+fn fq2_field_tests() {
+    use ff::PrimeField;
+
+    ::tests::field::random_field_tests::<Fq2>();
+    ::tests::field::random_sqrt_tests::<Fq2>();
+    ::tests::field::random_frobenius_tests::<Fq2, _>(super::fq::Fq::char(), 13);
+}
@@ -0,0 +1,31 @@
+use ff::{Field, PrimeField, PrimeFieldDecodingError, PrimeFieldRepr, Rand};
+
+#[derive(PrimeField)]
+#[PrimeFieldModulus = "8444461749428370424248824938781546531375899335154063827935233455917409239041"]
+#[PrimeFieldGenerator = "22"]
+pub struct Fr(FrRepr);
+
+#[test]
+fn test_fr_num_bits() {
+    assert_eq!(Fr::NUM_BITS, 253);
+    assert_eq!(Fr::CAPACITY, 252);
+}
+
+#[test]
+fn test_roots_of_unity() {
+    assert_eq!(Fr::S, 47);
+    assert_eq!(Fr::root_of_unity().pow([1 << Fr::S]), Fr::one());
+}
+
+#[test]
+fn fr_field_tests() {
+    ::tests::field::random_field_tests::<Fr>();
+    ::tests::field::random_sqrt_tests::<Fr>();
+    ::tests::field::random_frobenius_tests::<Fr, _>(Fr::char(), 13);
+    ::tests::field::from_str_tests::<Fr>();
+}
+
+#[test]
+fn fr_repr_tests() {
+    ::tests::repr::random_repr_tests::<FrRepr>();
+}
@@ -0,0 +1,43 @@
+mod ec;
+mod fq;
+mod fq12;
+mod fq2;
+mod fq6;
+mod fr;
+
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(test)]
+mod tests;
+
+pub use self::ec::{
+    G1, G1Affine, G1Compressed, G1Prepared, G1Uncompressed, G2, G2Affine, G2Compressed, G2Prepared,
+    G2Uncompressed,
+};
+pub use self::fq::{Fq, FqRepr};
+pub use self::fq12::Fq12;
+pub use self::fq2::Fq2;
+pub use self::fq6::Fq6;
+pub use self::fr::{Fr, FrRepr};
+
+use super::{CurveAffine, Engine};
+use bls12::{Bls12Parameters, TwistType};
+
+use ff::{BitIterator, Field, ScalarEngine};
+
+#[derive(Clone, Debug)]
+pub struct Bls12_377;
+
+impl Bls12Parameters for Bls12_377 {
+    // The BLS parameter x for BLS12-377 is 0x8508c00000000001
+    const X: u64 = 0x8508c00000000001;
+    const X_IS_NEGATIVE: bool = false;
+    const TWIST_TYPE: TwistType = TwistType::D;
+}
+
+bls12_engine_impl!(Bls12_377);
+
+#[test]
+fn bls12_377_engine_tests() {
+    ::tests::engine::engine_tests::<Bls12_377>();
+}
@@ -0,0 +1,133 @@
+use super::*;
+use *;
+
+#[test]
+fn test_pairing_result_against_zexe() {
+    // The pairing of the G1 and G2 generators, as computed by the Zexe
+    // implementation of BLS12-377.
+    assert_eq!(Bls12_377::pairing(G1::one(), G2::one()).into_fqk(), Fq12 {
+        c0: Fq6 {
+            c0: Fq2 {
+                c0: Fq::from_str("110083230830723987109655696820214910916273706674572586634856969551308702305356658303775506237961573154771378344140").unwrap(),
+                c1: Fq::from_str("244788780104742846436871668319121047075257290166108409917241404606936524542608766332803537171539190182256533917063").unwrap()
+            },
+            c1: Fq2 {
+                c0: Fq::from_str("70591179866591025581008162845739604858876788014997542519276300506514073583341324769099527146730334236662895865046").unwrap(),
+                c1: Fq::from_str("33215506749015620019257676299396072211755543898124622075825860836511008942214474501211834441808696780044441245539").unwrap()
+            },
+            c2: Fq2 {
+                c0: Fq::from_str("141996005453347263090173180206723577395804269667710911417921159823287638494815269986942278355476391040915850450151").unwrap(),
+                c1: Fq::from_str("49108445638151337603000681250658658472023497933997577640647495353498252268968290335483603123267725037131403671515").unwrap()
+            }
+        },
+        c1: Fq6 {
+            c0: Fq2 {
+                c0: Fq::from_str("90176803298438364423747154903552831429250422756541192446894181932259050413142675599091235793803716757083372706375").unwrap(),
+                c1: Fq::from_str("19151991642090052547303373703409991425197256637457462081216740605518326884012063439339008275308332070736636254862").unwrap()
+            },
+            c1: Fq2 {
+                c0: Fq::from_str("107814639769391127316528217157090438027031338224027008312403448100972183752115829716940620193448040349262503431554").unwrap(),
+                c1: Fq::from_str("38714735791791130983722173336432250534993930888432824523201238967962785087648814681113534394878856902006900821087").unwrap()
+            },
+            c2: Fq2 {
+                c0: Fq::from_str("61665802096505683799087817396448925253103935552660966716062941445711365774494544506267962952144550065239368469812").unwrap(),
+                c1: Fq::from_str("5382601735945323996025725556060270047499954807549021966963123697509349722278727211567122542201726580665357585411").unwrap()
+            }
+        }
+    });
+}
+
+fn test_roundtrip<G: CurveProjective, E: EncodedPoint<Affine = G::Affine>>() {
+    let mut e = G::zero();
+
+    for _ in 0..100 {
+        let e_affine = e.into_affine();
+        let encoded = E::from_affine(e_affine);
+        assert_eq!(encoded.as_ref().len(), E::size());
+        assert_eq!(encoded.into_affine().unwrap(), e_affine);
+
+        e.add_assign(&G::one());
+    }
+}
+
+#[test]
+fn test_g1_uncompressed_roundtrip() {
+    test_roundtrip::<G1, G1Uncompressed>();
+}
+
+#[test]
+fn test_g1_compressed_roundtrip() {
+    test_roundtrip::<G1, G1Compressed>();
+}
+
+#[test]
+fn test_g2_uncompressed_roundtrip() {
+    test_roundtrip::<G2, G2Uncompressed>();
+}
+
+#[test]
+fn test_g2_compressed_roundtrip() {
+    test_roundtrip::<G2, G2Compressed>();
+}
+
+#[test]
+fn test_g1_compressed_invalid_vectors() {
+    {
+        let z = G1Compressed::from_affine(G1Affine::zero());
+
+        {
+            let mut z = z;
+            z.as_mut()[0] &= 0b0111_1111;
+            if let Err(GroupDecodingError::UnexpectedCompressionMode) = z.into_affine() {
+                // :)
+            } else {
+                panic!("should have rejected the point because we expected a compressed point");
+            }
+        }
+
+        {
+            let mut z = z;
+            z.as_mut()[0] |= 0b0010_0000;
+            if let Err(GroupDecodingError::UnexpectedInformation) = z.into_affine() {
+                // :)
+            } else {
+                panic!("should have rejected the point because the parity bit should not be set if the point is at infinity");
+            }
+        }
+
+        for i in 0..G1Compressed::size() {
+            let mut z = z;
+            z.as_mut()[i] |= 0b0000_0001;
+            if let Err(GroupDecodingError::UnexpectedInformation) = z.into_affine() {
+                // :)
+            } else {
+                panic!("should have rejected the point because the coordinates should be zeroes at the point at infinity");
+            }
+        }
+    }
+
+    let o = G1Compressed::from_affine(G1Affine::one());
+
+    {
+        let mut o = o;
+        o.as_mut()[0] &= 0b0111_1111;
+        if let Err(GroupDecodingError::UnexpectedCompressionMode) = o.into_affine() {
+            // :)
+        } else {
+            panic!("should have rejected the point because we expected a compressed point");
+        }
+    }
+
+    {
+        let mut o = o;
+        for b in &mut o.as_mut()[..] {
+            *b = 0xff;
+        }
+        o.as_mut()[0] = 0b1001_1111;
+        if let Err(GroupDecodingError::CoordinateDecodingError(coordinate, _)) = o.into_affine() {
+            assert_eq!(coordinate, "x coordinate");
+        } else {
+            panic!("should have rejected the point because the x coordinate isn't in the field");
+        }
+    }
+}
@@ -1,943 +1,53 @@
-macro_rules! curve_impl {
-    (
-        $name:expr,
-        $projective:ident,
-        $affine:ident,
-        $prepared:ident,
-        $basefield:ident,
-        $scalarfield:ident,
-        $uncompressed:ident,
-        $compressed:ident,
-        $pairing:ident
-    ) => {
-        #[derive(Copy, Clone, PartialEq, Eq, Debug)]
-        pub struct $affine {
-            pub(crate) x: $basefield,
-            pub(crate) y: $basefield,
-            pub(crate) infinity: bool
-        }
-
-        impl ::std::fmt::Display for $affine
-        {
-            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                if self.infinity {
-                    write!(f, "{}(Infinity)", $name)
-                } else {
-                    write!(f, "{}(x={}, y={})", $name, self.x, self.y)
-                }
-            }
-        }
-
-        #[derive(Copy, Clone, Debug, Eq)]
-        pub struct $projective {
-           pub(crate) x: $basefield,
-           pub(crate) y: $basefield,
-           pub(crate) z: $basefield
-        }
-
-        impl ::std::fmt::Display for $projective
-        {
-            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                write!(f, "{}", self.into_affine())
-            }
-        }
-
-        impl PartialEq for $projective {
-            fn eq(&self, other: &$projective) -> bool {
-                if self.is_zero() {
-                    return other.is_zero();
-                }
-
-                if other.is_zero() {
-                    return false;
-                }
-
-                // The points (X, Y, Z) and (X', Y', Z')
-                // are equal when (X * Z^2) = (X' * Z'^2)
-                // and (Y * Z^3) = (Y' * Z'^3).
-
-                let mut z1 = self.z;
-                z1.square();
-                let mut z2 = other.z;
-                z2.square();
-
-                let mut tmp1 = self.x;
-                tmp1.mul_assign(&z2);
-
-                let mut tmp2 = other.x;
-                tmp2.mul_assign(&z1);
-
-                if tmp1 != tmp2 {
-                    return false;
-                }
-
-                z1.mul_assign(&self.z);
-                z2.mul_assign(&other.z);
-                z2.mul_assign(&self.y);
-                z1.mul_assign(&other.y);
-
-                if z1 != z2 {
-                    return false;
-                }
-
-                true
-            }
-        }
-
-        impl $affine {
-            fn mul_bits<S: AsRef<[u64]>>(&self, bits: BitIterator<S>) -> $projective {
-                let mut res = $projective::zero();
-                for i in bits {
-                    res.double();
-                    if i { res.add_assign_mixed(self) }
-                }
-                res
-            }
-
-            /// Attempts to construct an affine point given an x-coordinate. The
-            /// point is not guaranteed to be in the prime order subgroup.
-            ///
-            /// If and only if `greatest` is set will the lexicographically
-            /// largest y-coordinate be selected.
-            fn get_point_from_x(x: $basefield, greatest: bool) -> Option<$affine> {
-                // Compute x^3 + b
-                let mut x3b = x;
-                x3b.square();
-                x3b.mul_assign(&x);
-                x3b.add_assign(&$affine::get_coeff_b());
-
-                x3b.sqrt().map(|y| {
-                    let mut negy = y;
-                    negy.negate();
-
-                    $affine {
-                        x: x,
-                        y: if (y < negy) ^ greatest {
-                            y
-                        } else {
-                            negy
-                        },
-                        infinity: false
-                    }
-                })
-            }
-
-            fn is_on_curve(&self) -> bool {
-                if self.is_zero() {
-                    true
-                } else {
-                    // Check that the point is on the curve
-                    let mut y2 = self.y;
-                    y2.square();
-
-                    let mut x3b = self.x;
-                    x3b.square();
-                    x3b.mul_assign(&self.x);
-                    x3b.add_assign(&Self::get_coeff_b());
-
-                    y2 == x3b
-                }
-            }
-
-            fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
-                self.mul($scalarfield::char()).is_zero()
-            }
-        }
-
-        impl CurveAffine for $affine {
-            type Engine = Bls12;
-            type Scalar = $scalarfield;
-            type Base = $basefield;
-            type Prepared = $prepared;
-            type Projective = $projective;
-            type Uncompressed = $uncompressed;
-            type Compressed = $compressed;
-            type Pair = $pairing;
-            type PairingResult = Fq12;
-
-            fn zero() -> Self {
-                $affine {
-                    x: $basefield::zero(),
-                    y: $basefield::one(),
-                    infinity: true
-                }
-            }
-
-            fn one() -> Self {
-                Self::get_generator()
-            }
-
-            fn is_zero(&self) -> bool {
-                self.infinity
-            }
-
-            fn mul<S: Into<<Self::Scalar as PrimeField>::Repr>>(&self, by: S) -> $projective {
-                let bits = BitIterator::new(by.into());
-                self.mul_bits(bits)
-            }
-
-            fn negate(&mut self) {
-                if !self.is_zero() {
-                    self.y.negate();
-                }
-            }
-
-            fn prepare(&self) -> Self::Prepared {
-                $prepared::from_affine(*self)
-            }
-
-            fn pairing_with(&self, other: &Self::Pair) -> Self::PairingResult {
-                self.perform_pairing(other)
-            }
-
-            fn into_projective(&self) -> $projective {
-                (*self).into()
-            }
-
-        }
-
-        impl Rand for $projective {
-            fn rand<R: Rng + ?Sized>(rng: &mut R) -> Self {
-                loop {
-                    let x = rng.gen();
-                    let greatest = rng.gen();
-
-                    if let Some(p) = $affine::get_point_from_x(x, greatest) {
-                        let p = p.scale_by_cofactor();
-
-                        if !p.is_zero() {
-                            return p;
-                        }
-                    }
-                }
-            }
-        }
-
-        impl ::rand::distributions::Distribution<$projective> for ::rand::distributions::Standard {
-            fn sample<R: ::rand::Rng + ?Sized>(&self, rng: &mut R) -> $projective {
-                $projective::rand(rng)
-            }
-        }
-
-        impl CurveProjective for $projective {
-            type Engine = Bls12;
-            type Scalar = $scalarfield;
-            type Base = $basefield;
-            type Affine = $affine;
-
-            // The point at infinity is always represented by
-            // Z = 0.
-            fn zero() -> Self {
-                $projective {
-                    x: $basefield::zero(),
-                    y: $basefield::one(),
-                    z: $basefield::zero()
-                }
-            }
-
-            fn one() -> Self {
-                $affine::one().into()
-            }
-
-            // The point at infinity is always represented by
-            // Z = 0.
-            fn is_zero(&self) -> bool {
-                self.z.is_zero()
-            }
-
-            fn is_normalized(&self) -> bool {
-                self.is_zero() || self.z == $basefield::one()
-            }
-
-            fn batch_normalization(v: &mut [Self])
-            {
-                // Montgomery’s Trick and Fast Implementation of Masked AES
-                // Genelle, Prouff and Quisquater
-                // Section 3.2
-
-                // First pass: compute [a, ab, abc, ...]
-                let mut prod = Vec::with_capacity(v.len());
-                let mut tmp = $basefield::one();
-                for g in v.iter_mut()
-                          // Ignore normalized elements
-                          .filter(|g| !g.is_normalized())
-                {
-                    tmp.mul_assign(&g.z);
-                    prod.push(tmp);
-                }
-
-                // Invert `tmp`.
-                tmp = tmp.inverse().unwrap(); // Guaranteed to be nonzero.
-
-                // Second pass: iterate backwards to compute inverses
-                for (g, s) in v.iter_mut()
-                               // Backwards
-                               .rev()
-                               // Ignore normalized elements
-                               .filter(|g| !g.is_normalized())
-                               // Backwards, skip last element, fill in one for last term.
-                               .zip(prod.into_iter().rev().skip(1).chain(Some($basefield::one())))
-                {
-                    // tmp := tmp * g.z; g.z := tmp * s = 1/z
-                    let mut newtmp = tmp;
-                    newtmp.mul_assign(&g.z);
-                    g.z = tmp;
-                    g.z.mul_assign(&s);
-                    tmp = newtmp;
-                }
-
-                // Perform affine transformations
-                for g in v.iter_mut()
-                          .filter(|g| !g.is_normalized())
-                {
-                    let mut z = g.z; // 1/z
-                    z.square(); // 1/z^2
-                    g.x.mul_assign(&z); // x/z^2
-                    z.mul_assign(&g.z); // 1/z^3
-                    g.y.mul_assign(&z); // y/z^3
-                    g.z = $basefield::one(); // z = 1
-                }
-            }
-
-            fn double(&mut self) {
-                if self.is_zero() {
-                    return;
-                }
-
-                // Other than the point at infinity, no points on E or E'
-                // can double to equal the point at infinity, as y=0 is
-                // never true for points on the curve. (-4 and -4u-4
-                // are not cubic residue in their respective fields.)
-
-                // http://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian-0.html#doubling-dbl-2009-l
-
-                // A = X1^2
-                let mut a = self.x;
-                a.square();
-
-                // B = Y1^2
-                let mut b = self.y;
-                b.square();
-
-                // C = B^2
-                let mut c = b;
-                c.square();
-
-                // D = 2*((X1+B)2-A-C)
-                let mut d = self.x;
-                d.add_assign(&b);
-                d.square();
-                d.sub_assign(&a);
-                d.sub_assign(&c);
-                d.double();
-
-                // E = 3*A
-                let mut e = a;
-                e.double();
-                e.add_assign(&a);
-
-                // F = E^2
-                let mut f = e;
-                f.square();
-
-                // Z3 = 2*Y1*Z1
-                self.z.mul_assign(&self.y);
-                self.z.double();
-
-                // X3 = F-2*D
-                self.x = f;
-                self.x.sub_assign(&d);
-                self.x.sub_assign(&d);
-
-                // Y3 = E*(D-X3)-8*C
-                self.y = d;
-                self.y.sub_assign(&self.x);
-                self.y.mul_assign(&e);
-                c.double();
-                c.double();
-                c.double();
-                self.y.sub_assign(&c);
-            }
-
-            fn add_assign(&mut self, other: &Self) {
-                if self.is_zero() {
-                    *self = *other;
-                    return;
-                }
-
-                if other.is_zero() {
-                    return;
-                }
-
-                // http://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian-0.html#addition-add-2007-bl
-
-                // Z1Z1 = Z1^2
-                let mut z1z1 = self.z;
-                z1z1.square();
-
-                // Z2Z2 = Z2^2
-                let mut z2z2 = other.z;
-                z2z2.square();
-
-                // U1 = X1*Z2Z2
-                let mut u1 = self.x;
-                u1.mul_assign(&z2z2);
-
-                // U2 = X2*Z1Z1
-                let mut u2 = other.x;
-                u2.mul_assign(&z1z1);
-
-                // S1 = Y1*Z2*Z2Z2
-                let mut s1 = self.y;
-                s1.mul_assign(&other.z);
-                s1.mul_assign(&z2z2);
-
-                // S2 = Y2*Z1*Z1Z1
-                let mut s2 = other.y;
-                s2.mul_assign(&self.z);
-                s2.mul_assign(&z1z1);
-
-                if u1 == u2 && s1 == s2 {
-                    // The two points are equal, so we double.
-                    self.double();
-                } else {
-                    // If we're adding -a and a together, self.z becomes zero as H becomes zero.
-
-                    // H = U2-U1
-                    let mut h = u2;
-                    h.sub_assign(&u1);
-
-                    // I = (2*H)^2
-                    let mut i = h;
-                    i.double();
-                    i.square();
-
-                    // J = H*I
-                    let mut j = h;
-                    j.mul_assign(&i);
-
-                    // r = 2*(S2-S1)
-                    let mut r = s2;
-                    r.sub_assign(&s1);
-                    r.double();
-
-                    // V = U1*I
-                    let mut v = u1;
-                    v.mul_assign(&i);
-
-                    // X3 = r^2 - J - 2*V
-                    self.x = r;
-                    self.x.square();
-                    self.x.sub_assign(&j);
-                    self.x.sub_assign(&v);
-                    self.x.sub_assign(&v);
-
-                    // Y3 = r*(V - X3) - 2*S1*J
-                    self.y = v;
-                    self.y.sub_assign(&self.x);
-                    self.y.mul_assign(&r);
-                    s1.mul_assign(&j); // S1 = S1 * J * 2
-                    s1.double();
-                    self.y.sub_assign(&s1);
-
-                    // Z3 = ((Z1+Z2)^2 - Z1Z1 - Z2Z2)*H
-                    self.z.add_assign(&other.z);
-                    self.z.square();
-                    self.z.sub_assign(&z1z1);
-                    self.z.sub_assign(&z2z2);
-                    self.z.mul_assign(&h);
-                }
-            }
-
-            fn add_assign_mixed(&mut self, other: &Self::Affine) {
-                if other.is_zero() {
-                    return;
-                }
-
-                if self.is_zero() {
-                    self.x = other.x;
-                    self.y = other.y;
-                    self.z = $basefield::one();
-                    return;
-                }
-
-                // http://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian-0.html#addition-madd-2007-bl
-
-                // Z1Z1 = Z1^2
-                let mut z1z1 = self.z;
-                z1z1.square();
-
-                // U2 = X2*Z1Z1
-                let mut u2 = other.x;
-                u2.mul_assign(&z1z1);
-
-                // S2 = Y2*Z1*Z1Z1
-                let mut s2 = other.y;
-                s2.mul_assign(&self.z);
-                s2.mul_assign(&z1z1);
-
-                if self.x == u2 && self.y == s2 {
-                    // The two points are equal, so we double.
-                    self.double();
-                } else {
-                    // If we're adding -a and a together, self.z becomes zero as H becomes zero.
-
-                    // H = U2-X1
-                    let mut h = u2;
-                    h.sub_assign(&self.x);
-
-                    // HH = H^2
-                    let mut hh = h;
-                    hh.square();
-
-                    // I = 4*HH
-                    let mut i = hh;
-                    i.double();
-                    i.double();
-
-                    // J = H*I
-                    let mut j = h;
-                    j.mul_assign(&i);
-
-                    // r = 2*(S2-Y1)
-                    let mut r = s2;
-                    r.sub_assign(&self.y);
-                    r.double();
-
-                    // V = X1*I
-                    let mut v = self.x;
-                    v.mul_assign(&i);
-
-                    // X3 = r^2 - J - 2*V
-                    self.x = r;
-                    self.x.square();
-                    self.x.sub_assign(&j);
-                    self.x.sub_assign(&v);
-                    self.x.sub_assign(&v);
-
-                    // Y3 = r*(V-X3)-2*Y1*J
-                    j.mul_assign(&self.y); // J = 2*Y1*J
-                    j.double();
-                    self.y = v;
-                    self.y.sub_assign(&self.x);
-                    self.y.mul_assign(&r);
-                    self.y.sub_assign(&j);
-
-                    // Z3 = (Z1+H)^2-Z1Z1-HH
-                    self.z.add_assign(&h);
-                    self.z.square();
-                    self.z.sub_assign(&z1z1);
-                    self.z.sub_assign(&hh);
-                }
-            }
-
-            fn negate(&mut self) {
-                if !self.is_zero() {
-                    self.y.negate()
-                }
-            }
-
-            fn mul_assign<S: Into<<Self::Scalar as PrimeField>::Repr>>(&mut self, other: S) {
-                let mut res = Self::zero();
-
-                let mut found_one = false;
-
-                for i in BitIterator::new(other.into())
-                {
-                    if found_one {
-                        res.double();
-                    } else {
-                        found_one = i;
-                    }
-
-                    if i {
-                        res.add_assign(self);
-                    }
-                }
-
-                *self = res;
-            }
-
-            fn into_affine(&self) -> $affine {
-                (*self).into()
-            }
-
-            fn recommended_wnaf_for_scalar(scalar: <Self::Scalar as PrimeField>::Repr) -> usize {
-                Self::empirical_recommended_wnaf_for_scalar(scalar)
-            }
-
-            fn recommended_wnaf_for_num_scalars(num_scalars: usize) -> usize {
-                Self::empirical_recommended_wnaf_for_num_scalars(num_scalars)
-            }
-        }
-
-        // The affine point X, Y is represented in the jacobian
-        // coordinates with Z = 1.
-        impl From<$affine> for $projective {
-            fn from(p: $affine) -> $projective {
-                if p.is_zero() {
-                    $projective::zero()
-                } else {
-                    $projective {
-                        x: p.x,
-                        y: p.y,
-                        z: $basefield::one()
-                    }
-                }
-            }
-        }
-
-        // The projective point X, Y, Z is represented in the affine
-        // coordinates as X/Z^2, Y/Z^3.
-        impl From<$projective> for $affine {
-            fn from(p: $projective) -> $affine {
-                if p.is_zero() {
-                    $affine::zero()
-                } else if p.z == $basefield::one() {
-                    // If Z is one, the point is already normalized.
-                    $affine {
-                        x: p.x,
-                        y: p.y,
-                        infinity: false
-                    }
-                } else {
-                    // Z is nonzero, so it must have an inverse in a field.
-                    let zinv = p.z.inverse().unwrap();
-                    let mut zinv_powered = zinv;
-                    zinv_powered.square();
-
-                    // X/Z^2
-                    let mut x = p.x;
-                    x.mul_assign(&zinv_powered);
-
-                    // Y/Z^3
-                    let mut y = p.y;
-                    zinv_powered.mul_assign(&zinv);
-                    y.mul_assign(&zinv_powered);
-
-                    $affine {
-                        x: x,
-                        y: y,
-                        infinity: false
-                    }
-                }
-            }
-        }
-    }
-}
-
 pub mod g1 {
-    use super::super::{Bls12, Fq, Fq12, FqRepr, Fr, FrRepr};
+    use super::super::{Bls12, Fq, FqRepr, Fr, FrRepr};
     use super::g2::G2Affine;
     use ff::{Rand, BitIterator, Field, PrimeField, PrimeFieldRepr, SqrtField};
     use rand::Rng;
     use std::fmt;
     use {CurveAffine, CurveProjective, EncodedPoint, Engine, GroupDecodingError};
 
-    curve_impl!(
-        "G1",
-        G1,
-        G1Affine,
-        G1Prepared,
-        Fq,
-        Fr,
-        G1Uncompressed,
-        G1Compressed,
-        G2Affine
-    );
-
-    #[derive(Copy, Clone)]
-    pub struct G1Uncompressed([u8; 96]);
-
-    impl AsRef<[u8]> for G1Uncompressed {
-        fn as_ref(&self) -> &[u8] {
-            &self.0
-        }
-    }
-
-    impl AsMut<[u8]> for G1Uncompressed {
-        fn as_mut(&mut self) -> &mut [u8] {
-            &mut self.0
-        }
-    }
-
-    impl fmt::Debug for G1Uncompressed {
-        fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-            self.0[..].fmt(formatter)
-        }
-    }
-
-    impl EncodedPoint for G1Uncompressed {
-        type Affine = G1Affine;
-
-        fn empty() -> Self {
-            G1Uncompressed([0; 96])
-        }
-        fn size() -> usize {
-            96
-        }
-        fn into_affine(&self) -> Result<G1Affine, GroupDecodingError> {
-            let affine = self.into_affine_unchecked()?;
-
-            if !affine.is_on_curve() {
-                Err(GroupDecodingError::NotOnCurve)
-            } else if !affine.is_in_correct_subgroup_assuming_on_curve() {
-                Err(GroupDecodingError::NotInSubgroup)
-            } else {
-                Ok(affine)
-            }
-        }
-        fn into_affine_unchecked(&self) -> Result<G1Affine, GroupDecodingError> {
-            // Create a copy of this representation.
-            let mut copy = self.0;
-
-            if copy[0] & (1 << 7) != 0 {
-                // Distinguisher bit is set, but this should be uncompressed!
-                return Err(GroupDecodingError::UnexpectedCompressionMode);
-            }
-
-            if copy[0] & (1 << 6) != 0 {
-                // This is the point at infinity, which means that if we mask away
-                // the first two bits, the entire representation should consist
-                // of zeroes.
-                copy[0] &= 0x3f;
-
-                if copy.iter().all(|b| *b == 0) {
-                    Ok(G1Affine::zero())
-                } else {
-                    Err(GroupDecodingError::UnexpectedInformation)
-                }
-            } else {
-                if copy[0] & (1 << 5) != 0 {
-                    // The bit indicating the y-coordinate should be lexicographically
-                    // largest is set, but this is an uncompressed element.
-                    return Err(GroupDecodingError::UnexpectedInformation);
-                }
-
-                // Unset the three most significant bits.
-                copy[0] &= 0x1f;
-
-                let mut x = FqRepr([0; 6]);
-                let mut y = FqRepr([0; 6]);
-
-                {
-                    let mut reader = &copy[..];
-
-                    x.read_be(&mut reader).unwrap();
-                    y.read_be(&mut reader).unwrap();
-                }
-
-                Ok(G1Affine {
-                    x: Fq::from_repr(x).map_err(|e| {
-                        GroupDecodingError::CoordinateDecodingError("x coordinate", e)
-                    })?,
-                    y: Fq::from_repr(y).map_err(|e| {
-                        GroupDecodingError::CoordinateDecodingError("y coordinate", e)
-                    })?,
-                    infinity: false,
-                })
-            }
-        }
-        fn from_affine(affine: G1Affine) -> Self {
-            let mut res = Self::empty();
-
-            if affine.is_zero() {
-                // Set the second-most significant bit to indicate this point
-                // is at infinity.
-                res.0[0] |= 1 << 6;
-            } else {
-                let mut writer = &mut res.0[..];
-
-                affine.x.into_repr().write_be(&mut writer).unwrap();
-                affine.y.into_repr().write_be(&mut writer).unwrap();
-            }
-
+    // G1 cofactor = (x - 1)^2 / 3  = 76329603384216526031706109802092473003
+    bls12_g1_impl!(Bls12, 48, [0x8c00aaab0000aaab, 0x396c8c005555e156], glv);
+
+    impl ::glv::GlvParameters for G1 {
+        const LAMBDA: [u64; 4] = [
+            0x00000000ffffffff,
+            0xac45a4010001a402,
+            0x0000000000000000,
+            0x0000000000000000,
+        ];
+        const MINUS_B1: [u64; 4] = [
+            0x0000000000000001,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ];
+        const B2: [u64; 4] = [
+            0x0000000100000000,
+            0xac45a4010001a402,
+            0x0000000000000000,
+            0x0000000000000000,
+        ];
+        const B2_OVER_R: [u64; 4] = [
+            0x63f6e522f6cfee30,
+            0x7c6becf1e01faadd,
+            0x0000000000000001,
+            0x0000000000000000,
+        ];
+        const MINUS_B1_OVER_R: [u64; 4] = [
+            0x0000000000000002,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ];
+
+        fn endomorphism(&self) -> Self {
+            let mut res = *self;
+            res.x.mul_assign(&super::super::fq::BETA);
             res
         }
     }
 
-    #[derive(Copy, Clone)]
-    pub struct G1Compressed([u8; 48]);
-
-    impl AsRef<[u8]> for G1Compressed {
-        fn as_ref(&self) -> &[u8] {
-            &self.0
-        }
-    }
-
-    impl AsMut<[u8]> for G1Compressed {
-        fn as_mut(&mut self) -> &mut [u8] {
-            &mut self.0
-        }
-    }
-
-    impl fmt::Debug for G1Compressed {
-        fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-            self.0[..].fmt(formatter)
-        }
-    }
-
-    impl EncodedPoint for G1Compressed {
-        type Affine = G1Affine;
-
-        fn empty() -> Self {
-            G1Compressed([0; 48])
-        }
-        fn size() -> usize {
-            48
-        }
-        fn into_affine(&self) -> Result<G1Affine, GroupDecodingError> {
-            let affine = self.into_affine_unchecked()?;
-
-            // NB: Decompression guarantees that it is on the curve already.
-
-            if !affine.is_in_correct_subgroup_assuming_on_curve() {
-                Err(GroupDecodingError::NotInSubgroup)
-            } else {
-                Ok(affine)
-            }
-        }
-        fn into_affine_unchecked(&self) -> Result<G1Affine, GroupDecodingError> {
-            // Create a copy of this representation.
-            let mut copy = self.0;
-
-            if copy[0] & (1 << 7) == 0 {
-                // Distinguisher bit isn't set.
-                return Err(GroupDecodingError::UnexpectedCompressionMode);
-            }
-
-            if copy[0] & (1 << 6) != 0 {
-                // This is the point at infinity, which means that if we mask away
-                // the first two bits, the entire representation should consist
-                // of zeroes.
-                copy[0] &= 0x3f;
-
-                if copy.iter().all(|b| *b == 0) {
-                    Ok(G1Affine::zero())
-                } else {
-                    Err(GroupDecodingError::UnexpectedInformation)
-                }
-            } else {
-                // Determine if the intended y coordinate must be greater
-                // lexicographically.
-                let greatest = copy[0] & (1 << 5) != 0;
-
-                // Unset the three most significant bits.
-                copy[0] &= 0x1f;
-
-                let mut x = FqRepr([0; 6]);
-
-                {
-                    let mut reader = &copy[..];
-
-                    x.read_be(&mut reader).unwrap();
-                }
-
-                // Interpret as Fq element.
-                let x = Fq::from_repr(x)
-                    .map_err(|e| GroupDecodingError::CoordinateDecodingError("x coordinate", e))?;
-
-                G1Affine::get_point_from_x(x, greatest).ok_or(GroupDecodingError::NotOnCurve)
-            }
-        }
-        fn from_affine(affine: G1Affine) -> Self {
-            let mut res = Self::empty();
-
-            if affine.is_zero() {
-                // Set the second-most significant bit to indicate this point
-                // is at infinity.
-                res.0[0] |= 1 << 6;
-            } else {
-                {
-                    let mut writer = &mut res.0[..];
-
-                    affine.x.into_repr().write_be(&mut writer).unwrap();
-                }
-
-                let mut negy = affine.y;
-                negy.negate();
-
-                // Set the third most significant bit if the correct y-coordinate
-                // is lexicographically largest.
-                if affine.y > negy {
-                    res.0[0] |= 1 << 5;
-                }
-            }
-
-            // Set highest bit to distinguish this as a compressed element.
-            res.0[0] |= 1 << 7;
-
-            res
-        }
-    }
-
-    impl G1Affine {
-        fn scale_by_cofactor(&self) -> G1 {
-            // G1 cofactor = (x - 1)^2 / 3  = 76329603384216526031706109802092473003
-            let cofactor = BitIterator::new([0x8c00aaab0000aaab, 0x396c8c005555e156]);
-            self.mul_bits(cofactor)
-        }
-
-        fn get_generator() -> Self {
-            G1Affine {
-                x: super::super::fq::G1_GENERATOR_X,
-                y: super::super::fq::G1_GENERATOR_Y,
-                infinity: false,
-            }
-        }
-
-        fn get_coeff_b() -> Fq {
-            super::super::fq::B_COEFF
-        }
-
-        fn perform_pairing(&self, other: &G2Affine) -> Fq12 {
-            super::super::Bls12::pairing(*self, *other)
-        }
-    }
-
-    impl G1 {
-        fn empirical_recommended_wnaf_for_scalar(scalar: FrRepr) -> usize {
-            let num_bits = scalar.num_bits() as usize;
-
-            if num_bits >= 130 {
-                4
-            } else if num_bits >= 34 {
-                3
-            } else {
-                2
-            }
-        }
-
-        fn empirical_recommended_wnaf_for_num_scalars(num_scalars: usize) -> usize {
-            const RECOMMENDATIONS: [usize; 12] =
-                [1, 3, 7, 20, 43, 120, 273, 563, 1630, 3128, 7933, 62569];
-
-            let mut ret = 4;
-            for r in &RECOMMENDATIONS {
-                if num_scalars > *r {
-                    ret += 1;
-                } else {
-                    break;
-                }
-            }
-
-            ret
-        }
-    }
-
-    #[derive(Clone, Debug)]
-    pub struct G1Prepared(pub(crate) G1Affine);
-
-    impl G1Prepared {
-        pub fn is_zero(&self) -> bool {
-            self.0.is_zero()
-        }
-
-        pub fn from_affine(p: G1Affine) -> Self {
-            G1Prepared(p)
-        }
-    }
-
     #[test]
     fn g1_generator() {
         use SqrtField;
@@ -962,14 +72,14 @@ pub mod g1 {
                     y: if yrepr < negyrepr { y } else { negy },
                     infinity: false,
                 };
-                assert!(!p.is_in_correct_subgroup_assuming_on_curve());
+                assert!(!p.is_torsion_free());
 
                 let g1 = p.scale_by_cofactor();
                 if !g1.is_zero() {
                     assert_eq!(i, 4);
                     let g1 = G1Affine::from(g1);
 
-                    assert!(g1.is_in_correct_subgroup_assuming_on_curve());
+                    assert!(g1.is_torsion_free());
 
                     assert_eq!(g1, G1Affine::one());
                     break;
@@ -1005,7 +115,7 @@ pub mod g1 {
                 infinity: false,
             };
             assert!(!p.is_on_curve());
-            assert!(p.is_in_correct_subgroup_assuming_on_curve());
+            assert!(::hash_to_curve::mul_by_limbs(&p.into_projective(), Fr::char().as_ref()).is_zero());
         }
 
         // Reject point on a twist (b = 3)
@@ -1030,7 +140,7 @@ pub mod g1 {
                 infinity: false,
             };
             assert!(!p.is_on_curve());
-            assert!(!p.is_in_correct_subgroup_assuming_on_curve());
+            assert!(!p.is_torsion_free());
         }
 
         // Reject point in an invalid subgroup
@@ -1056,7 +166,7 @@ pub mod g1 {
                 infinity: false,
             };
             assert!(p.is_on_curve());
-            assert!(!p.is_in_correct_subgroup_assuming_on_curve());
+            assert!(!p.is_torsion_free());
         }
     }
 
@@ -1250,9 +360,9 @@ pub mod g1 {
             infinity: false,
         };
 
-        assert!(a.is_on_curve() && a.is_in_correct_subgroup_assuming_on_curve());
-        assert!(b.is_on_curve() && b.is_in_correct_subgroup_assuming_on_curve());
-        assert!(c.is_on_curve() && c.is_in_correct_subgroup_assuming_on_curve());
+        assert!(a.is_on_curve() && a.is_torsion_free());
+        assert!(b.is_on_curve() && b.is_torsion_free());
+        assert!(c.is_on_curve() && c.is_torsion_free());
 
         let mut tmp1 = a.into_projective();
         tmp1.add_assign(&b.into_projective());
@@ -1269,357 +379,167 @@ pub mod g1 {
     fn g1_curve_tests() {
         ::tests::curve::curve_tests::<G1>();
     }
-}
 
-pub mod g2 {
-    use super::super::{Bls12, Fq, Fq12, Fq2, FqRepr, Fr, FrRepr};
-    use super::g1::G1Affine;
-    use ff::{Rand, BitIterator, Field, PrimeField, PrimeFieldRepr, SqrtField};
-    use rand::Rng;
-    use std::fmt;
-    use {CurveAffine, CurveProjective, EncodedPoint, Engine, GroupDecodingError};
-
-    curve_impl!(
-        "G2",
-        G2,
-        G2Affine,
-        G2Prepared,
-        Fq2,
-        Fr,
-        G2Uncompressed,
-        G2Compressed,
-        G1Affine
-    );
-
-    #[derive(Copy, Clone)]
-    pub struct G2Uncompressed([u8; 192]);
-
-    impl AsRef<[u8]> for G2Uncompressed {
-        fn as_ref(&self) -> &[u8] {
-            &self.0
-        }
-    }
-
-    impl AsMut<[u8]> for G2Uncompressed {
-        fn as_mut(&mut self) -> &mut [u8] {
-            &mut self.0
-        }
-    }
-
-    impl fmt::Debug for G2Uncompressed {
-        fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-            self.0[..].fmt(formatter)
-        }
-    }
-
-    impl EncodedPoint for G2Uncompressed {
-        type Affine = G2Affine;
-
-        fn empty() -> Self {
-            G2Uncompressed([0; 192])
-        }
-        fn size() -> usize {
-            192
-        }
-        fn into_affine(&self) -> Result<G2Affine, GroupDecodingError> {
-            let affine = self.into_affine_unchecked()?;
-
-            if !affine.is_on_curve() {
-                Err(GroupDecodingError::NotOnCurve)
-            } else if !affine.is_in_correct_subgroup_assuming_on_curve() {
-                Err(GroupDecodingError::NotInSubgroup)
-            } else {
-                Ok(affine)
-            }
-        }
-        fn into_affine_unchecked(&self) -> Result<G2Affine, GroupDecodingError> {
-            // Create a copy of this representation.
-            let mut copy = self.0;
-
-            if copy[0] & (1 << 7) != 0 {
-                // Distinguisher bit is set, but this should be uncompressed!
-                return Err(GroupDecodingError::UnexpectedCompressionMode);
-            }
-
-            if copy[0] & (1 << 6) != 0 {
-                // This is the point at infinity, which means that if we mask away
-                // the first two bits, the entire representation should consist
-                // of zeroes.
-                copy[0] &= 0x3f;
-
-                if copy.iter().all(|b| *b == 0) {
-                    Ok(G2Affine::zero())
-                } else {
-                    Err(GroupDecodingError::UnexpectedInformation)
-                }
-            } else {
-                if copy[0] & (1 << 5) != 0 {
-                    // The bit indicating the y-coordinate should be lexicographically
-                    // largest is set, but this is an uncompressed element.
-                    return Err(GroupDecodingError::UnexpectedInformation);
-                }
-
-                // Unset the three most significant bits.
-                copy[0] &= 0x1f;
-
-                let mut x_c0 = FqRepr([0; 6]);
-                let mut x_c1 = FqRepr([0; 6]);
-                let mut y_c0 = FqRepr([0; 6]);
-                let mut y_c1 = FqRepr([0; 6]);
-
-                {
-                    let mut reader = &copy[..];
-
-                    x_c1.read_be(&mut reader).unwrap();
-                    x_c0.read_be(&mut reader).unwrap();
-                    y_c1.read_be(&mut reader).unwrap();
-                    y_c0.read_be(&mut reader).unwrap();
-                }
+    #[test]
+    fn g1_glv_mul() {
+        use rand::SeedableRng;
+        use rand_xorshift::XorShiftRng;
 
-                Ok(G2Affine {
-                    x: Fq2 {
-                        c0: Fq::from_repr(x_c0).map_err(|e| {
-                            GroupDecodingError::CoordinateDecodingError("x coordinate (c0)", e)
-                        })?,
-                        c1: Fq::from_repr(x_c1).map_err(|e| {
-                            GroupDecodingError::CoordinateDecodingError("x coordinate (c1)", e)
-                        })?,
-                    },
-                    y: Fq2 {
-                        c0: Fq::from_repr(y_c0).map_err(|e| {
-                            GroupDecodingError::CoordinateDecodingError("y coordinate (c0)", e)
-                        })?,
-                        c1: Fq::from_repr(y_c1).map_err(|e| {
-                            GroupDecodingError::CoordinateDecodingError("y coordinate (c1)", e)
-                        })?,
-                    },
-                    infinity: false,
-                })
-            }
-        }
-        fn from_affine(affine: G2Affine) -> Self {
-            let mut res = Self::empty();
-
-            if affine.is_zero() {
-                // Set the second-most significant bit to indicate this point
-                // is at infinity.
-                res.0[0] |= 1 << 6;
-            } else {
-                let mut writer = &mut res.0[..];
-
-                affine.x.c1.into_repr().write_be(&mut writer).unwrap();
-                affine.x.c0.into_repr().write_be(&mut writer).unwrap();
-                affine.y.c1.into_repr().write_be(&mut writer).unwrap();
-                affine.y.c0.into_repr().write_be(&mut writer).unwrap();
-            }
+        let mut rng = XorShiftRng::seed_from_u64(0x5dbe62598d313d76);
 
-            res
+        let mut scalars = vec![
+            FrRepr::from(0),
+            FrRepr::from(1),
+            Fr::char(),
+            FrRepr([0xffffffffffffffff; 4]),
+        ];
+        let mut minus_one = Fr::one();
+        minus_one.negate();
+        scalars.push(minus_one.into_repr());
+        for _ in 0..100 {
+            scalars.push(Fr::rand(&mut rng).into_repr());
         }
-    }
 
-    #[derive(Copy, Clone)]
-    pub struct G2Compressed([u8; 96]);
+        for s in scalars {
+            let p = G1::rand(&mut rng);
 
-    impl AsRef<[u8]> for G2Compressed {
-        fn as_ref(&self) -> &[u8] {
-            &self.0
-        }
-    }
+            // The double-and-add ladder used before the GLV method.
+            let expected = p.mul_double_and_add(s);
 
-    impl AsMut<[u8]> for G2Compressed {
-        fn as_mut(&mut self) -> &mut [u8] {
-            &mut self.0
+            let mut q = p;
+            q.mul_assign(s);
+            assert_eq!(q, expected);
+            assert_eq!(p.into_affine().mul(s), expected);
         }
     }
 
-    impl fmt::Debug for G2Compressed {
-        fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-            self.0[..].fmt(formatter)
-        }
-    }
-
-    impl EncodedPoint for G2Compressed {
-        type Affine = G2Affine;
-
-        fn empty() -> Self {
-            G2Compressed([0; 96])
-        }
-        fn size() -> usize {
-            96
-        }
-        fn into_affine(&self) -> Result<G2Affine, GroupDecodingError> {
-            let affine = self.into_affine_unchecked()?;
+    #[test]
+    fn g1_is_torsion_free() {
+        use rand::SeedableRng;
+        use rand_xorshift::XorShiftRng;
 
-            // NB: Decompression guarantees that it is on the curve already.
+        let mut rng = XorShiftRng::seed_from_u64(0x5dbe62598d313d76);
 
-            if !affine.is_in_correct_subgroup_assuming_on_curve() {
-                Err(GroupDecodingError::NotInSubgroup)
-            } else {
-                Ok(affine)
-            }
+        assert!(G1Affine::zero().is_torsion_free());
+        for _ in 0..10 {
+            assert!(G1::rand(&mut rng).into_affine().is_torsion_free());
         }
-        fn into_affine_unchecked(&self) -> Result<G2Affine, GroupDecodingError> {
-            // Create a copy of this representation.
-            let mut copy = self.0;
 
-            if copy[0] & (1 << 7) == 0 {
-                // Distinguisher bit isn't set.
-                return Err(GroupDecodingError::UnexpectedCompressionMode);
-            }
-
-            if copy[0] & (1 << 6) != 0 {
-                // This is the point at infinity, which means that if we mask away
-                // the first two bits, the entire representation should consist
-                // of zeroes.
-                copy[0] &= 0x3f;
-
-                if copy.iter().all(|b| *b == 0) {
-                    Ok(G2Affine::zero())
-                } else {
-                    Err(GroupDecodingError::UnexpectedInformation)
-                }
-            } else {
-                // Determine if the intended y coordinate must be greater
-                // lexicographically.
-                let greatest = copy[0] & (1 << 5) != 0;
-
-                // Unset the three most significant bits.
-                copy[0] &= 0x1f;
-
-                let mut x_c1 = FqRepr([0; 6]);
-                let mut x_c0 = FqRepr([0; 6]);
-
-                {
-                    let mut reader = &copy[..];
-
-                    x_c1.read_be(&mut reader).unwrap();
-                    x_c0.read_be(&mut reader).unwrap();
-                }
+        // Points on the curve found by incrementing x are almost never in the
+        // subgroup; the check must agree with multiplication by r.
+        let mut x = Fq::zero();
+        let mut outside = 0;
+        while outside < 10 {
+            let mut rhs = x;
+            rhs.square();
+            rhs.mul_assign(&x);
+            rhs.add_assign(&G1Affine::get_coeff_b());
 
-                // Interpret as Fq element.
-                let x = Fq2 {
-                    c0: Fq::from_repr(x_c0).map_err(|e| {
-                        GroupDecodingError::CoordinateDecodingError("x coordinate (c0)", e)
-                    })?,
-                    c1: Fq::from_repr(x_c1).map_err(|e| {
-                        GroupDecodingError::CoordinateDecodingError("x coordinate (c1)", e)
-                    })?,
+            if let Some(y) = rhs.sqrt() {
+                let p = G1Affine {
+                    x: x,
+                    y: y,
+                    infinity: false,
                 };
-
-                G2Affine::get_point_from_x(x, greatest).ok_or(GroupDecodingError::NotOnCurve)
-            }
-        }
-        fn from_affine(affine: G2Affine) -> Self {
-            let mut res = Self::empty();
-
-            if affine.is_zero() {
-                // Set the second-most significant bit to indicate this point
-                // is at infinity.
-                res.0[0] |= 1 << 6;
-            } else {
-                {
-                    let mut writer = &mut res.0[..];
-
-                    affine.x.c1.into_repr().write_be(&mut writer).unwrap();
-                    affine.x.c0.into_repr().write_be(&mut writer).unwrap();
-                }
-
-                let mut negy = affine.y;
-                negy.negate();
-
-                // Set the third most significant bit if the correct y-coordinate
-                // is lexicographically largest.
-                if affine.y > negy {
-                    res.0[0] |= 1 << 5;
+                let in_subgroup = ::hash_to_curve::mul_by_limbs(&p.into_projective(), Fr::char().as_ref()).is_zero();
+                assert_eq!(p.is_torsion_free(), in_subgroup);
+                assert!(p.scale_by_cofactor().into_affine().is_torsion_free());
+
+                if !in_subgroup {
+                    outside += 1;
+                    assert!(match p.into_uncompressed().into_affine() {
+                        Err(GroupDecodingError::NotInSubgroup) => true,
+                        _ => false,
+                    });
+                    assert!(match p.into_compressed().into_affine() {
+                        Err(GroupDecodingError::NotInSubgroup) => true,
+                        _ => false,
+                    });
                 }
             }
 
-            // Set highest bit to distinguish this as a compressed element.
-            res.0[0] |= 1 << 7;
-
-            res
-        }
-    }
-
-    impl G2Affine {
-        fn get_generator() -> Self {
-            G2Affine {
-                x: Fq2 {
-                    c0: super::super::fq::G2_GENERATOR_X_C0,
-                    c1: super::super::fq::G2_GENERATOR_X_C1,
-                },
-                y: Fq2 {
-                    c0: super::super::fq::G2_GENERATOR_Y_C0,
-                    c1: super::super::fq::G2_GENERATOR_Y_C1,
-                },
-                infinity: false,
-            }
-        }
-
-        fn get_coeff_b() -> Fq2 {
-            Fq2 {
-                c0: super::super::fq::B_COEFF,
-                c1: super::super::fq::B_COEFF,
-            }
-        }
-
-        fn scale_by_cofactor(&self) -> G2 {
-            // G2 cofactor = (x^8 - 4 x^7 + 5 x^6) - (4 x^4 + 6 x^3 - 4 x^2 - 4 x + 13) // 9
-            // 0x5d543a95414e7f1091d50792876a202cd91de4547085abaa68a205b2e5a7ddfa628f1cb4d9e82ef21537e293a6691ae1616ec6e786f0c70cf1c38e31c7238e5
-            let cofactor = BitIterator::new([
-                0xcf1c38e31c7238e5,
-                0x1616ec6e786f0c70,
-                0x21537e293a6691ae,
-                0xa628f1cb4d9e82ef,
-                0xa68a205b2e5a7ddf,
-                0xcd91de4547085aba,
-                0x91d50792876a202,
-                0x5d543a95414e7f1,
-            ]);
-            self.mul_bits(cofactor)
-        }
-
-        fn perform_pairing(&self, other: &G1Affine) -> Fq12 {
-            super::super::Bls12::pairing(*other, *self)
+            x.add_assign(&Fq::one());
         }
     }
+}
 
-    impl G2 {
-        fn empirical_recommended_wnaf_for_scalar(scalar: FrRepr) -> usize {
-            let num_bits = scalar.num_bits() as usize;
-
-            if num_bits >= 103 {
-                4
-            } else if num_bits >= 37 {
-                3
-            } else {
-                2
-            }
-        }
-
-        fn empirical_recommended_wnaf_for_num_scalars(num_scalars: usize) -> usize {
-            const RECOMMENDATIONS: [usize; 11] =
-                [1, 3, 8, 20, 47, 126, 260, 826, 1501, 4555, 84071];
+pub mod g2 {
+    use super::super::{Bls12, Fq, Fq2, FqRepr, Fr, FrRepr};
+    use super::g1::G1Affine;
+    use ff::{Rand, BitIterator, Field, PrimeField, PrimeFieldRepr, SqrtField};
+    use rand::Rng;
+    use std::fmt;
+    use {CurveAffine, CurveProjective, EncodedPoint, Engine, GroupDecodingError};
 
-            let mut ret = 4;
-            for r in &RECOMMENDATIONS {
-                if num_scalars > *r {
-                    ret += 1;
-                } else {
-                    break;
-                }
-            }
+    // G2 cofactor = (x^8 - 4 x^7 + 5 x^6) - (4 x^4 + 6 x^3 - 4 x^2 - 4 x + 13) // 9
+    // 0x5d543a95414e7f1091d50792876a202cd91de4547085abaa68a205b2e5a7ddfa628f1cb4d9e82ef21537e293a6691ae1616ec6e786f0c70cf1c38e31c7238e5
+    bls12_g2_impl!(
+        Bls12,
+        48,
+        [
+            0xcf1c38e31c7238e5,
+            0x1616ec6e786f0c70,
+            0x21537e293a6691ae,
+            0xa628f1cb4d9e82ef,
+            0xa68a205b2e5a7ddf,
+            0xcd91de4547085aba,
+            0x91d50792876a202,
+            0x5d543a95414e7f1,
+        ],
+        gls
+    );
 
-            ret
+    impl ::glv::GlsParameters for G2 {
+        const BASIS: [[i128; 4]; 4] = [
+            [15132376222941642752, 1, 0, 0],
+            [0, 15132376222941642752, 1, 0],
+            [0, 0, 15132376222941642752, 1],
+            [1, 0, -1, -15132376222941642752],
+        ];
+        const ROUNDING: [([u64; 4], bool); 4] = [
+            (
+                [
+                    0x92078a5e8573b29d,
+                    0x33cfcc0d3e76ec28,
+                    0x381204ca56cd56b5,
+                    0x0000000000000001,
+                ],
+                false,
+            ),
+            (
+                [
+                    0x63f6e522f6cfee2e,
+                    0x7c6becf1e01faadd,
+                    0x0000000000000001,
+                    0x0000000000000000,
+                ],
+                true,
+            ),
+            (
+                [
+                    0xcfbe4f7bd0027db3,
+                    0x0000000000000001,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ],
+                false,
+            ),
+            (
+                [
+                    0x0000000000000002,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ],
+                false,
+            ),
+        ];
+
+        fn endomorphism(&self) -> Self {
+            self.psi()
         }
     }
 
-    #[derive(Clone, Debug)]
-    pub struct G2Prepared {
-        pub(crate) coeffs: Vec<(Fq2, Fq2, Fq2)>,
-        pub(crate) infinity: bool,
-    }
-
     #[test]
     fn g2_generator() {
         use SqrtField;
@@ -1643,14 +563,14 @@ pub mod g2 {
                     infinity: false,
                 };
 
-                assert!(!p.is_in_correct_subgroup_assuming_on_curve());
+                assert!(!p.is_torsion_free());
 
                 let g2 = p.scale_by_cofactor();
                 if !g2.is_zero() {
                     assert_eq!(i, 2);
                     let g2 = G2Affine::from(g2);
 
-                    assert!(g2.is_in_correct_subgroup_assuming_on_curve());
+                    assert!(g2.is_torsion_free());
                     assert_eq!(g2, G2Affine::one());
                     break;
                 }
@@ -1705,7 +625,7 @@ pub mod g2 {
                 infinity: false,
             };
             assert!(!p.is_on_curve());
-            assert!(p.is_in_correct_subgroup_assuming_on_curve());
+            assert!(::hash_to_curve::mul_by_limbs(&p.into_projective(), Fr::char().as_ref()).is_zero());
         }
 
         // Reject point on a twist (b = 2 * (u + 1))
@@ -1750,7 +670,7 @@ pub mod g2 {
                 infinity: false,
             };
             assert!(!p.is_on_curve());
-            assert!(!p.is_in_correct_subgroup_assuming_on_curve());
+            assert!(!p.is_torsion_free());
         }
 
         // Reject point in an invalid subgroup
@@ -1796,7 +716,7 @@ pub mod g2 {
                 infinity: false,
             };
             assert!(p.is_on_curve());
-            assert!(!p.is_in_correct_subgroup_assuming_on_curve());
+            assert!(!p.is_torsion_free());
         }
     }
 
@@ -2022,6 +942,136 @@ pub mod g2 {
     fn g2_curve_tests() {
         ::tests::curve::curve_tests::<G2>();
     }
+
+    #[test]
+    fn g2_psi() {
+        use rand::SeedableRng;
+        use rand_xorshift::XorShiftRng;
+
+        let mut rng = XorShiftRng::seed_from_u64(0x5dbe62598d313d76);
+
+        assert!(G2::zero().psi().is_zero());
+
+        for _ in 0..10 {
+            // psi acts on G2 as multiplication by q.
+            let p = G2::rand(&mut rng);
+            assert_eq!(p.psi(), ::hash_to_curve::mul_by_limbs(&p, Fq::char().as_ref()));
+        }
+    }
+
+    #[test]
+    fn g2_is_torsion_free() {
+        use rand::SeedableRng;
+        use rand_xorshift::XorShiftRng;
+
+        let mut rng = XorShiftRng::seed_from_u64(0x5dbe62598d313d76);
+
+        assert!(G2Affine::zero().is_torsion_free());
+        for _ in 0..10 {
+            assert!(G2::rand(&mut rng).into_affine().is_torsion_free());
+        }
+
+        // Points on the curve found by incrementing x are almost never in the
+        // subgroup; the check must agree with multiplication by r.
+        let mut x = Fq2::zero();
+        let mut outside = 0;
+        while outside < 10 {
+            let mut rhs = x;
+            rhs.square();
+            rhs.mul_assign(&x);
+            rhs.add_assign(&G2Affine::get_coeff_b());
+
+            if let Some(y) = rhs.sqrt() {
+                let p = G2Affine {
+                    x: x,
+                    y: y,
+                    infinity: false,
+                };
+                let in_subgroup = ::hash_to_curve::mul_by_limbs(&p.into_projective(), Fr::char().as_ref()).is_zero();
+                assert_eq!(p.is_torsion_free(), in_subgroup);
+                assert!(p.scale_by_cofactor().into_affine().is_torsion_free());
+
+                if !in_subgroup {
+                    outside += 1;
+                    assert!(match p.into_uncompressed().into_affine() {
+                        Err(GroupDecodingError::NotInSubgroup) => true,
+                        _ => false,
+                    });
+                    assert!(match p.into_compressed().into_affine() {
+                        Err(GroupDecodingError::NotInSubgroup) => true,
+                        _ => false,
+                    });
+                }
+            }
+
+            x.add_assign(&Fq2::one());
+        }
+    }
+
+    #[test]
+    fn g2_clear_cofactor() {
+        use rand::SeedableRng;
+        use rand_xorshift::XorShiftRng;
+
+        let mut rng = XorShiftRng::seed_from_u64(0x5dbe62598d313d76);
+
+        assert!(G2::zero().clear_cofactor().is_zero());
+
+        // h_eff / h = 3(x^2 - 1).
+        let mut factor = Fr::from_repr(FrRepr::from(0xd201000000010000)).unwrap();
+        factor.square();
+        factor.sub_assign(&Fr::one());
+        factor.mul_assign(&Fr::from_repr(FrRepr::from(3)).unwrap());
+
+        let mut tested = 0;
+        while tested < 10 {
+            // Random points on the curve, which are almost never in G2.
+            if let Some(p) = G2Affine::get_point_from_x(Fq2::rand(&mut rng), false) {
+                let q = p.into_projective().clear_cofactor();
+                assert!(!q.is_zero());
+                assert!(q.into_affine().is_torsion_free());
+
+                let mut expected = p.scale_by_cofactor();
+                expected.mul_assign(factor);
+                assert_eq!(q, expected);
+
+                tested += 1;
+            }
+        }
+    }
+
+    #[test]
+    fn g2_gls_mul() {
+        use rand::SeedableRng;
+        use rand_xorshift::XorShiftRng;
+
+        let mut rng = XorShiftRng::seed_from_u64(0x5dbe62598d313d76);
+
+        let mut scalars = vec![
+            FrRepr::from(0),
+            FrRepr::from(1),
+            Fr::char(),
+            FrRepr([0xffffffffffffffff; 4]),
+        ];
+        let mut minus_one = Fr::one();
+        minus_one.negate();
+        scalars.push(minus_one.into_repr());
+        for _ in 0..100 {
+            scalars.push(Fr::rand(&mut rng).into_repr());
+        }
+
+        for s in scalars {
+            let p = G2::rand(&mut rng);
+
+            // The double-and-add ladder used before the GLS method.
+            let expected = p.mul_double_and_add(s);
+
+            let mut q = p;
+            q.mul_assign(s);
+            assert_eq!(q, expected);
+            assert_eq!(p.into_affine().mul(s), expected);
+        }
+    }
 }
 
 pub use self::g1::*;
@@ -11,10 +11,41 @@ pub const B_COEFF: Fq = Fq(FqRepr([
     0x9d645513d83de7e,
 ]));
 
+// B coefficient of BLS12-381 G2, 4 * (u + 1).
+pub const B_COEFF_FQ2: Fq2 = Fq2 {
+    c0: Fq(FqRepr([
+        0xaa270000000cfff3,
+        0x53cc0032fc34000a,
+        0x478fe97a6b0a807f,
+        0xb1d37ebee6ba24d7,
+        0x8ec9733bbf78ab2f,
+        0x9d645513d83de7e,
+    ])),
+    c1: Fq(FqRepr([
+        0xaa270000000cfff3,
+        0x53cc0032fc34000a,
+        0x478fe97a6b0a807f,
+        0xb1d37ebee6ba24d7,
+        0x8ec9733bbf78ab2f,
+        0x9d645513d83de7e,
+    ])),
+};
+
 // The generators of G1/G2 are computed by finding the lexicographically smallest valid x coordinate,
 // and its lexicographically smallest y coordinate and multiplying it by the cofactor such that the
 // result is nonzero.
 
+// A primitive cube root of unity, such that (x, y) -> (BETA * x, y) acts as
+// multiplication by the GLV eigenvalue lambda on G1.
+pub const BETA: Fq = Fq(FqRepr([
+    0xcd03c9e48671f071,
+    0x5dab22461fcda5d2,
+    0x587042afd3851b95,
+    0x8eb60ebe01bacb9e,
+    0x03f97d6e83d050d2,
+    0x18f0206554638741,
+]));
+
 // Generator of G1
 // x = 3685416753713387016781088315183077757961620795782546409894578378688607592378376318836054947676345821548104185464507
 // y = 1339506544944476473020471379941921221584933875938349620426543736416511423956333506472724655353366534992391756441569
@@ -71,6 +102,46 @@ pub const G2_GENERATOR_Y_C1: Fq = Fq(FqRepr([
     0xb2bc2a163de1bf2,
 ]));
 
+// Coefficients of the untwist-Frobenius-twist endomorphism psi of G2,
+// 1 / xi^((q - 1) / 3) and 1 / xi^((q - 1) / 2) for the M-type twist.
+pub const PSI_COEFF_X: Fq2 = Fq2 {
+    c0: Fq(FqRepr([
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    c1: Fq(FqRepr([
+        0x890dc9e4867545c3,
+        0x2af322533285a5d5,
+        0x50880866309b7e2c,
+        0xa20d1b8c7e881024,
+        0x14e4f04fe2db9068,
+        0x14e56d3f1564853a,
+    ])),
+};
+
+pub const PSI_COEFF_Y: Fq2 = Fq2 {
+    c0: Fq(FqRepr([
+        0x3e2f585da55c9ad1,
+        0x4294213d86c18183,
+        0x382844c88b623732,
+        0x92ad2afd19103e18,
+        0x1d794e4fac7cf0b9,
+        0x0bd592fc7d825ec8,
+    ])),
+    c1: Fq(FqRepr([
+        0x7bcfa7a25aa30fda,
+        0xdc17dec12a927e7c,
+        0x2f088dd86b4ebef1,
+        0xd1ca2087da74d4a7,
+        0x2da2596696cebc1d,
+        0x0e2b7eedbbfd87d2,
+    ])),
+};
+
 // Coefficients for the Frobenius automorphism.
 pub const FROBENIUS_COEFF_FQ2_C1: [Fq; 2] = [
     // Fq(-1)**(((q^0) - 1) / 2)
@@ -452,6 +523,13 @@ pub struct Fq(FqRepr);
 #[test]
 fn test_b_coeff() {
     assert_eq!(Fq::from_repr(FqRepr::from(4)).unwrap(), B_COEFF);
+    assert_eq!(
+        Fq2 {
+            c0: B_COEFF,
+            c1: B_COEFF,
+        },
+        B_COEFF_FQ2
+    );
 }
 
 #[test]
@@ -1165,6 +1243,22 @@ fn test_frob_coeffs() {
     );
 }
 
+#[test]
+fn test_psi_coeffs() {
+    // xi^((q - 1) / 2) = (xi^((q - 1) / 6))^3
+    let mut xi_to_q_minus_1_over_2 = FROBENIUS_COEFF_FQ12_C1[1];
+    xi_to_q_minus_1_over_2.square();
+    xi_to_q_minus_1_over_2.mul_assign(&FROBENIUS_COEFF_FQ12_C1[1]);
+
+    let mut x = PSI_COEFF_X;
+    x.mul_assign(&FROBENIUS_COEFF_FQ6_C1[1]);
+    assert_eq!(x, Fq2::one());
+
+    let mut y = PSI_COEFF_Y;
+    y.mul_assign(&xi_to_q_minus_1_over_2);
+    assert_eq!(y, Fq2::one());
+}
+
 #[test]
 fn test_neg_one() {
     let mut o = Fq::one();
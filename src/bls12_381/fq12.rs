@@ -4,155 +4,7 @@ use super::fq6::Fq6;
 use ff::{Rand, Field};
 use rand::Rng;
 
-/// An element of Fq12, represented by c0 + c1 * w.
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-pub struct Fq12 {
-    pub c0: Fq6,
-    pub c1: Fq6,
-}
-
-impl ::std::fmt::Display for Fq12 {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-        write!(f, "Fq12({} + {} * w)", self.c0, self.c1)
-    }
-}
-
-impl Rand for Fq12 {
-    fn rand<R: Rng + ?Sized>(rng: &mut R) -> Self {
-        Fq12 {
-            c0: rng.gen(),
-            c1: rng.gen(),
-        }
-    }
-}
-
-impl ::rand::distributions::Distribution<Fq12> for ::rand::distributions::Standard {
-    fn sample<R: ::rand::Rng + ?Sized>(&self, rng: &mut R) -> Fq12 {
-        Fq12::rand(rng)
-    }
-}
-
-impl Fq12 {
-    pub fn conjugate(&mut self) {
-        self.c1.negate();
-    }
-
-    pub fn mul_by_014(&mut self, c0: &Fq2, c1: &Fq2, c4: &Fq2) {
-        let mut aa = self.c0;
-        aa.mul_by_01(c0, c1);
-        let mut bb = self.c1;
-        bb.mul_by_1(c4);
-        let mut o = *c1;
-        o.add_assign(c4);
-        self.c1.add_assign(&self.c0);
-        self.c1.mul_by_01(c0, &o);
-        self.c1.sub_assign(&aa);
-        self.c1.sub_assign(&bb);
-        self.c0 = bb;
-        self.c0.mul_by_nonresidue();
-        self.c0.add_assign(&aa);
-    }
-}
-
-impl Field for Fq12 {
-    fn zero() -> Self {
-        Fq12 {
-            c0: Fq6::zero(),
-            c1: Fq6::zero(),
-        }
-    }
-
-    fn one() -> Self {
-        Fq12 {
-            c0: Fq6::one(),
-            c1: Fq6::zero(),
-        }
-    }
-
-    fn is_zero(&self) -> bool {
-        self.c0.is_zero() && self.c1.is_zero()
-    }
-
-    fn double(&mut self) {
-        self.c0.double();
-        self.c1.double();
-    }
-
-    fn negate(&mut self) {
-        self.c0.negate();
-        self.c1.negate();
-    }
-
-    fn add_assign(&mut self, other: &Self) {
-        self.c0.add_assign(&other.c0);
-        self.c1.add_assign(&other.c1);
-    }
-
-    fn sub_assign(&mut self, other: &Self) {
-        self.c0.sub_assign(&other.c0);
-        self.c1.sub_assign(&other.c1);
-    }
-
-    fn frobenius_map(&mut self, power: usize) {
-        self.c0.frobenius_map(power);
-        self.c1.frobenius_map(power);
-
-        self.c1.c0.mul_assign(&FROBENIUS_COEFF_FQ12_C1[power % 12]);
-        self.c1.c1.mul_assign(&FROBENIUS_COEFF_FQ12_C1[power % 12]);
-        self.c1.c2.mul_assign(&FROBENIUS_COEFF_FQ12_C1[power % 12]);
-    }
-
-    fn square(&mut self) {
-        let mut ab = self.c0;
-        ab.mul_assign(&self.c1);
-        let mut c0c1 = self.c0;
-        c0c1.add_assign(&self.c1);
-        let mut c0 = self.c1;
-        c0.mul_by_nonresidue();
-        c0.add_assign(&self.c0);
-        c0.mul_assign(&c0c1);
-        c0.sub_assign(&ab);
-        self.c1 = ab;
-        self.c1.add_assign(&ab);
-        ab.mul_by_nonresidue();
-        c0.sub_assign(&ab);
-        self.c0 = c0;
-    }
-
-    fn mul_assign(&mut self, other: &Self) {
-        let mut aa = self.c0;
-        aa.mul_assign(&other.c0);
-        let mut bb = self.c1;
-        bb.mul_assign(&other.c1);
-        let mut o = other.c0;
-        o.add_assign(&other.c1);
-        self.c1.add_assign(&self.c0);
-        self.c1.mul_assign(&o);
-        self.c1.sub_assign(&aa);
-        self.c1.sub_assign(&bb);
-        self.c0 = bb;
-        self.c0.mul_by_nonresidue();
-        self.c0.add_assign(&aa);
-    }
-
-    fn inverse(&self) -> Option<Self> {
-        let mut c0s = self.c0;
-        c0s.square();
-        let mut c1s = self.c1;
-        c1s.square();
-        c1s.mul_by_nonresidue();
-        c0s.sub_assign(&c1s);
-
-        c0s.inverse().map(|t| {
-            let mut tmp = Fq12 { c0: t, c1: t };
-            tmp.c0.mul_assign(&self.c0);
-            tmp.c1.mul_assign(&self.c1);
-            tmp.c1.negate();
-
-            tmp
-        })
-    }
-}
+bls12_fq12_impl!();
 
 #[cfg(test)]
 use rand::SeedableRng;
@@ -189,6 +41,63 @@ fn test_fq12_mul_by_014() {
     }
 }
 
+#[test]
+fn test_fq12_cyclotomic() {
+    use ff::PrimeField;
+    use super::fr::Fr;
+
+    let mut rng = XorShiftRng::seed_from_u64(0x5dbe62598d313d76);
+
+    let mut identity = Fq12::one();
+    identity.cyclotomic_square();
+    assert_eq!(identity, Fq12::one());
+    identity.compressed_square();
+    identity.decompress();
+    assert_eq!(identity, Fq12::one());
+
+    for _ in 0..100 {
+        // Maps a random element into the cyclotomic subgroup with the easy
+        // part of the final exponentiation, f^((q^6 - 1)(q^2 + 1)).
+        let g = Fq12::rand(&mut rng);
+        let mut f = g;
+        f.conjugate();
+        f.mul_assign(&g.inverse().unwrap());
+        let mut h = f;
+        h.frobenius_map(2);
+        f.mul_assign(&h);
+
+        let mut a = f;
+        a.cyclotomic_square();
+        let mut b = f;
+        b.square();
+        assert_eq!(a, b);
+
+        let mut compressed = vec![];
+        let mut c = f;
+        let mut d = f;
+        for _ in 0..5 {
+            c.compressed_square();
+            d.square();
+            let mut e = c;
+            e.decompress();
+            assert_eq!(e, d);
+            compressed.push((c, d));
+        }
+        let mut batch: Vec<_> = compressed.iter().map(|&(c, _)| c).collect();
+        Fq12::batch_decompress(&mut batch);
+        for (c, &(_, d)) in batch.iter().zip(compressed.iter()) {
+            assert_eq!(*c, d);
+        }
+
+        let r = Fr::char();
+        assert_eq!(f.cyclotomic_exp(r), f.pow(r));
+        assert_eq!(f.cyclotomic_exp(&[]), Fq12::one());
+        for exp in [[0, 0], [1, 0], [0xd201000000010000, 0], [rng.gen(), 0], [rng.gen(), rng.gen()]].iter() {
+            assert_eq!(f.cyclotomic_exp(exp), f.pow(exp));
+        }
+    }
+}
+
 #[test]
 fn fq12_field_tests() {
     use ff::PrimeField;
@@ -0,0 +1,1127 @@
+//! The `BLS12381G1_XMD:SHA-256_SSWU_RO_` and `BLS12381G2_XMD:SHA-256_SSWU_RO_`
+//! hash-to-curve suites of RFC 9380, section 8.8, together with their `_NU_`
+//! counterparts.
+//!
+//! Both suites use the simplified SWU map onto a curve isogenous to the
+//! target curve, followed by the isogeny: of degree 11 for G1 and degree 3
+//! for G2. The isogeny constants are those of RFC 9380, appendix E.
+
+use super::{Fq, Fq2, FqRepr, FrRepr, G1, G1Affine, G2, G2Affine};
+use ff::{Field, PrimeField};
+use hash_to_curve::{
+    isogeny_map, prime_field_from_okm, prime_field_sgn0, simplified_swu,
+    HashToField, MapToCurve,
+};
+use {CurveAffine, CurveProjective};
+
+impl HashToField for Fq {
+    // L = ceil((ceil(log2(q)) + k) / 8) = ceil((381 + 128) / 8)
+    const OKM_BYTES: usize = 64;
+
+    fn from_okm(okm: &[u8]) -> Fq {
+        prime_field_from_okm(okm)
+    }
+
+    fn sgn0(&self) -> bool {
+        prime_field_sgn0(self)
+    }
+}
+
+impl HashToField for Fq2 {
+    const OKM_BYTES: usize = 2 * 64;
+
+    fn from_okm(okm: &[u8]) -> Fq2 {
+        Fq2 {
+            c0: Fq::from_okm(&okm[..64]),
+            c1: Fq::from_okm(&okm[64..]),
+        }
+    }
+
+    fn sgn0(&self) -> bool {
+        self.c0.sgn0() || (self.c0.is_zero() && self.c1.sgn0())
+    }
+}
+
+fn fq(repr: &FqRepr) -> Fq {
+    Fq::from_repr(*repr).unwrap()
+}
+
+fn fq2(reprs: &[FqRepr; 2]) -> Fq2 {
+    Fq2 {
+        c0: fq(&reprs[0]),
+        c1: fq(&reprs[1]),
+    }
+}
+
+/// The coefficient A' of the curve E' : y^2 = x^3 + A' * x + B' that is
+/// 11-isogenous to G1.
+const SSWU_A_G1: FqRepr = FqRepr([
+    0x5cf428082d584c1d,
+    0x98936f8da0e0f97f,
+    0xd8e8981aefd881ac,
+    0xb0ea985383ee66a8,
+    0x3d693a02c96d4982,
+    0x00144698a3b8e943,
+]);
+
+/// The coefficient B' of the curve E' that is 11-isogenous to G1.
+const SSWU_B_G1: FqRepr = FqRepr([
+    0xd1cc48e98e172be0,
+    0x5a23215a316ceaa5,
+    0xa0b9c14fcef35ef5,
+    0x2016c1f0f24f4070,
+    0x018b12e8753eee3b,
+    0x12e2908d11688030,
+]);
+
+/// The non-square Z = 11 used by the G1 suite.
+const SSWU_Z_G1: u64 = 11;
+
+/// The effective cofactor h_eff = 1 - x of the G1 suite.
+const H_EFF_G1: u64 = 0xd201000000010001;
+
+/// Coefficients of the 11-isogeny x-coordinate numerator polynomial, in ascending degree.
+const ISO11_X_NUM: [FqRepr; 12] = [
+    FqRepr([
+        0xaeac1662734649b7,
+        0x5610c2d5f2e62d6e,
+        0xf2627b56cdb4e2c8,
+        0x6b303e88a2d7005f,
+        0xb809101dd9981585,
+        0x11a05f2b1e833340,
+    ]),
+    FqRepr([
+        0xe834eef1b3cb83bb,
+        0x4838f2a6f318c356,
+        0xf565e33c70d1e86b,
+        0x7c17e75b2f6a8417,
+        0x0588bab22147a81c,
+        0x17294ed3e943ab2f,
+    ]),
+    FqRepr([
+        0xe0179f9dac9edcb0,
+        0x958c3e3d2a09729f,
+        0x6878e501ec68e25c,
+        0xce032473295983e5,
+        0x1d1048c5d10a9a1b,
+        0x0d54005db97678ec,
+    ]),
+    FqRepr([
+        0xc5b388641d9b6861,
+        0x5336e25ce3107193,
+        0xf1b33289f1b33083,
+        0xd7f5e4656a8dbf25,
+        0x4e0609d307e55412,
+        0x1778e7166fcc6db7,
+    ]),
+    FqRepr([
+        0x51154ce9ac8895d9,
+        0x985a286f301e77c4,
+        0x086eeb65982fac18,
+        0x99db995a1257fb3f,
+        0x6642b4b3e4118e54,
+        0x0e99726a3199f443,
+    ]),
+    FqRepr([
+        0xcd13c1c66f652983,
+        0xa0870d2dcae73d19,
+        0x9ed3ab9097e68f90,
+        0xdb3cb17dd952799b,
+        0x01d1201bf7a74ab5,
+        0x1630c3250d7313ff,
+    ]),
+    FqRepr([
+        0xddd7f225a139ed84,
+        0x8da25128c1052eca,
+        0x9008e218f9c86b2a,
+        0xb11586264f0f8ce1,
+        0x6a3726c38ae652bf,
+        0x0d6ed6553fe44d29,
+    ]),
+    FqRepr([
+        0x9ccb5618e3f0c88e,
+        0x39b7c8f8c8f475af,
+        0xa682c62ef0f27533,
+        0x356de5ab275b4db1,
+        0xe8743884d1117e53,
+        0x17b81e7701abdbe2,
+    ]),
+    FqRepr([
+        0x6d71986a8497e317,
+        0x4fa295f296b74e95,
+        0xa2c596c928c5d1de,
+        0xc43b756ce79f5574,
+        0x7b90b33563be990d,
+        0x080d3cf1f9a78fc4,
+    ]),
+    FqRepr([
+        0x7f241067be390c9e,
+        0xa3190b2edc032779,
+        0x676314baf4bb1b7f,
+        0xdd2ecb803a0c5c99,
+        0x2e0c37515d138f22,
+        0x169b1f8e1bcfa7c4,
+    ]),
+    FqRepr([
+        0xca67df3f1605fb7b,
+        0xf69b771f8c285dec,
+        0xd50af36003b14866,
+        0xfa7dccdde6787f96,
+        0x72d8ec09d2565b0d,
+        0x10321da079ce07e2,
+    ]),
+    FqRepr([
+        0xa9c8ba2e8ba2d229,
+        0xc24b1b80b64d391f,
+        0x23c0bf1bc24c6b68,
+        0x31d79d7e22c837bc,
+        0xbd1e962381edee3d,
+        0x06e08c248e260e70,
+    ]),
+];
+
+/// Coefficients of the 11-isogeny x-coordinate denominator polynomial, in ascending degree.
+const ISO11_X_DEN: [FqRepr; 11] = [
+    FqRepr([
+        0x993cf9fa40d21b1c,
+        0xb558d681be343df8,
+        0x9c9588617fc8ac62,
+        0x01d5ef4ba35b48ba,
+        0x18b2e62f4bd3fa6f,
+        0x08ca8d548cff19ae,
+    ]),
+    FqRepr([
+        0xe5c8276ec82b3bff,
+        0x13daa8846cb026e9,
+        0x0126c2588c48bf57,
+        0x7041e8ca0cf0800c,
+        0x48b4711298e53636,
+        0x12561a5deb559c43,
+    ]),
+    FqRepr([
+        0xfcc239ba5cb83e19,
+        0xd6a3d0967c94fedc,
+        0xfca64e00b11aceac,
+        0x6f89416f5a718cd1,
+        0x8137e629bff2991f,
+        0x0b2962fe57a3225e,
+    ]),
+    FqRepr([
+        0x130de8938dc62cd8,
+        0x4976d5243eecf5c4,
+        0x54cca8abc28d6fd0,
+        0x5b08243f16b16551,
+        0xc83aafef7c40eb54,
+        0x03425581a58ae2fe,
+    ]),
+    FqRepr([
+        0x539d395b3532a21e,
+        0x9bd29ba81f35781d,
+        0x8d6b44e833b306da,
+        0xffdfc759a12062bb,
+        0x0a6f1d5f43e7a07d,
+        0x13a8e162022914a8,
+    ]),
+    FqRepr([
+        0xc02df9a29f6304a5,
+        0x7400d24bc4228f11,
+        0x0a43bcef24b8982f,
+        0x395735e9ce9cad4d,
+        0x55390f7f0506c6e9,
+        0x0e7355f8e4e667b9,
+    ]),
+    FqRepr([
+        0xec2574496ee84a3a,
+        0xea73b3538f0de06c,
+        0x4e2e073062aede9c,
+        0x570f5799af53a189,
+        0x0f3e0c63e0596721,
+        0x0772caacf1693619,
+    ]),
+    FqRepr([
+        0x11f7d99bbdcc5a5e,
+        0x0fa5b9489d11e2d3,
+        0x1996e1cdf9822c58,
+        0x6e7f63c21bca68a8,
+        0x30b3f5b074cf0199,
+        0x14a7ac2a9d64a8b2,
+    ]),
+    FqRepr([
+        0x4776ec3a79a1d641,
+        0x03826692abba4370,
+        0x74100da67f398835,
+        0xe07f8d1d7161366b,
+        0x5e920b3dafc7a3cc,
+        0x0a10ecf6ada54f82,
+    ]),
+    FqRepr([
+        0x2d6384d168ecdd0a,
+        0x93174e4b4b786500,
+        0x76df533978f31c15,
+        0xf682b4ee96f7d037,
+        0x476d6e3eb3a56680,
+        0x095fc13ab9e92ad4,
+    ]),
+    FqRepr([
+        0x0000000000000001,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]),
+];
+
+/// Coefficients of the 11-isogeny y-coordinate numerator polynomial, in ascending degree.
+const ISO11_Y_NUM: [FqRepr; 16] = [
+    FqRepr([
+        0xbe9845719707bb33,
+        0xcd0c7aee9b3ba3c2,
+        0x2b52af6c956543d3,
+        0x11ad138e48a86952,
+        0x259d1f094980dcfa,
+        0x090d97c81ba24ee0,
+    ]),
+    FqRepr([
+        0xe097e75a2e41c696,
+        0xd6c56711962fa8bf,
+        0x0f906343eb67ad34,
+        0x1223e96c254f383d,
+        0xd51036d776fb4683,
+        0x134996a104ee5811,
+    ]),
+    FqRepr([
+        0xb8dfe240c72de1f6,
+        0xd26d521628b00523,
+        0xc344be4b91400da7,
+        0x2552e2d658a31ce2,
+        0xf4a384c86a3b4994,
+        0x00cc786baa966e66,
+    ]),
+    FqRepr([
+        0xa6355c77b0e5f4cb,
+        0xde405aba9ec61dec,
+        0x09e4a3ec03251cf9,
+        0xd42aa7b90eeb791c,
+        0x7898751ad8746757,
+        0x01f86376e8981c21,
+    ]),
+    FqRepr([
+        0x41b6daecf2e8fedb,
+        0x2ee7f8dc099040a8,
+        0x79833fd221351adc,
+        0x195536fbe3ce50b8,
+        0x5caf4fe2a21529c4,
+        0x08cc03fdefe0ff13,
+    ]),
+    FqRepr([
+        0x99b23ab13633a5f0,
+        0x203f6326c95a8072,
+        0x76505c3d3ad5544e,
+        0x74a7d0d4afadb7bd,
+        0x2211e11db8f0a6a0,
+        0x16603fca40634b6a,
+    ]),
+    FqRepr([
+        0xc961f8855fe9d6f2,
+        0x47a87ac2460f415e,
+        0x5231413c4d634f37,
+        0xe75bb8ca2be184cb,
+        0xb2c977d027796b3c,
+        0x04ab0b9bcfac1bbc,
+    ]),
+    FqRepr([
+        0xa15e4ca31870fb29,
+        0x42f64550fedfe935,
+        0xfd038da6c26c8426,
+        0x170a05bfe3bdd81f,
+        0xde9926bd2ca6c674,
+        0x0987c8d5333ab86f,
+    ]),
+    FqRepr([
+        0x60370e577bdba587,
+        0x69d65201c78607a3,
+        0x1e8b6e6a1f20cabe,
+        0x8f3abd16679dc26c,
+        0xe88c9e221e4da1bb,
+        0x09fc4018bd96684b,
+    ]),
+    FqRepr([
+        0x2bafaaebca731c30,
+        0x9b3f7055dd4eba6f,
+        0x06985e7ed1e4d43b,
+        0xc42a0ca7915af6fe,
+        0x223abde7ada14a23,
+        0x0e1bba7a1186bdb5,
+    ]),
+    FqRepr([
+        0xe813711ad011c132,
+        0x31bf3a5cce3fbafc,
+        0xd1183e416389e610,
+        0xcd2fcbcb6caf493f,
+        0x0dfd0b8f1d43fb93,
+        0x19713e47937cd1be,
+    ]),
+    FqRepr([
+        0xce07c8a4d0074d8e,
+        0x49d9cdf41b44d606,
+        0x2e6bfe7f911f6432,
+        0x523559b8aaf0c246,
+        0xb918c143fed2edcc,
+        0x18b46a908f36f6de,
+    ]),
+    FqRepr([
+        0x0d4c04f00b971ef8,
+        0x06c851c1919211f2,
+        0xc02710e807b4633f,
+        0x7aa7b12a3426b08e,
+        0xd155096004f53f44,
+        0x0b182cac101b9399,
+    ]),
+    FqRepr([
+        0x42d9d3f5db980133,
+        0xc6cf90ad1c232a64,
+        0x13e6632d3c40659c,
+        0x757b3b080d4c1580,
+        0x72fc00ae7be315dc,
+        0x0245a394ad1eca9b,
+    ]),
+    FqRepr([
+        0x866b1e715475224b,
+        0x6ba1049b6579afb7,
+        0xd9ab0f5d396a7ce4,
+        0x5e673d81d7e86568,
+        0x02a159f748c4a3fc,
+        0x05c129645e44cf11,
+    ]),
+    FqRepr([
+        0x04b456be69c8b604,
+        0xb665027efec01c77,
+        0x57add4fa95af01b2,
+        0xcb181d8f84965a39,
+        0x4ea50b3b42df2eb5,
+        0x15e6be4e990f03ce,
+    ]),
+];
+
+/// Coefficients of the 11-isogeny y-coordinate denominator polynomial, in ascending degree.
+const ISO11_Y_DEN: [FqRepr; 16] = [
+    FqRepr([
+        0x01479253b03663c1,
+        0x07f3688ef60c206d,
+        0xeec3232b5be72e7a,
+        0x601a6de578980be6,
+        0x52181140fad0eae9,
+        0x16112c4c3a9c98b2,
+    ]),
+    FqRepr([
+        0x32f6102c2e49a03d,
+        0x78a4260763529e35,
+        0xa4a10356f453e01f,
+        0x85c84ff731c4d59c,
+        0x1a0cbd6c43c348b8,
+        0x1962d75c2381201e,
+    ]),
+    FqRepr([
+        0x1e2538b53dbf67f2,
+        0xa6757cd636f96f89,
+        0x0c35a5dd279cd2ec,
+        0x78c4855551ae7f31,
+        0x6faaae7d6e8eb157,
+        0x058df3306640da27,
+    ]),
+    FqRepr([
+        0xa8d26d98445f5416,
+        0x727364f2c28297ad,
+        0x123da489e726af41,
+        0xd115c5dbddbcd30e,
+        0xf20d23bf89edb4d1,
+        0x16b7d288798e5395,
+    ]),
+    FqRepr([
+        0xda39142311a5001d,
+        0xa20b15dc0fd2eded,
+        0x542eda0fc9dec916,
+        0xc6d19c9f0f69bbb0,
+        0xb00cc912f8228ddc,
+        0x0be0e079545f43e4,
+    ]),
+    FqRepr([
+        0x02c6477faaf9b7ac,
+        0x49f38db9dfa9cce2,
+        0xc5ecd87b6f0f5a64,
+        0xb70152c65550d881,
+        0x9fb266eaac783182,
+        0x08d9e5297186db2d,
+    ]),
+    FqRepr([
+        0x3d1a1399126a775c,
+        0xd5fa9c01a58b1fb9,
+        0x5dd365bc400a0051,
+        0x5eecfdfa8d0cf8ef,
+        0xc3ba8734ace9824b,
+        0x166007c08a99db2f,
+    ]),
+    FqRepr([
+        0x60ee415a15812ed9,
+        0xb920f5b00801dee4,
+        0xfeb34fd206357132,
+        0xe5a4375efa1f4fd7,
+        0x03bcddfabba6ff6e,
+        0x16a3ef08be3ea7ea,
+    ]),
+    FqRepr([
+        0x6b233d9d55535d4a,
+        0x52cfe2f7bb924883,
+        0xabc5750c4bf39b48,
+        0xf9fb0ce4c6af5920,
+        0x1a1be54fd1d74cc4,
+        0x1866c8ed336c6123,
+    ]),
+    FqRepr([
+        0x346ef48bb8913f55,
+        0xc7385ea3d529b35e,
+        0x5308592e7ea7d4fb,
+        0x3216f763e13d87bb,
+        0xea820597d94a8490,
+        0x167a55cda70a6e1c,
+    ]),
+    FqRepr([
+        0x00f8b49cba8f6aa8,
+        0x71a5c29f4f830604,
+        0x0e591b36e636a5c8,
+        0x9c6dd039bb61a629,
+        0x48f010a01ad2911d,
+        0x04d2f259eea405bd,
+    ]),
+    FqRepr([
+        0x9684b529e2561092,
+        0x16f968986f7ebbea,
+        0x8c0f9a88cea79135,
+        0x7f94ff8aefce42d2,
+        0xf5852c1e48c50c47,
+        0x0accbb67481d033f,
+    ]),
+    FqRepr([
+        0x1e99b138573345cc,
+        0x93000763e3b90ac1,
+        0x7d5ceef9a00d9b86,
+        0x543346d98adf0226,
+        0xc3613144b45f1496,
+        0x0ad6b9514c767fe3,
+    ]),
+    FqRepr([
+        0xd1fadc1326ed06f7,
+        0x420517bd8714cc80,
+        0xcb748df27942480e,
+        0xbf565b94e72927c1,
+        0x628bdd0d53cd76f2,
+        0x02660400eb2e4f3b,
+    ]),
+    FqRepr([
+        0x4415473a1d634b8f,
+        0x5ca2f570f1349780,
+        0x324efcd6356caa20,
+        0x71c40f65e273b853,
+        0x6b24255e0d7819c1,
+        0x0e0fa1d816ddc03e,
+    ]),
+    FqRepr([
+        0x0000000000000001,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]),
+];
+
+impl MapToCurve for G1 {
+    type Field = Fq;
+    const SUITE_ID: &'static [u8] = b"BLS12381G1_XMD:SHA-256_SSWU_RO_";
+
+    fn map_to_curve(u: &Fq) -> G1 {
+        let (x, y) = simplified_swu(
+            u,
+            &fq(&SSWU_A_G1),
+            &fq(&SSWU_B_G1),
+            &Fq::from_repr(FqRepr::from(SSWU_Z_G1)).unwrap(),
+        );
+
+        let coeffs = |reprs: &[FqRepr]| reprs.iter().map(fq).collect::<Vec<_>>();
+        match isogeny_map(
+            &x,
+            &y,
+            &coeffs(&ISO11_X_NUM),
+            &coeffs(&ISO11_X_DEN),
+            &coeffs(&ISO11_Y_NUM),
+            &coeffs(&ISO11_Y_DEN),
+        ) {
+            Some((x, y)) => G1Affine {
+                x,
+                y,
+                infinity: false,
+            }.into_projective(),
+            None => G1::zero(),
+        }
+    }
+
+    fn clear_cofactor(&self) -> G1 {
+        // The result is not yet in the prime order subgroup, so this cannot
+        // use the GLV method.
+        self.mul_double_and_add(FrRepr::from(H_EFF_G1))
+    }
+}
+
+/// The non-square Z = -(2 + u) used by the G2 suite.
+fn sswu_z_g2() -> Fq2 {
+    let mut z = Fq2 {
+        c0: Fq::from_repr(FqRepr::from(2)).unwrap(),
+        c1: Fq::one(),
+    };
+    z.negate();
+    z
+}
+
+/// The effective cofactor h_eff of the G2 suite, in little-endian limbs.
+#[cfg(test)]
+const H_EFF_G2: [u64; 10] = [
+    0xe8020005aaa95551,
+    0x59894c0adebbf6b4,
+    0xe954cbc06689f6a3,
+    0x2ec0ec69d7477c1a,
+    0x6d82bf015d1212b0,
+    0x329c2f178731db95,
+    0x9986ff031508ffe1,
+    0x88e2a8e9145ad768,
+    0x584c6a0ea91b3528,
+    0x0bc69f08f2ee75b3,
+];
+
+/// Coefficients of the 3-isogeny x-coordinate numerator polynomial, in ascending degree.
+const ISO3_X_NUM: [[FqRepr; 2]; 4] = [
+    [
+        FqRepr([
+            0x6238aaaaaaaa97d6,
+            0x5c2638e343d9c71c,
+            0x88b58423c50ae15d,
+            0x32c52d39fd3a042a,
+            0xbb5b7a9a47d7ed85,
+            0x05c759507e8e333e,
+        ]),
+        FqRepr([
+            0x6238aaaaaaaa97d6,
+            0x5c2638e343d9c71c,
+            0x88b58423c50ae15d,
+            0x32c52d39fd3a042a,
+            0xbb5b7a9a47d7ed85,
+            0x05c759507e8e333e,
+        ]),
+    ],
+    [
+        FqRepr([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ]),
+        FqRepr([
+            0x26a9ffffffffc71a,
+            0x1472aaa9cb8d5555,
+            0x9a208c6b4f20a418,
+            0x984f87adf7ae0c7f,
+            0x32126fced787c88f,
+            0x11560bf17baa99bc,
+        ]),
+    ],
+    [
+        FqRepr([
+            0x26a9ffffffffc71e,
+            0x1472aaa9cb8d5555,
+            0x9a208c6b4f20a418,
+            0x984f87adf7ae0c7f,
+            0x32126fced787c88f,
+            0x11560bf17baa99bc,
+        ]),
+        FqRepr([
+            0x9354ffffffffe38d,
+            0x0a395554e5c6aaaa,
+            0xcd104635a790520c,
+            0xcc27c3d6fbd7063f,
+            0x190937e76bc3e447,
+            0x08ab05f8bdd54cde,
+        ]),
+    ],
+    [
+        FqRepr([
+            0x88e2aaaaaaaa5ed1,
+            0x7098e38d0f671c71,
+            0x22d6108f142b8575,
+            0xcb14b4e7f4e810aa,
+            0xed6dea691f5fb614,
+            0x171d6541fa38ccfa,
+        ]),
+        FqRepr([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ]),
+    ],
+];
+
+/// Coefficients of the 3-isogeny x-coordinate denominator polynomial, in ascending degree.
+const ISO3_X_DEN: [[FqRepr; 2]; 3] = [
+    [
+        FqRepr([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ]),
+        FqRepr([
+            0xb9feffffffffaa63,
+            0x1eabfffeb153ffff,
+            0x6730d2a0f6b0f624,
+            0x64774b84f38512bf,
+            0x4b1ba7b6434bacd7,
+            0x1a0111ea397fe69a,
+        ]),
+    ],
+    [
+        FqRepr([
+            0x000000000000000c,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ]),
+        FqRepr([
+            0xb9feffffffffaa9f,
+            0x1eabfffeb153ffff,
+            0x6730d2a0f6b0f624,
+            0x64774b84f38512bf,
+            0x4b1ba7b6434bacd7,
+            0x1a0111ea397fe69a,
+        ]),
+    ],
+    [
+        FqRepr([
+            0x0000000000000001,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ]),
+        FqRepr([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ]),
+    ],
+];
+
+/// Coefficients of the 3-isogeny y-coordinate numerator polynomial, in ascending degree.
+const ISO3_Y_NUM: [[FqRepr; 2]; 4] = [
+    [
+        FqRepr([
+            0x12cfc71c71c6d706,
+            0xfc8c25ebf8c92f68,
+            0xf54439d87d27e500,
+            0x0f7da5d4a07f649b,
+            0x59a4c18b076d1193,
+            0x1530477c7ab4113b,
+        ]),
+        FqRepr([
+            0x12cfc71c71c6d706,
+            0xfc8c25ebf8c92f68,
+            0xf54439d87d27e500,
+            0x0f7da5d4a07f649b,
+            0x59a4c18b076d1193,
+            0x1530477c7ab4113b,
+        ]),
+    ],
+    [
+        FqRepr([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ]),
+        FqRepr([
+            0x6238aaaaaaaa97be,
+            0x5c2638e343d9c71c,
+            0x88b58423c50ae15d,
+            0x32c52d39fd3a042a,
+            0xbb5b7a9a47d7ed85,
+            0x05c759507e8e333e,
+        ]),
+    ],
+    [
+        FqRepr([
+            0x26a9ffffffffc71c,
+            0x1472aaa9cb8d5555,
+            0x9a208c6b4f20a418,
+            0x984f87adf7ae0c7f,
+            0x32126fced787c88f,
+            0x11560bf17baa99bc,
+        ]),
+        FqRepr([
+            0x9354ffffffffe38f,
+            0x0a395554e5c6aaaa,
+            0xcd104635a790520c,
+            0xcc27c3d6fbd7063f,
+            0x190937e76bc3e447,
+            0x08ab05f8bdd54cde,
+        ]),
+    ],
+    [
+        FqRepr([
+            0xe1b371c71c718b10,
+            0x4e79097a56dc4bd9,
+            0xb0e977c69aa27452,
+            0x761b0f37a1e26286,
+            0xfbf7043de3811ad0,
+            0x124c9ad43b6cf79b,
+        ]),
+        FqRepr([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ]),
+    ],
+];
+
+/// Coefficients of the 3-isogeny y-coordinate denominator polynomial, in ascending degree.
+const ISO3_Y_DEN: [[FqRepr; 2]; 4] = [
+    [
+        FqRepr([
+            0xb9feffffffffa8fb,
+            0x1eabfffeb153ffff,
+            0x6730d2a0f6b0f624,
+            0x64774b84f38512bf,
+            0x4b1ba7b6434bacd7,
+            0x1a0111ea397fe69a,
+        ]),
+        FqRepr([
+            0xb9feffffffffa8fb,
+            0x1eabfffeb153ffff,
+            0x6730d2a0f6b0f624,
+            0x64774b84f38512bf,
+            0x4b1ba7b6434bacd7,
+            0x1a0111ea397fe69a,
+        ]),
+    ],
+    [
+        FqRepr([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ]),
+        FqRepr([
+            0xb9feffffffffa9d3,
+            0x1eabfffeb153ffff,
+            0x6730d2a0f6b0f624,
+            0x64774b84f38512bf,
+            0x4b1ba7b6434bacd7,
+            0x1a0111ea397fe69a,
+        ]),
+    ],
+    [
+        FqRepr([
+            0x0000000000000012,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ]),
+        FqRepr([
+            0xb9feffffffffaa99,
+            0x1eabfffeb153ffff,
+            0x6730d2a0f6b0f624,
+            0x64774b84f38512bf,
+            0x4b1ba7b6434bacd7,
+            0x1a0111ea397fe69a,
+        ]),
+    ],
+    [
+        FqRepr([
+            0x0000000000000001,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ]),
+        FqRepr([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ]),
+    ],
+];
+
+impl MapToCurve for G2 {
+    type Field = Fq2;
+    const SUITE_ID: &'static [u8] = b"BLS12381G2_XMD:SHA-256_SSWU_RO_";
+
+    fn map_to_curve(u: &Fq2) -> G2 {
+        // E' : y^2 = x^3 + 240u * x + 1012(1 + u)
+        let a = Fq2 {
+            c0: Fq::zero(),
+            c1: Fq::from_repr(FqRepr::from(240)).unwrap(),
+        };
+        let b = Fq2 {
+            c0: Fq::from_repr(FqRepr::from(1012)).unwrap(),
+            c1: Fq::from_repr(FqRepr::from(1012)).unwrap(),
+        };
+        let (x, y) = simplified_swu(u, &a, &b, &sswu_z_g2());
+
+        let coeffs = |reprs: &[[FqRepr; 2]]| reprs.iter().map(fq2).collect::<Vec<_>>();
+        match isogeny_map(
+            &x,
+            &y,
+            &coeffs(&ISO3_X_NUM),
+            &coeffs(&ISO3_X_DEN),
+            &coeffs(&ISO3_Y_NUM),
+            &coeffs(&ISO3_Y_DEN),
+        ) {
+            Some((x, y)) => G2Affine {
+                x,
+                y,
+                infinity: false,
+            }.into_projective(),
+            None => G2::zero(),
+        }
+    }
+
+    fn clear_cofactor(&self) -> G2 {
+        G2::clear_cofactor(self)
+    }
+}
+
+#[cfg(test)]
+fn test_messages() -> Vec<Vec<u8>> {
+    vec![
+        b"".to_vec(),
+        b"abc".to_vec(),
+        b"abcdef0123456789".to_vec(),
+        format!("q128_{}", "q".repeat(128)).into_bytes(),
+        format!("a512_{}", "a".repeat(512)).into_bytes(),
+    ]
+}
+
+#[cfg(test)]
+fn check_vectors<G: ::hash_to_curve::HashToCurve>(
+    hash: fn(&[u8], &[u8]) -> G,
+    dst: &[u8],
+    expected: &[[&str; 2]],
+) {
+    for (msg, coords) in test_messages().iter().zip(expected.iter()) {
+        let p = hash(msg, dst);
+        assert!(::hash_to_curve::mul_by_limbs(&p, G::Scalar::char().as_ref()).is_zero());
+        let p = p.into_affine();
+
+        let expected = coords.concat();
+        assert_eq!(::hex::encode(p.into_uncompressed().as_ref()), expected);
+    }
+}
+
+#[test]
+fn test_g1_hash_to_curve() {
+    use hash_to_curve::HashToCurve;
+
+    // RFC 9380, appendix J.9.1: the (x, y) coordinates of P
+    check_vectors(
+        G1::hash_to_curve,
+        b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_",
+        &[
+            [
+                "052926add2207b76ca4fa57a8734416c8dc95e24501772c814278700eed6d1e4e8cf62d9c09db0fac349612b759e79a1",
+                "08ba738453bfed09cb546dbb0783dbb3a5f1f566ed67bb6be0e8c67e2e81a4cc68ee29813bb7994998f3eae0c9c6a265",
+            ],
+            [
+                "03567bc5ef9c690c2ab2ecdf6a96ef1c139cc0b2f284dca0a9a7943388a49a3aee664ba5379a7655d3c68900be2f6903",
+                "0b9c15f3fe6e5cf4211f346271d7b01c8f3b28be689c8429c85b67af215533311f0b8dfaaa154fa6b88176c229f2885d",
+            ],
+            [
+                "11e0b079dea29a68f0383ee94fed1b940995272407e3bb916bbf268c263ddd57a6a27200a784cbc248e84f357ce82d98",
+                "03a87ae2caf14e8ee52e51fa2ed8eefe80f02457004ba4d486d6aa1f517c0889501dc7413753f9599b099ebcbbd2d709",
+            ],
+            [
+                "15f68eaa693b95ccb85215dc65fa81038d69629f70aeee0d0f677cf22285e7bf58d7cb86eefe8f2e9bc3f8cb84fac488",
+                "1807a1d50c29f430b8cafc4f8638dfeeadf51211e1602a5f184443076715f91bb90a48ba1e370edce6ae1062f5e6dd38",
+            ],
+            [
+                "082aabae8b7dedb0e78aeb619ad3bfd9277a2f77ba7fad20ef6aabdc6c31d19ba5a6d12283553294c1825c4b3ca2dcfe",
+                "05b84ae5a942248eea39e1d91030458c40153f3b654ab7872d779ad1e942856a20c438e8d99bc8abfbf74729ce1f7ac8",
+            ],
+        ],
+    );
+}
+
+#[test]
+fn test_g1_encode_to_curve() {
+    use hash_to_curve::HashToCurve;
+
+    // RFC 9380, appendix J.9.2
+    check_vectors(
+        G1::encode_to_curve,
+        b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_NU_",
+        &[
+            [
+                "184bb665c37ff561a89ec2122dd343f20e0f4cbcaec84e3c3052ea81d1834e192c426074b02ed3dca4e7676ce4ce48ba",
+                "04407b8d35af4dacc809927071fc0405218f1401a6d15af775810e4e460064bcc9468beeba82fdc751be70476c888bf3",
+            ],
+            [
+                "009769f3ab59bfd551d53a5f846b9984c59b97d6842b20a2c565baa167945e3d026a3755b6345df8ec7e6acb6868ae6d",
+                "1532c00cf61aa3d0ce3e5aa20c3b531a2abd2c770a790a2613818303c6b830ffc0ecf6c357af3317b9575c567f11cd2c",
+            ],
+            [
+                "1974dbb8e6b5d20b84df7e625e2fbfecb2cdb5f77d5eae5fb2955e5ce7313cae8364bc2fff520a6c25619739c6bdcb6a",
+                "15f9897e11c6441eaa676de141c8d83c37aab8667173cbe1dfd6de74d11861b961dccebcd9d289ac633455dfcc7013a3",
+            ],
+            [
+                "0a7a047c4a8397b3446450642c2ac64d7239b61872c9ae7a59707a8f4f950f101e766afe58223b3bff3a19a7f754027c",
+                "1383aebba1e4327ccff7cf9912bda0dbc77de048b71ef8c8a81111d71dc33c5e3aa6edee9cf6f5fe525d50cc50b77cc9",
+            ],
+            [
+                "0e7a16a975904f131682edbb03d9560d3e48214c9986bd50417a77108d13dc957500edf96462a3d01e62dc6cd468ef11",
+                "0ae89e677711d05c30a48d6d75e76ca9fb70fe06c6dd6ff988683d89ccde29ac7d46c53bb97a59b1901abf1db66052db",
+            ],
+        ],
+    );
+}
+
+#[test]
+fn test_g2_clear_cofactor() {
+    use ff::Rand;
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    let mut rng = XorShiftRng::seed_from_u64(0x5dbe62598d313d76);
+
+    for _ in 0..10 {
+        // The outputs of map_to_curve are almost never in the subgroup.
+        let p = G2::map_to_curve(&Fq2::rand(&mut rng));
+        assert_eq!(
+            G2::clear_cofactor(&p),
+            ::hash_to_curve::mul_by_limbs(&p, &H_EFF_G2)
+        );
+    }
+}
+
+#[test]
+fn test_g2_hash_to_curve() {
+    use hash_to_curve::HashToCurve;
+
+    // RFC 9380, appendix J.10.1. Encoded points hold x.c1 || x.c0 and
+    // y.c1 || y.c0.
+    check_vectors(
+        G2::hash_to_curve,
+        b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_",
+        &[
+            [
+                "05cb8437535e20ecffaef7752baddf98034139c38452458baeefab379ba13dff5bf5dd71b72418717047f5b0f37da03d0141ebfbdca40eb85b87142e130ab689c673cf60f1a3e98d69335266f30d9b8d4ac44c1038e9dcdd5393faf5c41fb78a",
+                "12424ac32561493f3fe3c260708a12b7c620e7be00099a974e259ddc7d1f6395c3c811cdd19f1e8dbf3e9ecfdcbab8d60503921d7f6a12805e72940b963c0cf3471c7b2a524950ca195d11062ee75ec076daf2d4bc358c4b190c0c98064fdd92",
+            ],
+            [
+                "139cddbccdc5e91b9623efd38c49f81a6f83f175e80b06fc374de9eb4b41dfe4ca3a230ed250fbe3a2acf73a41177fd802c2d18e033b960562aae3cab37a27ce00d80ccd5ba4b7fe0e7a210245129dbec7780ccc7954725f4168aff2787776e6",
+                "00aa65dae3c8d732d10ecd2c50f8a1baf3001578f71c694e03866e9f3d49ac1e1ce70dd94a733534f106d4cec0eddd161787327b68159716a37440985269cf584bcb1e621d3a7202be6ea05c4cfe244aeb197642555a0645fb87bf7466b2ba48",
+            ],
+            [
+                "190d119345b94fbd15497bcba94ecf7db2cbfd1e1fe7da034d26cbba169fb3968288b3fafb265f9ebd380512a71c3f2c121982811d2491fde9ba7ed31ef9ca474f0e1501297f68c298e9f4c0028add35aea8bb83d53c08cfc007c1e005723cd0",
+                "0bb5e7572275c567462d91807de765611490205a941a5a6af3b1691bfe596c31225d3aabdf15faff860cb4ef17c7c3be05571a0f8d3c08d094576981f4a3b8eda0a8e771fcdcc8ecceaf1356a6acf17574518acb506e435b639353c2e14827c8",
+            ],
+            [
+                "0934aba516a52d8ae479939a91998299c76d39cc0c035cd18813bec433f587e2d7a4fef038260eef0cef4d02aae3eb9119a84dd7248a1066f737cc34502ee5555bd3c19f2ecdb3c7d9e24dc65d4e25e50d83f0f77105e955d78f4762d33c17da",
+                "09bcccfa036b4847c9950780733633f13619994394c23ff0b32fa6b795844f4a0673e20282d07bc69641cee04f5e566214f81cd421617428bc3b9fe25afbb751d934a00493524bc4e065635b0555084dd54679df1536101b2c979c0152d09192",
+            ],
+            [
+                "11fca2ff525572795a801eed17eb12785887c7b63fb77a42be46ce4a34131d71f7a73e95fee3f812aea3de78b4d0156901a6ba2f9a11fa5598b2d8ace0fbe0a0eacb65deceb476fbbcb64fd24557c2f4b18ecfc5663e54ae16a84f5ab7f62534",
+                "03a47f8e6d1763ba0cad63d6114c0accbef65707825a511b251a660a9b3994249ae4e63fac38b23da0c398689ee2ab520b6798718c8aed24bc19cb27f866f1c9effcdbf92397ad6448b5c9db90d2b9da6cbabf48adc1adf59a1a28344e79d57e",
+            ],
+        ],
+    );
+}
+
+#[test]
+fn test_g2_encode_to_curve() {
+    use hash_to_curve::HashToCurve;
+
+    // RFC 9380, appendix J.10.2
+    check_vectors(
+        G2::encode_to_curve,
+        b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_NU_",
+        &[
+            [
+                "126b855e9e69b1f691f816e48ac6977664d24d99f8724868a184186469ddfd4617367e94527d4b74fc86413483afb35b00e7f4568a82b4b7dc1f14c6aaa055edf51502319c723c4dc2688c7fe5944c213f510328082396515734b6612c4e7bb7",
+                "1498aadcf7ae2b345243e281ae076df6de84455d766ab6fcdaad71fab60abb2e8b980a440043cd305db09d283c895e3d0caead0fd7b6176c01436833c79d305c78be307da5f6af6c133c47311def6ff1e0babf57a0fb5539fce7ee12407b0a42",
+            ],
+            [
+                "0296238ea82c6d4adb3c838ee3cb2346049c90b96d602d7bb1b469b905c9228be25c627bffee872def773d5b2a2eb57d108ed59fd9fae381abfd1d6bce2fd2fa220990f0f837fa30e0f27914ed6e1454db0d1ee957b219f61da6ff8be0d6441f",
+                "153606c417e59fb331b7ae6bce4fbf7c5190c33ce9402b5ebe2b70e44fca614f3f1382a3625ed5493843d0b0a652fc3f033f90f6057aadacae7963b0a0b379dd46750c1c94a6357c99b65f63b79e321ff50fe3053330911c56b6ceea08fee656",
+            ],
+            [
+                "0da75be60fb6aa0e9e3143e40c42796edf15685cafe0279afd2a67c3dff1c82341f17effd402e4f1af240ea90f4b659b038af300ef34c7759a6caaa4e69363cafeed218a1f207e93b2c70d91a1263d375d6730bd6b6509dcac3ba5b567e85bf3",
+                "0492f4fed741b073e5a82580f7c663f9b79e036b70ab3e51162359cec4e77c78086fe879b65ca7a47d34374c8315ac5e19b148cbdf163cf0894f29660d2e7bfb2b68e37d54cc83fd4e6e62c020eaa48709302ef8e746736c0e19342cc1ce3df4",
+            ],
+            [
+                "12c8c05c1d5fc7bfa847f4d7d81e294e66b9a78bc9953990c358945e1f042eedafce608b67fdd3ab0cb2e6e263b9b1ad0c5ae723be00e6c3f0efe184fdc0702b64588fe77dda152ab13099a3bacd3876767fa7bbad6d6fd90b3642e902b208f9",
+                "11c624c56dbe154d759d021eec60fab3d8b852395a89de497e48504366feedd4662d023af447d66926a28076813dd64604e77ddb3ede41b5ec4396b7421dd916efc68a358a0d7425bddd253547f2fb4830522358491827265dfc5bcc1928a569",
+            ],
+            [
+                "1565c2f625032d232f13121d3cfb476f45275c303a037faa255f9da62000c2c864ea881e2bcddd111edc4a3c0da3e88d0ea4e7c33d43e17cc516a72f76437c4bf81d8f4eac69ac355d3bf9b71b8138d55dc10fd458be115afa798b55dac34be1",
+                "0f8991d2a1ad662e7b6f58ab787947f1fa607fce12dde171bc17903b012091b657e15333e11701edcf5b63ba2a561247043b6f5fe4e52c839148dc66f2b3751e69a0f6ebb3d056d6465d50d4108543ecd956e10fa1640dfd9bc0030cc2558d28",
+            ],
+        ],
+    );
+}
+
+#[test]
+fn test_sgn0() {
+    use ff::PrimeFieldRepr;
+
+    // (q - 1) / 2 is odd, and (q + 1) / 2 = -((q - 1) / 2) is even
+    let mut half = Fq::char();
+    half.div2();
+    let half = Fq::from_repr(half).unwrap();
+    let mut neg_half = half;
+    neg_half.negate();
+
+    assert!(!Fq::zero().sgn0());
+    assert!(Fq::one().sgn0());
+    assert!(half.sgn0());
+    assert!(!neg_half.sgn0());
+
+    // The sign of an Fq2 element is that of c0, unless c0 is zero
+    assert!(!Fq2::zero().sgn0());
+    assert!(Fq2::one().sgn0());
+    assert!(Fq2 { c0: half, c1: Fq::zero() }.sgn0());
+    assert!(!Fq2 { c0: neg_half, c1: Fq::one() }.sgn0());
+    assert!(Fq2 { c0: Fq::zero(), c1: Fq::one() }.sgn0());
+    assert!(!Fq2 { c0: Fq::zero(), c1: neg_half }.sgn0());
+}
@@ -446,6 +446,21 @@ mod tests {
         test_roundtrip(&f.into_repr());
     }
 
+    #[test]
+    fn serde_g2_prepared() {
+        let mut rng = XorShiftRng::seed_from_u64(0x5dbe62598d313d76);
+        let g: G2 = rng.gen();
+        test_roundtrip(&g.into_affine().prepare());
+        test_roundtrip(&G2Affine::zero().prepare());
+    }
+
+    #[test]
+    fn serde_gt() {
+        let mut rng = XorShiftRng::seed_from_u64(0x5dbe62598d313d76);
+        let g: super::super::Gt = rng.gen();
+        test_roundtrip(&g);
+    }
+
     #[test]
     fn serde_fq12() {
         let mut rng = XorShiftRng::seed_from_u64(0x5dbe62598d313d76);
@@ -20,7 +20,7 @@ fn test_pairing_result_against_relic() {
     0F41E58663BF08CF 068672CBD01A7EC7 3BACA4D72CA93544 DEFF686BFD6DF543 D48EAA24AFE47E1E FDE449383B676631
     */
 
-    assert_eq!(Bls12::pairing(G1::one(), G2::one()), Fq12 {
+    assert_eq!(Bls12::pairing(G1::one(), G2::one()).into_fqk(), Fq12 {
         c0: Fq6 {
             c0: Fq2 {
                 c0: Fq::from_str("2819105605953691245277803056322684086884703000473961065716485506033588504203831029066448642358042597501014294104502").unwrap(),
@@ -609,3 +609,68 @@ fn test_g2_compressed_invalid_vectors() {
         }
     }
 }
+
+#[test]
+fn test_g2_prepared_encoding() {
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    let mut rng = XorShiftRng::seed_from_u64(0x5dbe62598d313d76);
+
+    let zero = G2Affine::zero();
+    let bytes = zero.prepare().into_bytes();
+    assert_eq!(bytes, vec![G2Prepared::ENCODING_VERSION, 1, 0, 0, 0, 0]);
+    assert_eq!(G2Prepared::from_bytes(&bytes, &zero).unwrap(), zero.prepare());
+
+    for _ in 0..10 {
+        let p = G1::rand(&mut rng).into_affine();
+        let q = G2::rand(&mut rng).into_affine();
+        let other = G2::rand(&mut rng).into_affine();
+        let mut minus_q = q;
+        minus_q.negate();
+        let prepared = q.prepare();
+        let bytes = prepared.into_bytes();
+
+        let decoded = G2Prepared::from_bytes_unchecked(&bytes).unwrap();
+        assert_eq!(decoded, prepared);
+        assert!(decoded.is_prepared_from(&q));
+        assert!(!decoded.is_prepared_from(&other));
+        assert!(!decoded.is_prepared_from(&minus_q));
+        assert!(!decoded.is_prepared_from(&zero));
+        assert_eq!(G2Prepared::from_bytes(&bytes, &q).unwrap(), prepared);
+        assert!(G2Prepared::from_bytes(&bytes, &other).is_err());
+        assert_eq!(
+            Bls12::miller_loop(&[(&p.prepare(), &decoded)]),
+            Bls12::miller_loop(&[(&p.prepare(), &prepared)])
+        );
+
+        // A different version, a truncated encoding, trailing bytes, the flag
+        // of the point at infinity and a wrong number of lines.
+        let mut version = bytes.clone();
+        version[0] += 1;
+        let mut infinity = bytes.clone();
+        infinity[1] = 1;
+        let mut flag = bytes.clone();
+        flag[1] = 2;
+        let mut len = bytes.clone();
+        len[5] -= 1;
+        let mut extra = bytes.clone();
+        extra.push(0);
+        for bytes in &[version, bytes[..bytes.len() - 1].to_vec(), extra, infinity, flag, len] {
+            match G2Prepared::from_bytes_unchecked(bytes) {
+                Err(GroupDecodingError::UnexpectedInformation) => {}
+                _ => panic!("invalid encoding was decoded"),
+            }
+        }
+
+        // A coefficient that is not reduced.
+        let mut unreduced = bytes.clone();
+        for b in &mut unreduced[6..6 + ::std::mem::size_of::<FqRepr>()] {
+            *b = 0xff;
+        }
+        match G2Prepared::from_bytes_unchecked(&unreduced) {
+            Err(GroupDecodingError::CoordinateDecodingError(..)) => {}
+            _ => panic!("unreduced coefficient was decoded"),
+        }
+    }
+}
@@ -0,0 +1,718 @@
+//! Shared implementation of the BN family of pairing-friendly curves.
+//!
+//! A BN curve is described by its parameter u, from which the field moduli
+//! q = 36u^4 + 36u^3 + 24u^2 + 6u + 1 and r = 36u^4 + 36u^3 + 18u^2 + 6u + 1,
+//! the G2 cofactor 2q - r and the loop count 6u + 2 of the optimal ate
+//! pairing follow. Instantiating a curve of the family requires:
+//!
+//! * an `fq` module deriving the base field `Fq` from its modulus, and
+//!   defining the generators `G1_GENERATOR_X/Y`, `G2_GENERATOR_X/Y_C0/C1`,
+//!   the coefficients `B_COEFF` and `B_COEFF_FQ2`, the twist constant
+//!   `XI_TO_Q_MINUS_1_OVER_2` and the Frobenius coefficients of the
+//!   extension tower;
+//! * an `fr` module deriving the scalar field `Fr`;
+//! * an `fq2` module providing the quadratic extension `Fq2`, whose
+//!   `mul_by_nonresidue` and `mul_by_xi` multiply by the nonresidue ξ used
+//!   to build Fq6;
+//! * an implementation of `BnParameters` for the engine type.
+//!
+//! The Frobenius coefficients and the twist constant are powers of ξ that
+//! cannot be computed in constant expressions, so they are kept as constants
+//! of the `fq` module. `BnParameters::frobenius_coeff` derives them from u,
+//! which is used to check the constants of each curve.
+//!
+//! The remaining extension fields, the groups and the engine are then
+//! implemented by the `bn_fq6_impl!`, `bn_fq12_impl!`, `bn_g1_impl!`,
+//! `bn_g2_impl!` and `bn_engine_impl!` macros. See the `bn256` module for an
+//! example.
+
+#[macro_use]
+mod ec;
+#[macro_use]
+mod fq12;
+#[macro_use]
+mod fq6;
+
+use ff::Field;
+
+pub use bls12::TwistType;
+
+/// The parameters of a curve of the BN family.
+pub trait BnParameters {
+    /// The absolute value of the parameter u, as little-endian limbs.
+    const U: &'static [u64];
+    /// Whether the parameter u is negative.
+    const U_IS_NEGATIVE: bool;
+    /// The type of the twist E' that G2 is defined on.
+    const TWIST_TYPE: TwistType;
+
+    /// Returns the base field modulus q = 36u^4 + 36u^3 + 24u^2 + 6u + 1.
+    fn base_field_modulus() -> Vec<u64> {
+        eval_poly(Self::U, Self::U_IS_NEGATIVE, &[1, 6, 24, 36, 36])
+    }
+
+    /// Returns the group order r = 36u^4 + 36u^3 + 18u^2 + 6u + 1.
+    fn scalar_field_modulus() -> Vec<u64> {
+        eval_poly(Self::U, Self::U_IS_NEGATIVE, &[1, 6, 18, 36, 36])
+    }
+
+    /// Returns the cofactor 2q - r = 36u^4 + 36u^3 + 30u^2 + 6u + 1 of G2
+    /// in E'(Fq2).
+    fn g2_cofactor() -> Vec<u64> {
+        eval_poly(Self::U, Self::U_IS_NEGATIVE, &[1, 6, 30, 36, 36])
+    }
+
+    /// Returns the absolute value of the ate loop count 6u + 2, which has
+    /// the sign of u.
+    fn ate_loop_count() -> Vec<u64> {
+        let six_u = mul(Self::U, &[6]);
+        if Self::U_IS_NEGATIVE {
+            sub(&six_u, &[2])
+        } else {
+            add(&six_u, &[2])
+        }
+    }
+
+    /// Returns the non-adjacent form of the absolute value of 6u + 2, least
+    /// significant digit first.
+    fn six_u_plus_2_naf() -> Vec<i8> {
+        let mut n = Self::ate_loop_count();
+        let mut naf = vec![];
+
+        while !n.is_empty() {
+            if n[0] & 1 == 1 {
+                if n[0] & 3 == 1 {
+                    naf.push(1);
+                    n = sub(&n, &[1]);
+                } else {
+                    naf.push(-1);
+                    n = add(&n, &[1]);
+                }
+            } else {
+                naf.push(0);
+            }
+            n = div_small(&n, 2).0;
+        }
+
+        naf
+    }
+
+    /// Returns ξ^((q^power - 1) / divisor), for a divisor of q - 1. These are
+    /// the Frobenius coefficients of the extension tower: for example the
+    /// coefficients of c1 in Fq6 are `frobenius_coeff(&xi, power, 3)`, and
+    /// those of c1 in Fq12 are `frobenius_coeff(&xi, power, 6)`.
+    fn frobenius_coeff<F: Field>(xi: &F, power: usize, divisor: u64) -> F {
+        let q_minus_one = sub(&Self::base_field_modulus(), &[1]);
+        let (exp, rem) = div_small(&q_minus_one, divisor);
+        assert_eq!(rem, 0, "divisor must divide q - 1");
+
+        // (q^power - 1) / d = (q - 1) / d * (1 + q + ... + q^(power - 1))
+        let base = xi.pow(&exp);
+        let mut res = F::one();
+        for i in 0..power {
+            let mut tmp = base;
+            tmp.frobenius_map(i);
+            res.mul_assign(&tmp);
+        }
+
+        res
+    }
+}
+
+// Arithmetic on unsigned integers given as little-endian limbs, without
+// leading zero limbs. It is only used to derive the constants of a curve
+// from its parameter u.
+
+fn normalize(mut a: Vec<u64>) -> Vec<u64> {
+    while a.last() == Some(&0) {
+        a.pop();
+    }
+    a
+}
+
+fn add(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut res = Vec::with_capacity(a.len().max(b.len()) + 1);
+    let mut carry = 0u128;
+    for i in 0..a.len().max(b.len()) {
+        let tmp = u128::from(*a.get(i).unwrap_or(&0)) + u128::from(*b.get(i).unwrap_or(&0)) + carry;
+        res.push(tmp as u64);
+        carry = tmp >> 64;
+    }
+    res.push(carry as u64);
+    normalize(res)
+}
+
+fn sub(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut res = Vec::with_capacity(a.len());
+    let mut borrow = 0u64;
+    for (i, limb) in a.iter().enumerate() {
+        let (tmp, b1) = limb.overflowing_sub(*b.get(i).unwrap_or(&0));
+        let (tmp, b2) = tmp.overflowing_sub(borrow);
+        res.push(tmp);
+        borrow = (b1 | b2) as u64;
+    }
+    assert!(borrow == 0 && b.len() <= a.len(), "subtraction underflow");
+    normalize(res)
+}
+
+fn mul(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut res = vec![0u64; a.len() + b.len()];
+    for i in 0..a.len() {
+        let mut carry = 0u128;
+        for j in 0..b.len() {
+            let tmp = u128::from(a[i]) * u128::from(b[j]) + u128::from(res[i + j]) + carry;
+            res[i + j] = tmp as u64;
+            carry = tmp >> 64;
+        }
+        res[i + b.len()] = carry as u64;
+    }
+    normalize(res)
+}
+
+fn div_small(a: &[u64], d: u64) -> (Vec<u64>, u64) {
+    let mut res = vec![0u64; a.len()];
+    let mut rem = 0u128;
+    for i in (0..a.len()).rev() {
+        let tmp = (rem << 64) | u128::from(a[i]);
+        res[i] = (tmp / u128::from(d)) as u64;
+        rem = tmp % u128::from(d);
+    }
+    (normalize(res), rem as u64)
+}
+
+/// Evaluates the polynomial with the given nonnegative coefficients, constant
+/// term first, at the signed point u. The result must be nonnegative.
+fn eval_poly(u: &[u64], u_is_negative: bool, coeffs: &[u64]) -> Vec<u64> {
+    let mut pos = vec![];
+    let mut neg = vec![];
+    let mut power = vec![1];
+    for (i, c) in coeffs.iter().enumerate() {
+        let term = mul(&power, &[*c]);
+        if u_is_negative && i % 2 == 1 {
+            neg = add(&neg, &term);
+        } else {
+            pos = add(&pos, &term);
+        }
+        power = mul(&power, u);
+    }
+    sub(&pos, &neg)
+}
+
+/// Implements `Engine` and its target group `Gt` for a BN curve, computing
+/// the optimal ate pairing with the Miller loop over the non-adjacent form
+/// of 6u + 2 and the final exponentiation of https://eprint.iacr.org/2008/490.
+macro_rules! bn_engine_impl {
+    ($engine:ident) => {
+        gt_impl!($engine, Fq12);
+        g2_prepared_impl!();
+
+        impl ScalarEngine for $engine {
+            type Fr = Fr;
+        }
+
+        impl Engine for $engine {
+            type G1 = G1;
+            type G1Affine = G1Affine;
+            type G2 = G2;
+            type G2Affine = G2Affine;
+            type Fq = Fq;
+            type Fqe = Fq2;
+            type Fqk = Fq12;
+            type Gt = Gt;
+
+            fn miller_loop<'a, I>(i: I) -> Self::Fqk
+            where
+                I: IntoIterator<
+                    Item = &'a (
+                        &'a <Self::G1Affine as CurveAffine>::Prepared,
+                        &'a <Self::G2Affine as CurveAffine>::Prepared,
+                    ),
+                >,
+            {
+                // Shares the squarings of the loop between the pairs `i`, which the
+                // `parallel` feature splits into chunks across threads.
+                fn multi_miller_loop(i: &[(&G1Prepared, &G2Prepared)]) -> Fq12 {
+                    let mut pairs = vec![];
+                    for &(p, q) in i {
+                        if !p.is_zero() && !q.is_zero() {
+                            pairs.push((p, q.coeffs.iter()));
+                        }
+                    }
+
+                    // Final steps of the line function on prepared coefficients
+                    fn ell(f: &mut Fq12, coeffs: &(Fq2, Fq2, Fq2), p: &G1Affine) {
+                        let mut c0 = coeffs.0;
+                        let mut c1 = coeffs.1;
+
+                        c0.c0.mul_assign(&p.y);
+                        c0.c1.mul_assign(&p.y);
+
+                        c1.c0.mul_assign(&p.x);
+                        c1.c1.mul_assign(&p.x);
+
+                        // Sparse multiplication in Fq12
+                        match <$engine as ::bn::BnParameters>::TWIST_TYPE {
+                            ::bn::TwistType::M => f.mul_by_014(&coeffs.2, &c1, &c0),
+                            ::bn::TwistType::D => f.mul_by_034(&c0, &c1, &coeffs.2),
+                        }
+                    }
+
+                    let six_u_plus_2_naf = <$engine as ::bn::BnParameters>::six_u_plus_2_naf();
+
+                    let mut f = Fq12::one();
+
+                    for i in (1..six_u_plus_2_naf.len()).rev() {
+                        if i != six_u_plus_2_naf.len() - 1 {
+                            f.square();
+                        }
+                        for &mut (p, ref mut coeffs) in &mut pairs {
+                            ell(&mut f, coeffs.next().unwrap(), &p.0);
+                        }
+                        if six_u_plus_2_naf[i - 1] != 0 {
+                            for &mut (p, ref mut coeffs) in &mut pairs {
+                                ell(&mut f, coeffs.next().unwrap(), &p.0);
+                            }
+                        }
+                    }
+
+                    if <$engine as ::bn::BnParameters>::U_IS_NEGATIVE {
+                        f.conjugate();
+                    }
+
+                    // two additional steps: for q1 and minus q2
+
+                    for &mut (p, ref mut coeffs) in &mut pairs {
+                        ell(&mut f, coeffs.next().unwrap(), &p.0);
+                    }
+
+                    for &mut (p, ref mut coeffs) in &mut pairs {
+                        ell(&mut f, coeffs.next().unwrap(), &p.0);
+                    }
+
+                    for &mut (_p, ref mut coeffs) in &mut pairs {
+                        assert_eq!(coeffs.next(), None);
+                    }
+
+                    f
+                }
+
+                let pairs: Vec<_> = i.into_iter().map(|&(p, q)| (p, q)).collect();
+
+                #[cfg(feature = "parallel")]
+                let f = ::parallel::miller_loop(&pairs, multi_miller_loop);
+                #[cfg(not(feature = "parallel"))]
+                let f = multi_miller_loop(&pairs);
+
+                f
+            }
+
+            fn final_exponentiation(r: &Fq12) -> Option<Fq12> {
+                let mut f1 = *r;
+                f1.conjugate();
+
+                match r.inverse() {
+                    Some(mut f2) => {
+                        let mut r = f1;
+                        r.mul_assign(&f2);
+                        f2 = r;
+                        r.frobenius_map(2);
+                        r.mul_assign(&f2);
+
+                        Some($engine::final_exponentiation_hard_part(&r))
+                    }
+                    None => None,
+                }
+            }
+        }
+
+        impl $engine {
+            /// Raises an element of the cyclotomic subgroup, the output of the
+            /// easy part of the final exponentiation, to the power
+            /// (q^4 - q^2 + 1) / r, with the addition chain of Scott et al.,
+            /// https://eprint.iacr.org/2008/490, which writes the exponent in
+            /// base q and takes three exponentiations by u. The shorter chain of
+            /// Fuentes-Castañeda et al. raises to a multiple of this exponent
+            /// instead, which would change the values of the pairing.
+            pub fn final_exponentiation_hard_part(f: &Fq12) -> Fq12 {
+                fn exp_by_x(f: &mut Fq12) {
+                    *f = f.cyclotomic_exp(<$engine as ::bn::BnParameters>::U);
+                    if <$engine as ::bn::BnParameters>::U_IS_NEGATIVE {
+                        f.conjugate();
+                    }
+                }
+
+                let r = *f;
+
+                let mut fp = r;
+                fp.frobenius_map(1);
+
+                let mut fp2 = r;
+                fp2.frobenius_map(2);
+                let mut fp3 = fp2;
+                fp3.frobenius_map(1);
+
+                let mut fu = r;
+                exp_by_x(&mut fu);
+
+                let mut fu2 = fu;
+                exp_by_x(&mut fu2);
+
+                let mut fu3 = fu2;
+                exp_by_x(&mut fu3);
+
+                let mut y3 = fu;
+                y3.frobenius_map(1);
+
+                let mut fu2p = fu2;
+                fu2p.frobenius_map(1);
+
+                let mut fu3p = fu3;
+                fu3p.frobenius_map(1);
+
+                let mut y2 = fu2;
+                y2.frobenius_map(2);
+
+                let mut y0 = fp;
+                y0.mul_assign(&fp2);
+                y0.mul_assign(&fp3);
+
+                let mut y1 = r;
+                y1.conjugate();
+
+                let mut y5 = fu2;
+                y5.conjugate();
+
+                y3.conjugate();
+
+                let mut y4 = fu;
+                y4.mul_assign(&fu2p);
+                y4.conjugate();
+
+                let mut y6 = fu3;
+                y6.mul_assign(&fu3p);
+                y6.conjugate();
+
+                y6.cyclotomic_square();
+                y6.mul_assign(&y4);
+                y6.mul_assign(&y5);
+
+                let mut t1 = y3;
+                t1.mul_assign(&y5);
+                t1.mul_assign(&y6);
+
+                y6.mul_assign(&y2);
+
+                t1.cyclotomic_square();
+                t1.mul_assign(&y6);
+                t1.cyclotomic_square();
+
+                let mut t0 = t1;
+                t0.mul_assign(&y1);
+
+                t1.mul_assign(&y0);
+
+                t0.cyclotomic_square();
+                t0.mul_assign(&t1);
+
+                t0
+            }
+        }
+
+        impl G2Prepared {
+            pub fn is_zero(&self) -> bool {
+                self.infinity
+            }
+
+            /// Checks that the first line coefficients are those of `q`, rather
+            /// than preparing `q` again. The line tangent at `q` determines `q`,
+            /// as its coefficients are 4y, -6x^2 and 6x^3 - 4y^2, but the later
+            /// lines are not checked, so this catches coefficients paired with
+            /// the wrong point but not corrupted ones.
+            pub fn is_prepared_from(&self, q: &G2Affine) -> bool {
+                if self.infinity || q.is_zero() {
+                    return self.infinity == q.is_zero();
+                }
+                self.coeffs.first() == G2Prepared::prepare(*q, true).coeffs.first()
+            }
+
+            /// The number of lines of a prepared point other than the point at
+            /// infinity, a doubling for every digit of 6u + 2 but the leading
+            /// one and an addition for every nonzero digit among them, followed
+            /// by the additions of q1 and -q2.
+            fn num_lines() -> usize {
+                let naf = <$engine as ::bn::BnParameters>::six_u_plus_2_naf();
+                let steps: usize = naf[..naf.len() - 1]
+                    .iter()
+                    .map(|&d| if d != 0 { 2 } else { 1 })
+                    .sum();
+                steps + 2
+            }
+
+            pub fn from_affine(q: G2Affine) -> Self {
+                G2Prepared::prepare(q, false)
+            }
+
+            /// Computes the line coefficients of `q`, stopping after the first
+            /// doubling step if `first_line` is set.
+            fn prepare(q: G2Affine, first_line: bool) -> Self {
+                if q.is_zero() {
+                    return G2Prepared {
+                        coeffs: vec![],
+                        infinity: true,
+                    };
+                }
+
+                fn doubling_step(r: &mut G2) -> (Fq2, Fq2, Fq2) {
+                    // Adaptation of Algorithm 26, https://eprint.iacr.org/2010/354.pdf
+                    let mut tmp0 = r.x;
+                    tmp0.square();
+
+                    let mut tmp1 = r.y;
+                    tmp1.square();
+
+                    let mut tmp2 = tmp1;
+                    tmp2.square();
+
+                    let mut tmp3 = tmp1;
+                    tmp3.add_assign(&r.x);
+                    tmp3.square();
+                    tmp3.sub_assign(&tmp0);
+                    tmp3.sub_assign(&tmp2);
+                    tmp3.double();
+
+                    let mut tmp4 = tmp0;
+                    tmp4.double();
+                    tmp4.add_assign(&tmp0);
+
+                    let mut tmp6 = r.x;
+                    tmp6.add_assign(&tmp4);
+
+                    let mut tmp5 = tmp4;
+                    tmp5.square();
+
+                    let mut zsquared = r.z;
+                    zsquared.square();
+
+                    r.x = tmp5;
+                    r.x.sub_assign(&tmp3);
+                    r.x.sub_assign(&tmp3);
+
+                    r.z.add_assign(&r.y);
+                    r.z.square();
+                    r.z.sub_assign(&tmp1);
+                    r.z.sub_assign(&zsquared);
+
+                    r.y = tmp3;
+                    r.y.sub_assign(&r.x);
+                    r.y.mul_assign(&tmp4);
+
+                    tmp2.double();
+                    tmp2.double();
+                    tmp2.double();
+
+                    r.y.sub_assign(&tmp2);
+
+                    // up to here everything was by algorith, line 11
+                    // use R instead of new T
+
+                    // tmp3 is the first part of line 12
+                    tmp3 = tmp4;
+                    tmp3.mul_assign(&zsquared);
+                    tmp3.double();
+                    tmp3.negate();
+
+                    // tmp6 is from line 14
+                    tmp6.square();
+                    tmp6.sub_assign(&tmp0);
+                    tmp6.sub_assign(&tmp5);
+
+                    tmp1.double();
+                    tmp1.double();
+
+                    tmp6.sub_assign(&tmp1);
+
+                    // tmp0 is the first part of line 16
+                    tmp0 = r.z;
+                    tmp0.mul_assign(&zsquared);
+                    tmp0.double();
+
+                    (tmp0, tmp3, tmp6)
+                }
+
+                fn addition_step(r: &mut G2, q: &G2Affine) -> (Fq2, Fq2, Fq2) {
+                    // Adaptation of Algorithm 27, https://eprint.iacr.org/2010/354.pdf
+                    let mut zsquared = r.z;
+                    zsquared.square();
+
+                    let mut ysquared = q.y;
+                    ysquared.square();
+
+                    // t0 corresponds to line 1
+                    let mut t0 = zsquared;
+                    t0.mul_assign(&q.x);
+
+                    // t1 corresponds to lines 2 and 3
+                    let mut t1 = q.y;
+                    t1.add_assign(&r.z);
+                    t1.square();
+                    t1.sub_assign(&ysquared);
+                    t1.sub_assign(&zsquared);
+                    t1.mul_assign(&zsquared);
+
+                    // t2 corresponds to line 4
+                    let mut t2 = t0;
+                    t2.sub_assign(&r.x);
+
+                    // t3 corresponds to line 5
+                    let mut t3 = t2;
+                    t3.square();
+
+                    // t4 corresponds to line 6
+                    let mut t4 = t3;
+                    t4.double();
+                    t4.double();
+
+                    // t5 corresponds to line 7
+                    let mut t5 = t4;
+                    t5.mul_assign(&t2);
+
+                    // t6 corresponds to line 8
+                    let mut t6 = t1;
+                    t6.sub_assign(&r.y);
+                    t6.sub_assign(&r.y);
+
+                    // t9 corresponds to line 9
+                    let mut t9 = t6;
+                    t9.mul_assign(&q.x);
+
+                    // corresponds to line 10
+                    let mut t7 = t4;
+                    t7.mul_assign(&r.x);
+
+                    // corresponds to line 11, but assigns to r.x instead of T.x
+                    r.x = t6;
+                    r.x.square();
+                    r.x.sub_assign(&t5);
+                    r.x.sub_assign(&t7);
+                    r.x.sub_assign(&t7);
+
+                    // corresponds to line 12, but assigns to r.z instead of T.z
+                    r.z.add_assign(&t2);
+                    r.z.square();
+                    r.z.sub_assign(&zsquared);
+                    r.z.sub_assign(&t3);
+
+                    // corresponds to line 13
+                    let mut t10 = q.y;
+                    t10.add_assign(&r.z);
+
+                    // corresponds to line 14
+                    let mut t8 = t7;
+                    t8.sub_assign(&r.x);
+                    t8.mul_assign(&t6);
+
+                    // corresponds to line 15
+                    t0 = r.y;
+                    t0.mul_assign(&t5);
+                    t0.double();
+
+                    // corresponds to line 12, but assigns to r.y instead of T.y
+                    r.y = t8;
+                    r.y.sub_assign(&t0);
+
+                    // corresponds to line 17
+                    t10.square();
+                    t10.sub_assign(&ysquared);
+
+                    let mut ztsquared = r.z;
+                    ztsquared.square();
+
+                    t10.sub_assign(&ztsquared);
+
+                    // corresponds to line 18
+                    t9.double();
+                    t9.sub_assign(&t10);
+
+                    // t10 = 2*Zt from Algo 27, line 19
+                    t10 = r.z;
+                    t10.double();
+
+                    // t1 = first multiplicator of line 21
+                    t6.negate();
+
+                    t1 = t6;
+                    t1.double();
+
+                    // t9 corresponds to t9 from Algo 27
+                    (t10, t1, t9)
+                }
+
+                let six_u_plus_2_naf = <$engine as ::bn::BnParameters>::six_u_plus_2_naf();
+
+                let mut coeffs = vec![];
+                let mut r: G2 = q.into();
+
+                let mut negq = q;
+                negq.negate();
+
+                for i in (1..six_u_plus_2_naf.len()).rev() {
+                    coeffs.push(doubling_step(&mut r));
+                    if first_line {
+                        return G2Prepared {
+                            coeffs,
+                            infinity: false,
+                        };
+                    }
+                    match six_u_plus_2_naf[i - 1] {
+                        1 => {
+                            coeffs.push(addition_step(&mut r, &q));
+                        }
+                        -1 => {
+                            coeffs.push(addition_step(&mut r, &negq));
+                        }
+                        _ => continue,
+                    }
+                }
+
+                // The loop computed [|6u + 2|]Q, negate it to get [6u + 2]Q.
+                if <$engine as ::bn::BnParameters>::U_IS_NEGATIVE {
+                    r.negate();
+                }
+
+                // The Frobenius endomorphism on E', which multiplies the
+                // conjugated coordinates by ξ^((q - 1) / 3) and ξ^((q - 1) / 2)
+                // for a D-type twist, and by their inverses for an M-type one.
+                let mut gamma_x = FROBENIUS_COEFF_FQ6_C1[1];
+                let mut gamma_y = XI_TO_Q_MINUS_1_OVER_2;
+                let mut gamma2_x = FROBENIUS_COEFF_FQ6_C1[2];
+                if <$engine as ::bn::BnParameters>::TWIST_TYPE == ::bn::TwistType::M {
+                    gamma_x = gamma_x.inverse().unwrap();
+                    gamma_y = gamma_y.inverse().unwrap();
+                    gamma2_x = gamma2_x.inverse().unwrap();
+                }
+
+                let mut q1 = q;
+
+                q1.x.c1.negate();
+                q1.x.mul_assign(&gamma_x);
+
+                q1.y.c1.negate();
+                q1.y.mul_assign(&gamma_y);
+
+                coeffs.push(addition_step(&mut r, &q1));
+
+                // ξ^((q^2 - 1) / 2) = -1, so the y-coordinate of -q2 is the
+                // one of q.
+                let mut minusq2 = q;
+                minusq2.x.mul_assign(&gamma2_x);
+
+                coeffs.push(addition_step(&mut r, &minusq2));
+
+                G2Prepared {
+                    coeffs,
+                    infinity: false,
+                }
+            }
+        }
+    };
+}
@@ -30,9 +30,110 @@ pub const B_COEFF_FQ2: Fq2 = Fq2 {
     ])),
 };
 
+// The constants c1, c2, c3 and c4 of the SVDW map of RFC 9380, section
+// 6.6.1, for the curve of G1 and Z = 1
+// In Montgommery form with R = 2^256
+pub const SVDW_C_G1: [Fq; 4] = [
+    Fq(FqRepr([
+        0x115482203dbf392d,
+        0x926242126eaa626a,
+        0xe16a48076063c052,
+        0x07c5909386eddc93,
+    ])),
+    Fq(FqRepr([
+        0xb461a4448976f7d5,
+        0xc6843fb439555fa7,
+        0x28f0d12384840918,
+        0x112ceb58a394e07d,
+    ])),
+    Fq(FqRepr([
+        0x7c8487078735ab72,
+        0x51da7e0048bfb8d4,
+        0x945cfd183cbd7bf4,
+        0x0b70b1ec48ae62c6,
+    ])),
+    Fq(FqRepr([
+        0xa79a2bdca0800831,
+        0x19fd7617e49815a1,
+        0xbb8d0c885550c7b1,
+        0x05c4aeb6ec7e0f48,
+    ])),
+];
+
+// The constants of the SVDW map for the twist of G2 and Z = 1
+// In Montgommery form with R = 2^256
+pub const SVDW_C_G2: [Fq2; 4] = [
+    Fq2 {
+        c0: Fq(FqRepr([
+            0xd335f05a64ca12fe,
+            0x75029bbec388940d,
+            0xd4d64ba9406d402e,
+            0x02baef80fc5ae772,
+        ])),
+        c1: Fq(FqRepr([
+            0x38e7ecccd1dcff67,
+            0x65f0b37d93ce0d3e,
+            0xd749d0dd22ac00aa,
+            0x0141b9ce4a688d4d,
+        ])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0xb461a4448976f7d5,
+            0xc6843fb439555fa7,
+            0x28f0d12384840918,
+            0x112ceb58a394e07d,
+        ])),
+        c1: Fq(FqRepr([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0xaaad0cab9a24277f,
+            0xf2209f5b7e5b757a,
+            0xc3a46b7e850013a7,
+            0x1f9e7f3768c5c9af,
+        ])),
+        c1: Fq(FqRepr([
+            0x412278c8de85d863,
+            0xfe3e4c7f559d375a,
+            0x5e44b9da0a96ad23,
+            0x297d818d387725c8,
+        ])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x63cdc796b49b3a32,
+            0x73a8220d40eb16f6,
+            0xb46d1eed55c49000,
+            0x1c9ef4f5f0528b82,
+        ])),
+        c1: Fq(FqRepr([
+            0x9aeb505b1600fe13,
+            0x64eb25e9f8b4638f,
+            0x43edd9e4fdf1577a,
+            0x2eb756b528a63917,
+        ])),
+    },
+];
+
 
 // The generators of G1/G2
 
+// A primitive cube root of unity, such that (x, y) -> (BETA * x, y) acts as
+// multiplication by the GLV eigenvalue lambda on G1.
+// In Montgommery form with R = 2^256
+pub const BETA: Fq = Fq(FqRepr([
+    0x3350c88e13e80b9c,
+    0x7dce557cdb5e56b9,
+    0x6001b4b8b615564a,
+    0x2682e617020217e0,
+]));
+
 // Generator of G1
 // x = 1
 // y = 2
@@ -432,12 +533,20 @@ pub const FROBENIUS_COEFF_FQ12_C1: [Fq2; 12] = [
 
 // -((2**256) mod q) mod q
 pub const NEGATIVE_ONE: Fq = Fq(FqRepr([
-    0x974bc177a0000006,
-    0xf13771b2da58a367,
-    0x51e1a2470908122e,
+    0x68c3488912edefaa,
+    0x8d087f6872aabf4f,
+    0x51e1a24709081231,
     0x2259d6b14729c0fa,
 ]));
 
+#[test]
+fn test_neg_one() {
+    let mut o = Fq::one();
+    o.negate();
+
+    assert_eq!(NEGATIVE_ONE, o);
+}
+
 #[cfg(test)]
 use rand::{SeedableRng};
 
@@ -581,3 +690,30 @@ fn fq_field_tests() {
     crate::tests::field::random_frobenius_tests::<Fq, _>(Fq::char(), 13);
     crate::tests::field::from_str_tests::<Fq>();
 }
+
+#[test]
+fn test_frob_coeffs_from_u() {
+    use bn::BnParameters;
+    use super::Bn256;
+
+    let mut nqr = Fq::one();
+    nqr.negate();
+
+    let mut xi = Fq2::one();
+    xi.mul_by_nonresidue();
+
+    let mut xi2 = xi;
+    xi2.square();
+
+    for i in 0..2 {
+        assert_eq!(FROBENIUS_COEFF_FQ2_C1[i], Bn256::frobenius_coeff(&nqr, i, 2));
+    }
+    assert_eq!(XI_TO_Q_MINUS_1_OVER_2, Bn256::frobenius_coeff(&xi, 1, 2));
+    for i in 0..6 {
+        assert_eq!(FROBENIUS_COEFF_FQ6_C1[i], Bn256::frobenius_coeff(&xi, i, 3));
+        assert_eq!(FROBENIUS_COEFF_FQ6_C2[i], Bn256::frobenius_coeff(&xi2, i, 3));
+    }
+    for i in 0..12 {
+        assert_eq!(FROBENIUS_COEFF_FQ12_C1[i], Bn256::frobenius_coeff(&xi, i, 6));
+    }
+}
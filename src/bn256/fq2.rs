@@ -936,6 +936,7 @@ fn test_fq2_legendre() {
     assert_eq!(QuadraticResidue, m1.legendre());
     m1.mul_by_nonresidue();
     assert_eq!(QuadraticNonResidue, m1.legendre());
+    assert!(m1.sqrt().is_none());
 }
 
 #[cfg(test)]
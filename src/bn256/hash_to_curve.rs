@@ -0,0 +1,294 @@
+//! Hashing to the bn256 groups with the Shallue-van de Woestijne map of
+//! RFC 9380, section 6.6.1, instantiated as the `BN254G1_XMD:SHA-256_SVDW_RO_`
+//! and `BN254G2_XMD:SHA-256_SVDW_RO_` suites.
+//!
+//! G1 has cofactor 1. Points on the twist are sent to G2 with the method of
+//! Fuentes-Castañeda, Knapp and Rodríguez-Henríquez, "Faster hashing to G2".
+
+use super::fq::{B_COEFF, B_COEFF_FQ2, SVDW_C_G1, SVDW_C_G2};
+use super::{Fq, Fq2, FrRepr, G1, G1Affine, G2, G2Affine, BN_U};
+use crate::hash_to_curve::{
+    prime_field_from_okm, prime_field_sgn0, svdw, HashToCurve, HashToField, MapToCurve,
+};
+use crate::{CurveAffine, CurveProjective};
+use ff::Field;
+
+impl HashToField for Fq {
+    // L = ceil((ceil(log2(q)) + k) / 8) = ceil((254 + 128) / 8)
+    const OKM_BYTES: usize = 48;
+
+    fn from_okm(okm: &[u8]) -> Fq {
+        prime_field_from_okm(okm)
+    }
+
+    fn sgn0(&self) -> bool {
+        prime_field_sgn0(self)
+    }
+}
+
+impl HashToField for Fq2 {
+    const OKM_BYTES: usize = 2 * 48;
+
+    fn from_okm(okm: &[u8]) -> Fq2 {
+        Fq2 {
+            c0: Fq::from_okm(&okm[..48]),
+            c1: Fq::from_okm(&okm[48..]),
+        }
+    }
+
+    fn sgn0(&self) -> bool {
+        self.c0.sgn0() || (self.c0.is_zero() && self.c1.sgn0())
+    }
+}
+
+impl MapToCurve for G1 {
+    type Field = Fq;
+    const SUITE_ID: &'static [u8] = b"BN254G1_XMD:SHA-256_SVDW_RO_";
+
+    fn map_to_curve(u: &Fq) -> G1 {
+        // E : y^2 = x^3 + 3, with Z = 1
+        let (x, y) = svdw(u, &Fq::zero(), &B_COEFF, &Fq::one(), &SVDW_C_G1);
+
+        G1Affine {
+            x,
+            y,
+            infinity: false,
+        }.into_projective()
+    }
+
+    fn clear_cofactor(&self) -> G1 {
+        *self
+    }
+}
+
+impl MapToCurve for G2 {
+    type Field = Fq2;
+    const SUITE_ID: &'static [u8] = b"BN254G2_XMD:SHA-256_SVDW_RO_";
+
+    fn map_to_curve(u: &Fq2) -> G2 {
+        // E' : y^2 = x^3 + 3 / xi, with Z = 1
+        let (x, y) = svdw(u, &Fq2::zero(), &B_COEFF_FQ2, &Fq2::one(), &SVDW_C_G2);
+
+        G2Affine {
+            x,
+            y,
+            infinity: false,
+        }.into_projective()
+    }
+
+    /// Computes [u]P + psi([3u]P) + psi^2([u]P) + psi^3(P), a multiple of
+    /// the cofactor clearing map that only needs a multiplication by u.
+    fn clear_cofactor(&self) -> G2 {
+        // The point is not yet in G2, so this cannot use the GLS method.
+        let up = self.mul_double_and_add(FrRepr::from(BN_U));
+
+        let mut three_up = up;
+        three_up.double();
+        three_up.add_assign(&up);
+
+        let mut res = up;
+        res.add_assign(&three_up.psi());
+        res.add_assign(&up.psi().psi());
+        res.add_assign(&self.psi().psi().psi());
+        res
+    }
+}
+
+/// Hashes `msg` to G1 with the `BN254G1_XMD:SHA-256_SVDW_RO_` suite, using
+/// the domain separation tag `dst`.
+pub fn hash_to_g1(msg: &[u8], dst: &[u8]) -> G1 {
+    G1::hash_to_curve(msg, dst)
+}
+
+/// Hashes `msg` to G2 with the `BN254G2_XMD:SHA-256_SVDW_RO_` suite, using
+/// the domain separation tag `dst`.
+pub fn hash_to_g2(msg: &[u8], dst: &[u8]) -> G2 {
+    G2::hash_to_curve(msg, dst)
+}
+
+#[cfg(test)]
+fn check_vectors<G: CurveProjective>(
+    hash: fn(&[u8], &[u8]) -> G,
+    dst: &[u8],
+    expected: &[[&str; 2]],
+) {
+    use ff::PrimeField;
+
+    let msgs = [
+        b"".to_vec(),
+        b"abc".to_vec(),
+        b"abcdef0123456789".to_vec(),
+        format!("q128_{}", "q".repeat(128)).into_bytes(),
+        format!("a512_{}", "a".repeat(512)).into_bytes(),
+    ];
+
+    for (msg, coords) in msgs.iter().zip(expected.iter()) {
+        let p = hash(msg, dst);
+        assert!(crate::hash_to_curve::mul_by_limbs(&p, G::Scalar::char().as_ref()).is_zero());
+        assert_eq!(::hex::encode(p.into_affine().into_uncompressed().as_ref()), coords.concat());
+    }
+}
+
+// The expected points below are regression values computed with this
+// implementation, not published test vectors: RFC 9380 defines no suite for
+// BN254, and the G2 suite with its cofactor clearing is specific to this
+// crate. G2 coordinates are encoded as c1 || c0.
+
+#[test]
+fn test_hash_to_g1() {
+    check_vectors(
+        hash_to_g1,
+        b"QUUX-V01-CS02-with-BN254G1_XMD:SHA-256_SVDW_RO_",
+        &[
+            [
+                "0a976ab906170db1f9638d376514dbf8c42aef256a54bbd48521f20749e59e86",
+                "02925ead66b9e68bfc309b014398640ab55f6619ab59bc1fab2210ad4c4d53d5",
+            ],
+            [
+                "23f717bee89b1003957139f193e6be7da1df5f1374b26a4643b0378b5baf53d1",
+                "04142f826b71ee574452dbc47e05bc3e1a647478403a7ba38b7b93948f4e151d",
+            ],
+            [
+                "187dbf1c3c89aceceef254d6548d7163fdfa43084145f92c4c91c85c21442d4a",
+                "0abd99d5b0000910b56058f9cc3b0ab0a22d47cf27615f588924fac1e5c63b4d",
+            ],
+            [
+                "00fe2b0743575324fc452d590d217390ad48e5a16cf051bee5c40a2eba233f5c",
+                "0794211e0cc72d3cbbdf8e4e5cd6e7d7e78d101ff94862caae8acbe63e9fdc78",
+            ],
+            [
+                "01b05dc540bd79fd0fea4fbb07de08e94fc2e7bd171fe025c479dc212a2173ce",
+                "1bf028afc00c0f843d113758968f580640541728cfc6d32ced9779aa613cd9b0",
+            ],
+        ],
+    );
+}
+
+#[test]
+fn test_encode_to_g1() {
+    check_vectors(
+        G1::encode_to_curve,
+        b"QUUX-V01-CS02-with-BN254G1_XMD:SHA-256_SVDW_NU_",
+        &[
+            [
+                "1bb8810e2ceaf04786d4efd216fc2820ddd9363712efc736ada11049d8af5925",
+                "1efbf8d54c60d865cce08437668ea30f5bf90d287dbd9b5af31da852915e8f11",
+            ],
+            [
+                "0da4a96147df1f35b0f820bd35c6fac3b80e8e320de7c536b1e054667b22c332",
+                "189bd3fbffe4c8740d6543754d95c790e44cd2d162858e3b733d2b8387983bb7",
+            ],
+            [
+                "2ff727cfaaadb3acab713fa22d91f5fddab3ed77948f3ef6233d7ea9b03f4da1",
+                "304080768fd2f87a852155b727f97db84b191e41970506f0326ed4046d1141aa",
+            ],
+            [
+                "11a2eaa8e3e89de056d1b3a288a7f733c8a1282efa41d28e71af065ab245df9b",
+                "060f37c447ac29fd97b9bb83be98ddccf15e34831a9cdf5493b7fede0777ae06",
+            ],
+            [
+                "27409dccc6ee4ce90e24744fda8d72c0bc64e79766f778da0c1c0ef1c186ea84",
+                "1ac201a542feca15e77f30370da183514dc99d8a0b2c136d64ede35cd0b51dc0",
+            ],
+        ],
+    );
+}
+
+#[test]
+fn test_hash_to_g2() {
+    check_vectors(
+        hash_to_g2,
+        b"QUUX-V01-CS02-with-BN254G2_XMD:SHA-256_SVDW_RO_",
+        &[
+            [
+                "1747d950a6f23c16156e2171bce95d1189b04148ad12628869ed21c96a8c93351192005a0f121921a6d5629946199e4b27ff8ee4d6dd4f9581dc550ade851300",
+                "2c9755350ca363ef2cf541005437221c5740086c2e909b71d075152484e845f40498f6bb5ac309a07d9a8b88e6ff4b8de0d5f27a075830e1eb0e68ea318201d8",
+            ],
+            [
+                "0b5db3ca7e8ef5edf3a33dfc3242357fbccead98099c3eb564b3d9d13cba4efd16c88b54eec9af86a41569608cd0f60aab43464e52ce7e6e298bf584b94fccd2",
+                "22d02d2da7f288545ff8789e789902245ab08c6b1d253561eec789ec2c1bd6301c42ba524cb74db8e2c680449746c028f7bea923f245e69f89256af2d6c5f3ac",
+            ],
+            [
+                "2a8a360585b6b05996ef69c3c09b2c6fb17afe2b1e944f07559c53178eabf1711435fd84aa43c699230e371f6fea3545ce7e053cbbb06a320296a2b81efddc70",
+                "142f08e2441ec431defc24621b73cfe0252d19b243cb55b84bdeb85de039207a2820188dcdc13ffdca31694942418afa1d6dfaaf259d012fab4da52b0f592e38",
+            ],
+            [
+                "2718ef38d1bc4347f0266c774c8ef4ee5fa7056cc27a4bd7ecf7a888efb95b262cffc213fb63d00d923cb22cda5a2904837bb93a2fe6e875c532c51744388341",
+                "2206ec0a9288f31ed78531c37295df3b56c42a1284443ee9893adb1521779001232553f728341afa64ce66d00535764557a052e38657594e10074ad28728c584",
+            ],
+            [
+                "17f9f6292998cf18ccc155903c1fe6b6465d40c794a3e1ed644a4182ad639f4a242a0a159f36f87065e7c5170426012087023165ce47a486e53d6e2845ca625a",
+                "18ef4886c818f01fdf309bc9a46dd904273917f85e74ecd0de62460a681220372dc5b7b65c9c79e6ef4afab8fbe3083c66d4ce31c78f6621ece17ecc892cf4b3",
+            ],
+        ],
+    );
+}
+
+#[test]
+fn test_encode_to_g2() {
+    check_vectors(
+        G2::encode_to_curve,
+        b"QUUX-V01-CS02-with-BN254G2_XMD:SHA-256_SVDW_NU_",
+        &[
+            [
+                "070077acfda8443392fb30222ba96b63f4b734e678494bf4ed0e07074b440a7b04e9ea7f5807198397a99e234e91d4b9e6cadf0135ebedd97fd75cffed6e994d",
+                "0a7cf5d0d356f0c4d163570209e5f8f749bf91dc2a7d9ba58199a95ce02242b42d3653bf41ec170ce2d48774d02393c8d5f60fee5690b4f8cbc8531e269227f9",
+            ],
+            [
+                "29226a3ca7415a541599274bf9e805050c82d443fd953481b17236325be3b6b7101e2f3d9fa22cb435ecb67d5284dc27c247856d6de4e420e1812e0bcea5afd8",
+                "2e7c8a61fe36735852597ac564966560afe0ef8221918d5534e57f3096f7047d290bf12841dd276211effe86af369c11a2cb364c443981d0faf347cfb7b68715",
+            ],
+            [
+                "2d0bb492bb59847c106af8285fae5be0b5f96b6dcad56b3a0c7ddc364ae55a3a0fcda542dd52f0e527bf828e63fe2a1f63a05c9a5c7a28865cfef247c6e1e8a6",
+                "0afb68b6e28f44f49d6ab4c3014e73f7e07fd4d0b13a9519b798e9f1927a47b9172d50b483e9bb9aa230e7cb82fbd522af1b73c1643bbd022614533311071780",
+            ],
+            [
+                "2596aa6bcb29439a9cdc7cfe0b9d247a890a4295dc17d053c293c7e40c27387f1d050758368c65df07014cab4752d8244ddf21691ab6418a3493bcc2a946b38d",
+                "27aef639d6eb4157c6f076e9fdae2f9eb15042dea92304fc54ebd5f69c5c34432f84eec5eaa87952d0d81c93c3f470c1e1a00d0ba307d8fda78b76841aca8e82",
+            ],
+            [
+                "261e8ebaff3438064599465bb52880e8e8a663b27cfb6d794d90ac60437819a9013729abbd4fbe2a13bc742960afa9053a4e6be06ea712b0d18153a9ec3854a7",
+                "06bd9197b3c0c1cc4d17695042dcbaf0168329a113d358c3b17885f71a394986132285a30dc36cc14da2d145390a6328e574155ebaece32856fb890d1f7ba16e",
+            ],
+        ],
+    );
+}
+
+#[test]
+fn test_g2_clear_cofactor() {
+    use super::Fr;
+    use crate::hash_to_curve::mul_by_limbs;
+    use ff::{PrimeField, Rand};
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    let mut rng = XorShiftRng::seed_from_u64(0x5dbe62598d313d76);
+
+    for _ in 0..10 {
+        // Points on the twist are almost never in G2 before clearing
+        let u = Fq2::rand(&mut rng);
+        let p = G2::map_to_curve(&u);
+        assert!(!mul_by_limbs(&p, Fr::char().as_ref()).is_zero());
+
+        let q = p.clear_cofactor();
+        assert!(!q.is_zero());
+        assert!(mul_by_limbs(&q, Fr::char().as_ref()).is_zero());
+
+        // psi acts on G2 as multiplication by p
+        assert_eq!(q.psi(), mul_by_limbs(&q, Fq::char().as_ref()));
+    }
+}
+
+#[test]
+fn test_svdw_constants() {
+    use crate::hash_to_curve::svdw_constants;
+
+    assert_eq!(
+        svdw_constants(&Fq::zero(), &B_COEFF, &Fq::one()),
+        SVDW_C_G1
+    );
+    assert_eq!(
+        svdw_constants(&Fq2::zero(), &B_COEFF_FQ2, &Fq2::one()),
+        SVDW_C_G2
+    );
+}
@@ -0,0 +1,175 @@
+use super::*;
+use *;
+
+#[test]
+fn test_pairing_result() {
+    // Pins the values of the pairing, which changes to the final
+    // exponentiation must preserve.
+    assert_eq!(Bn256::pairing(G1::one(), G2::one()).into_fqk(), Fq12 {
+        c0: Fq6 {
+            c0: Fq2 {
+                c0: Fq::from_str("8493334370784016972005089913588211327688223499729897951716206968320726508021").unwrap(),
+                c1: Fq::from_str("3758435817766288188804561253838670030762970764366672594784247447067868088068").unwrap(),
+            },
+            c1: Fq2 {
+                c0: Fq::from_str("6565798094314091391201231504228224566495939541538094766881371862976727043038").unwrap(),
+                c1: Fq::from_str("14656606573936501743457633041048024656612227301473084805627390748872617280984").unwrap(),
+            },
+            c2: Fq2 {
+                c0: Fq::from_str("634997487638609332803583491743335852620873788902390365055086820718589720118").unwrap(),
+                c1: Fq::from_str("19455424343576886430889849773367397946457449073528455097210946839000147698372").unwrap(),
+            },
+        },
+        c1: Fq6 {
+            c0: Fq2 {
+                c0: Fq::from_str("20049218015652006197026173611347504489508678646783216776320737476707192559881").unwrap(),
+                c1: Fq::from_str("18059168546148152671857026372711724379319778306792011146784665080987064164612").unwrap(),
+            },
+            c1: Fq2 {
+                c0: Fq::from_str("12145052038566888241256672223106590273978429515702193755778990643425246950730").unwrap(),
+                c1: Fq::from_str("17918828665069491344039743589118342552553375221610735811112289083834142789347").unwrap(),
+            },
+            c2: Fq2 {
+                c0: Fq::from_str("6223602427219597392892794664899549544171383137467762280768257680446283161705").unwrap(),
+                c1: Fq::from_str("7484542354754424633621663080190936924481536615300815203692506276894207018007").unwrap(),
+            },
+        },
+    });
+
+    let mut p = G1::one();
+    p.mul_assign(Fr::from_str("123456789").unwrap());
+    let mut q = G2::one();
+    q.double();
+    assert_eq!(Bn256::pairing(p, q).into_fqk(), Fq12 {
+        c0: Fq6 {
+            c0: Fq2 {
+                c0: Fq::from_str("12998636711261582010468775555587688804899096515153534394215865010123220596996").unwrap(),
+                c1: Fq::from_str("7151743050652916980549376613311496903939914576469356144145064071308207492761").unwrap(),
+            },
+            c1: Fq2 {
+                c0: Fq::from_str("5811032267782555003817227990890735214070054921100020377516147595653110541070").unwrap(),
+                c1: Fq::from_str("9330185649208935235657837822504838451231195606967483526294125574301780183863").unwrap(),
+            },
+            c2: Fq2 {
+                c0: Fq::from_str("852690087227038637035386213751272742084875575479030391827248040386051698618").unwrap(),
+                c1: Fq::from_str("17516414389465802734370801851200517303634345933050356527676987311839113990109").unwrap(),
+            },
+        },
+        c1: Fq6 {
+            c0: Fq2 {
+                c0: Fq::from_str("9104067877134416508126743147216147358346107120440596207285395776045804776154").unwrap(),
+                c1: Fq::from_str("9844501879303599487654696111367048698754858879296903627077280503224497924268").unwrap(),
+            },
+            c1: Fq2 {
+                c0: Fq::from_str("16494019609112551785133232726247629354518295702910773986641460412934785820800").unwrap(),
+                c1: Fq::from_str("16618335695132503969843723905943975340040495657764554422429345415813096069110").unwrap(),
+            },
+            c2: Fq2 {
+                c0: Fq::from_str("14690388495140329645098383374692030070771327413170627215280332546490625646405").unwrap(),
+                c1: Fq::from_str("9433454703659825605361653371511638900481049983659636966474275666318876924518").unwrap(),
+            },
+        },
+    });
+}
+
+#[test]
+fn test_final_exponentiation_hard_part() {
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    // (q^4 - q^2 + 1) / r
+    const HARD_PART: [u64; 12] = [
+        0xe81bb482ccdf42b1,
+        0x5abf5cc4f49c36d4,
+        0xf1154e7e1da014fd,
+        0xdcc7b44c87cdbacf,
+        0xaaa441e3954bcf8a,
+        0x6b887d56d5095f23,
+        0x79581e16f3fd90c6,
+        0x3b1b1355d189227d,
+        0x4e529a5861876f6b,
+        0x6c0eb522d5b12278,
+        0x331ec15183177faf,
+        0x01baaa710b0759ad,
+    ];
+
+    let mut rng = XorShiftRng::seed_from_u64(0x5dbe62598d313d76);
+
+    for _ in 0..10 {
+        // The easy part of the final exponentiation, f^((q^6 - 1)(q^2 + 1)).
+        let g = Fq12::rand(&mut rng);
+        let mut f = g;
+        f.conjugate();
+        f.mul_assign(&g.inverse().unwrap());
+        let mut h = f;
+        h.frobenius_map(2);
+        f.mul_assign(&h);
+
+        assert_eq!(Bn256::final_exponentiation_hard_part(&f), f.pow(HARD_PART));
+    }
+}
+
+#[test]
+fn test_g2_prepared_encoding() {
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    let mut rng = XorShiftRng::seed_from_u64(0x5dbe62598d313d76);
+
+    let zero = G2Affine::zero();
+    let bytes = zero.prepare().into_bytes();
+    assert_eq!(bytes, vec![G2Prepared::ENCODING_VERSION, 1, 0, 0, 0, 0]);
+    assert_eq!(G2Prepared::from_bytes(&bytes, &zero).unwrap(), zero.prepare());
+
+    for _ in 0..10 {
+        let p = G1::rand(&mut rng).into_affine();
+        let q = G2::rand(&mut rng).into_affine();
+        let other = G2::rand(&mut rng).into_affine();
+        let mut minus_q = q;
+        minus_q.negate();
+        let prepared = q.prepare();
+        let bytes = prepared.into_bytes();
+
+        let decoded = G2Prepared::from_bytes_unchecked(&bytes).unwrap();
+        assert_eq!(decoded, prepared);
+        assert!(decoded.is_prepared_from(&q));
+        assert!(!decoded.is_prepared_from(&other));
+        assert!(!decoded.is_prepared_from(&minus_q));
+        assert!(!decoded.is_prepared_from(&zero));
+        assert_eq!(G2Prepared::from_bytes(&bytes, &q).unwrap(), prepared);
+        assert!(G2Prepared::from_bytes(&bytes, &other).is_err());
+        assert_eq!(
+            Bn256::miller_loop(&[(&p.prepare(), &decoded)]),
+            Bn256::miller_loop(&[(&p.prepare(), &prepared)])
+        );
+
+        // A different version, a truncated encoding, trailing bytes, the flag
+        // of the point at infinity and a wrong number of lines.
+        let mut version = bytes.clone();
+        version[0] += 1;
+        let mut infinity = bytes.clone();
+        infinity[1] = 1;
+        let mut flag = bytes.clone();
+        flag[1] = 2;
+        let mut len = bytes.clone();
+        len[5] -= 1;
+        let mut extra = bytes.clone();
+        extra.push(0);
+        for bytes in &[version, bytes[..bytes.len() - 1].to_vec(), extra, infinity, flag, len] {
+            match G2Prepared::from_bytes_unchecked(bytes) {
+                Err(GroupDecodingError::UnexpectedInformation) => {}
+                _ => panic!("invalid encoding was decoded"),
+            }
+        }
+
+        // A coefficient that is not reduced.
+        let mut unreduced = bytes.clone();
+        for b in &mut unreduced[6..6 + ::std::mem::size_of::<FqRepr>()] {
+            *b = 0xff;
+        }
+        match G2Prepared::from_bytes_unchecked(&unreduced) {
+            Err(GroupDecodingError::CoordinateDecodingError(..)) => {}
+            _ => panic!("unreduced coefficient was decoded"),
+        }
+    }
+}
@@ -0,0 +1,114 @@
+pub mod g1 {
+    use super::super::{Bn462, Fq, FqRepr, Fr, FrRepr};
+    use super::g2::G2Affine;
+    use ff::{Rand, BitIterator, Field, PrimeField, PrimeFieldRepr, SqrtField};
+    use rand::Rng;
+    use std::fmt;
+    use crate::{CurveAffine, CurveProjective, EncodedPoint, Engine, GroupDecodingError};
+
+    // Coordinates are encoded in 64 bytes, the size of FqRepr, which leaves
+    // the two most significant bits free for the flags.
+    bn_g1_impl!(Bn462, 64);
+
+    #[test]
+    fn g1_generator_on_curve() {
+        let gen = G1Affine::get_generator();
+        assert!(gen.is_on_curve());
+        assert!(gen.mul(Fr::char()).is_zero());
+    }
+
+    #[test]
+    fn g1_curve_tests() {
+        crate::tests::curve::curve_tests::<G1>();
+    }
+}
+
+pub mod g2 {
+    use super::super::{Bn462, Fq, Fq2, FqRepr, Fr, FrRepr};
+    use super::g1::G1Affine;
+    use ff::{Rand, BitIterator, Field, PrimeField, PrimeFieldRepr, SqrtField};
+    use rand::Rng;
+    use std::fmt;
+    use crate::{CurveAffine, CurveProjective, EncodedPoint, Engine, GroupDecodingError};
+
+    // G2 cofactor = 2q - r
+    // 0x240480360120023ffffffffff6ff0cf6b7d9bfca0000000000d812908fa1ce0227fffffffff6ff66fc63f5f7f4c0000000002401b008a0168019
+    bn_g2_impl!(Bn462, 64);
+
+    #[test]
+    fn g2_generator_on_curve() {
+        let gen = G2Affine::get_generator();
+        assert!(gen.is_on_curve());
+        assert!(gen.mul(Fr::char()).is_zero());
+    }
+
+    #[test]
+    fn g2_cofactor_clears_to_subgroup() {
+        use rand::SeedableRng;
+        use rand_xorshift::XorShiftRng;
+
+        let mut rng = XorShiftRng::seed_from_u64(0x5dbe62598d313d76);
+
+        for _ in 0..10 {
+            let p: G2 = rng.gen();
+            assert!(p.into_affine().mul(Fr::char()).is_zero());
+        }
+    }
+
+    #[test]
+    fn g2_is_torsion_free() {
+        use rand::SeedableRng;
+        use rand_xorshift::XorShiftRng;
+
+        let mut rng = XorShiftRng::seed_from_u64(0x5dbe62598d313d76);
+
+        assert!(G2Affine::zero().is_torsion_free());
+        for _ in 0..10 {
+            assert!(G2::rand(&mut rng).into_affine().is_torsion_free());
+        }
+
+        // Points on the curve found by incrementing x are almost never in the
+        // subgroup; the check must agree with multiplication by r.
+        let mut x = Fq2::zero();
+        let mut outside = 0;
+        while outside < 10 {
+            let mut rhs = x;
+            rhs.square();
+            rhs.mul_assign(&x);
+            rhs.add_assign(&G2Affine::get_coeff_b());
+
+            if let Some(y) = rhs.sqrt() {
+                let p = G2Affine {
+                    x: x,
+                    y: y,
+                    infinity: false,
+                };
+                let in_subgroup = crate::hash_to_curve::mul_by_limbs(&p.into_projective(), Fr::char().as_ref()).is_zero();
+                assert_eq!(p.is_torsion_free(), in_subgroup);
+                assert!(p.scale_by_cofactor().into_affine().is_torsion_free());
+
+                if !in_subgroup {
+                    outside += 1;
+                    assert!(match p.into_uncompressed().into_affine() {
+                        Err(GroupDecodingError::NotInSubgroup) => true,
+                        _ => false,
+                    });
+                    assert!(match p.into_compressed().into_affine() {
+                        Err(GroupDecodingError::NotInSubgroup) => true,
+                        _ => false,
+                    });
+                }
+            }
+
+            x.add_assign(&Fq2::one());
+        }
+    }
+
+    #[test]
+    fn g2_curve_tests() {
+        crate::tests::curve::curve_tests::<G2>();
+    }
+}
+
+pub use self::g1::*;
+pub use self::g2::*;
@@ -0,0 +1,831 @@
+use super::fq2::Fq2;
+use ff::{Rand, Field, PrimeField, PrimeFieldDecodingError, PrimeFieldRepr};
+
+#[derive(PrimeField)]
+#[PrimeFieldModulus = "6701817056313037086248947066310538444882082605308124576230408038843357549886356779857393369967010764802541005796711440355753503701056323603"]
+#[PrimeFieldGenerator = "2"]
+pub struct Fq(FqRepr);
+
+// B coefficient of BN462 curve, B = 5
+// In Montgommery form with R = 2^512
+pub const B_COEFF: Fq = Fq(FqRepr([
+    0xee5649741a813322,
+    0xfd8349e3f9dafe9b,
+    0xf69f467c0dae8dbf,
+    0xe6e87256a5f54d40,
+    0x725d2bf39b25fb90,
+    0xfe700aa2680e1a6a,
+    0xf51bb5409179c031,
+    0x0000000000000774,
+]));
+
+// B coefficient of the twist, B / ξ = 2 - u
+pub const B_COEFF_FQ2: Fq2 = Fq2 {
+    c0: Fq(FqRepr([
+        0x7c23dd683ddce150,
+        0x5dd47c27fd8acc3e,
+        0x62a61c2a6b6502a6,
+        0xd19d6d241596f880,
+        0xfa6077fb0adbfee6,
+        0xff5ffd0d00cb040b,
+        0xfbcfe3003bfd8013,
+        0x0000000000001fcb,
+    ])),
+    c1: Fq(FqRepr([
+        0xe5efc15421250f6b,
+        0xc79db82c013a99e0,
+        0xceacf1e1c9b47b1c,
+        0xa9c1d8afbd3693bf,
+        0xc299c4027a920164,
+        0x004ff8788c9135d3,
+        0x824e0f9fe4413ff6,
+        0x000000000000141e,
+    ])),
+};
+
+// The generators of G1/G2 are the ones of the IETF draft on pairing-friendly curves.
+
+// Generator of G1
+// x = 6261597770751686766304127463725316165100263872407411044109477525993917946983736130639000042561733070367145611905524997790967974535682758669
+// y = 204179267387879644906723091734489368197142008739619847677216635537192963546899453469410182641127177605626616416197675607744619328027952094
+pub const G1_GENERATOR_X: Fq = Fq(FqRepr([
+    0x60b833a0482110fc,
+    0x2af93ed9abd13a14,
+    0x6c44860453edecca,
+    0x811fa9068dba1a13,
+    0xe0a5dffddef391ed,
+    0x2c6de500c0bdedd2,
+    0x29a905e8abf39d13,
+    0x0000000000000785,
+]));
+pub const G1_GENERATOR_Y: Fq = Fq(FqRepr([
+    0x341e519317cb9454,
+    0x9cc9f2c90251d92d,
+    0x2f5528e0cf0b493e,
+    0x152a8165fef04e6d,
+    0xbf9e409a6384a317,
+    0x02d5d0f1c2b68657,
+    0x7b40e1d4cf6c2332,
+    0x00000000000007be,
+]));
+
+// Generator of G2
+//
+// x = 5429676250952120738079401324805469514221097756885530389490433608502196833923625032570676158004283815296282738280125250844176255190836236931*u
+//     + 435957817554675384810319183662593807485670857958721340760639045638688403359611555448024676202863729167237030977063758895670478936635204831
+//
+// y = 1348242666749953965803169724417379008027088789760376109041697464227670473912998920979159892085102322139067996255049863460825323823268276538*u
+//     + 1865296053548497491451636771459553614287380508128407539143891039650838457793645725025312127717124840537779621176728195068769940238989989198
+
+pub const G2_GENERATOR_X_C0: Fq = Fq(FqRepr([
+    0x76e30f7cc9ee8adc,
+    0xf81795d7672b1122,
+    0xfd72bb9fcfd06c09,
+    0x706e716d58081d77,
+    0x35545e784b044a95,
+    0x271c39c8cc4b5253,
+    0x626fd151b2e46f87,
+    0x0000000000002172,
+]));
+pub const G2_GENERATOR_X_C1: Fq = Fq(FqRepr([
+    0x987f5d0946aa5fb7,
+    0x0e249077d244e595,
+    0x8edb1b350eae7a44,
+    0x3d8ec0f53d225897,
+    0xdab10a2c4973fe32,
+    0xfb4e1be8dd040a3d,
+    0x86f7dab6b4a6f8ee,
+    0x000000000000006f,
+]));
+pub const G2_GENERATOR_Y_C0: Fq = Fq(FqRepr([
+    0xf9db9dd1920106f4,
+    0xa6e72663a34786ab,
+    0x24ba07e7a716c9fa,
+    0xcedaad780bacddbe,
+    0x24a70d3c8b1bad0f,
+    0x964fb960a9f2ff79,
+    0xd722823a70bdeade,
+    0x00000000000002c1,
+]));
+pub const G2_GENERATOR_Y_C1: Fq = Fq(FqRepr([
+    0x6ea78171f0466a2b,
+    0xd2f85160a598e710,
+    0x7a9f91eb3c5e1cb9,
+    0x3dc63e25940e7057,
+    0x3437fc781eb949c7,
+    0xd21f8a07cf799962,
+    0x2ff68b889e358905,
+    0x0000000000001b03,
+]));
+
+// Coefficients for the Frobenius automorphism.
+pub const FROBENIUS_COEFF_FQ2_C1: [Fq; 2] = [
+    // Fq(-1)**(((q^0) - 1) / 2)
+    Fq(FqRepr([
+        0x3e11eeb41eee70a8,
+        0x2eea3e13fec5661f,
+        0x31530e1535b28153,
+        0x68ceb6920acb7c40,
+        0xfd303bfd856dff73,
+        0xffaffe8680658205,
+        0xfde7f1801dfec009,
+        0x0000000000000fe5,
+    ])),
+    // Fq(-1)**(((q^1) - 1) / 2)
+    Fq(FqRepr([
+        0xe5efc15421250f6b,
+        0xc79db82c013a99e0,
+        0xceacf1e1c9b47b1c,
+        0xa9c1d8afbd3693bf,
+        0xc299c4027a920164,
+        0x004ff8788c9135d3,
+        0x824e0f9fe4413ff6,
+        0x000000000000141e,
+    ])),
+];
+
+// Fq2(u + 2)**(((q^1) - 1) / 2)
+pub const XI_TO_Q_MINUS_1_OVER_2: Fq2 = Fq2 {
+    c0: Fq(FqRepr([
+        0x68df801330cc654d,
+        0x13115ae98aa9d16d,
+        0x4daac6cb113fe602,
+        0x5794c299b6b85112,
+        0x0794dc2088b9689b,
+        0xc175794b223d3f20,
+        0xad93dfb6fe40843e,
+        0x0000000000000c9c,
+    ])),
+    c1: Fq(FqRepr([
+        0xd1bf00266198ca9a,
+        0x2622b5d31553a2da,
+        0x9b558d96227fcc04,
+        0xaf2985336d70a224,
+        0x0f29b8411172d136,
+        0x82eaf296447a7e40,
+        0x5b27bf6dfc81087d,
+        0x0000000000001939,
+    ])),
+};
+
+pub const FROBENIUS_COEFF_FQ6_C1: [Fq2; 6] = [
+    // Fq2(u + 2)**(((q^0) - 1) / 3)
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x3e11eeb41eee70a8,
+            0x2eea3e13fec5661f,
+            0x31530e1535b28153,
+            0x68ceb6920acb7c40,
+            0xfd303bfd856dff73,
+            0xffaffe8680658205,
+            0xfde7f1801dfec009,
+            0x0000000000000fe5,
+        ])),
+        c1: Fq(FqRepr([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+    },
+    // Fq2(u + 2)**(((q^1) - 1) / 3)
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x14a56e51d493bcc9,
+            0xb84358efb8503d8f,
+            0x39b4a0b18436f576,
+            0xe4aaf59436d2c30f,
+            0xe091597b24ae8bb1,
+            0xe7f3df49c0b63234,
+            0xdc08285e422ff3d9,
+            0x0000000000000d82,
+        ])),
+        c1: Fq(FqRepr([
+            0x65925b95a8a1f1c5,
+            0xd7e63ef34893b931,
+            0xf9eb470fcaf539e4,
+            0x3c19efb402e70760,
+            0xeb0f3bbef193cd74,
+            0x64ddfca93ddb64e3,
+            0xebc1f798b984f2e2,
+            0x0000000000000fa4,
+        ])),
+    },
+    // Fq2(u + 2)**(((q^2) - 1) / 3)
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x1411710cf60202c8,
+            0x66e69ee38f2f8e88,
+            0x6280de9a92a4e382,
+            0xb29d7462344c7831,
+            0x7f965ccd9ee433ba,
+            0x0087fb5a1511f6b7,
+            0x318cc43c76021fef,
+            0x0000000000000078,
+        ])),
+        c1: Fq(FqRepr([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+    },
+    // Fq2(u + 2)**(((q^3) - 1) / 3)
+    Fq2 {
+        c0: Fq(FqRepr([
+            0xa063be75a7a70c71,
+            0x19924d7f199f4e8b,
+            0x5d4bf52831b210ee,
+            0x0b497580ad80cd70,
+            0x514f20d59b9424b4,
+            0xa57612d18e8c8891,
+            0xaec25573dd56adda,
+            0x00000000000001f9,
+        ])),
+        c1: Fq(FqRepr([
+            0x6e01d90cb5d41773,
+            0x82a2cc75c74b2a5d,
+            0x04ccfa89db55a622,
+            0x099007c5b97ba2eb,
+            0x6e55e86095aa6d3f,
+            0x057381b6b4222a43,
+            0x0d49773d7fd2f731,
+            0x0000000000001c38,
+        ])),
+    },
+    // Fq2(u + 2)**(((q^4) - 1) / 3)
+    Fq2 {
+        c0: Fq(FqRepr([
+            0xd1de50472b230ca3,
+            0x60b71948720b0b58,
+            0x6c2c1347370f979a,
+            0xf724644d88ea1b8e,
+            0x43036734dbadcda9,
+            0xffc7fd1e777f3f1c,
+            0x50c14b636e3f2006,
+            0x00000000000013a6,
+        ])),
+        c1: Fq(FqRepr([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+    },
+    // Fq2(u + 2)**(((q^5) - 1) / 3)
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x6ef88340c3d8b6d9,
+            0x24b24fd12e1073e5,
+            0x68ff6a1d497df60b,
+            0x229c242ce3ae7f80,
+            0x8de985af3fbd5072,
+            0x729604e3bdb3fd13,
+            0xf56b834de2b95e4b,
+            0x0000000000001487,
+        ])),
+        c1: Fq(FqRepr([
+            0x746f2b6e21b0f6ee,
+            0x9286e116f0211c71,
+            0x0147be54588318d8,
+            0xdf772709d3a175b4,
+            0x262edbe078c1c6fc,
+            0x95ae6f9e27efe08c,
+            0x07609369cb2815ec,
+            0x0000000000001c2c,
+        ])),
+    },
+];
+
+pub const FROBENIUS_COEFF_FQ6_C2: [Fq2; 6] = [
+    // Fq2(u + 2)**(((2 * q^0) - 1) / 3)
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x3e11eeb41eee70a8,
+            0x2eea3e13fec5661f,
+            0x31530e1535b28153,
+            0x68ceb6920acb7c40,
+            0xfd303bfd856dff73,
+            0xffaffe8680658205,
+            0xfde7f1801dfec009,
+            0x0000000000000fe5,
+        ])),
+        c1: Fq(FqRepr([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+    },
+    // Fq2(u + 2)**(((2 * q^1) - 1) / 3)
+    Fq2 {
+        c0: Fq(FqRepr([
+            0xfb09fc06e7668e9c,
+            0x9b1d75f7f522952e,
+            0x3df9742f18207d9c,
+            0x39eeb2d4241bf7f0,
+            0x434639e8c5a35e62,
+            0xcd01a43cc00cec1e,
+            0x40ad3fed4d425a3c,
+            0x0000000000000b64,
+        ])),
+        c1: Fq(FqRepr([
+            0x8c921cfa68cca674,
+            0x7f5d2d647e455f04,
+            0xab3bd277f5c827fb,
+            0x0e1c4458547c6693,
+            0x90e84453848de2dd,
+            0x4a85ae502ca1bea1,
+            0x9de40ca8a03a0dcf,
+            0x00000000000009e4,
+        ])),
+    },
+    // Fq2(u + 2)**(((2 * q^2) - 1) / 3)
+    Fq2 {
+        c0: Fq(FqRepr([
+            0xd1de50472b230ca3,
+            0x60b71948720b0b58,
+            0x6c2c1347370f979a,
+            0xf724644d88ea1b8e,
+            0x43036734dbadcda9,
+            0xffc7fd1e777f3f1c,
+            0x50c14b636e3f2006,
+            0x00000000000013a6,
+        ])),
+        c1: Fq(FqRepr([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+    },
+    // Fq2(u + 2)**(((2 * q^3) - 1) / 3)
+    Fq2 {
+        c0: Fq(FqRepr([
+            0xf908db11799adb2e,
+            0x6a2a4dfad656a6d5,
+            0xcdbccaa0d426e816,
+            0x11afcf8a688608ed,
+            0x651b26994c03bf78,
+            0x921d9a8438c94e61,
+            0xab3ca1cded9808f5,
+            0x0000000000000ea0,
+        ])),
+        c1: Fq(FqRepr([
+            0xfb4a623c19a834da,
+            0x2d4ad72d40b9daf1,
+            0xe5aea58420d85803,
+            0x03cc2c97678409e5,
+            0xd623f3e7f689a1ef,
+            0xdec29a1cfba4fe09,
+            0x86080ed19e15ad66,
+            0x0000000000001181,
+        ])),
+    },
+    // Fq2(u + 2)**(((2 * q^4) - 1) / 3)
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x1411710cf60202c8,
+            0x66e69ee38f2f8e88,
+            0x6280de9a92a4e382,
+            0xb29d7462344c7831,
+            0x7f965ccd9ee433ba,
+            0x0087fb5a1511f6b7,
+            0x318cc43c76021fef,
+            0x0000000000000078,
+        ])),
+        c1: Fq(FqRepr([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+    },
+    // Fq2(u + 2)**(((2 * q^5) - 1) / 3)
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x2feed8efdf121649,
+            0xf140324d3486c3fb,
+            0xf449c127131f96bc,
+            0xc6f20ce33b600f21,
+            0x17689f7dee58e2fd,
+            0xa0e0b83e14207d5a,
+            0x944c1f64c7659ccd,
+            0x00000000000009ff,
+        ])),
+        c1: Fq(FqRepr([
+            0x9c2530d1bd9ea4c5,
+            0x49dff1ae4100c609,
+            0x6f1587fae8c67c71,
+            0x00a81e520c019f86,
+            0x58bdc7c484e87c0c,
+            0xd6b7ae91e4affb2e,
+            0x5c49e5a5c3f044c9,
+            0x000000000000089e,
+        ])),
+    },
+];
+
+// non_residue^((modulus^i-1)/6) for i=0,...,11
+pub const FROBENIUS_COEFF_FQ12_C1: [Fq2; 12] = [
+    // Fq2(u + 2)**(((q^0) - 1) / 6)
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x3e11eeb41eee70a8,
+            0x2eea3e13fec5661f,
+            0x31530e1535b28153,
+            0x68ceb6920acb7c40,
+            0xfd303bfd856dff73,
+            0xffaffe8680658205,
+            0xfde7f1801dfec009,
+            0x0000000000000fe5,
+        ])),
+        c1: Fq(FqRepr([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+    },
+    // Fq2(u + 2)**(((q^1) - 1) / 6)
+    Fq2 {
+        c0: Fq(FqRepr([
+            0xc0696fa7c6e23251,
+            0x9b58e25cf0f1df0d,
+            0xc14560073aab9c79,
+            0x224b6a68dfdbbac9,
+            0x7d997c2a5792eb25,
+            0x5c1a4d614a2d223b,
+            0xf1724aaf3af407ff,
+            0x00000000000020d3,
+        ])),
+        c1: Fq(FqRepr([
+            0xc419bc0bf0aeeac1,
+            0x352ddf0031b8e848,
+            0x27838eb3a4c48e55,
+            0xaa3c3bf6ff656e5e,
+            0x5753783f47b9fd4d,
+            0x9d2e7092606b4575,
+            0x7ad74e2b650ad865,
+            0x0000000000000d59,
+        ])),
+    },
+    // Fq2(u + 2)**(((q^2) - 1) / 6)
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x52235fc114f07370,
+            0x95d0dcf78df4f4a7,
+            0x93d3ecafc85764d5,
+            0x1b6c2af43f17f471,
+            0x7cc698cb2452332e,
+            0x0037f9e0957778bd,
+            0x2f74b5bc9400dff9,
+            0x000000000000105e,
+        ])),
+        c1: Fq(FqRepr([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+    },
+    // Fq2(u + 2)**(((q^3) - 1) / 6)
+    Fq2 {
+        c0: Fq(FqRepr([
+            0xa744b4c5fc01fb15,
+            0x823d62201bed442e,
+            0x2ffd5d67d17adf46,
+            0xd5d4a8d6c3f053bd,
+            0xa0a781b4efe3a136,
+            0x7f38d0fa478ee7e1,
+            0xd31bec6745d251df,
+            0x0000000000001766,
+        ])),
+        c1: Fq(FqRepr([
+            0xb632b7d50d31b7be,
+            0x074e1386d81cb165,
+            0xb9e75cb474a83cd5,
+            0x2113968cf9b309f5,
+            0xcd5924384591a768,
+            0x27744658aec3537c,
+            0xca5d6e75b321b9d2,
+            0x0000000000000c35,
+        ])),
+    },
+    // Fq2(u + 2)**(((q^4) - 1) / 6)
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x1411710cf60202c8,
+            0x66e69ee38f2f8e88,
+            0x6280de9a92a4e382,
+            0xb29d7462344c7831,
+            0x7f965ccd9ee433ba,
+            0x0087fb5a1511f6b7,
+            0x318cc43c76021fef,
+            0x0000000000000078,
+        ])),
+        c1: Fq(FqRepr([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+    },
+    // Fq2(u + 2)**(((q^5) - 1) / 6)
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x0adcf526753348d7,
+            0xdd6c76032afb6521,
+            0x6eb7fd5796363f3c,
+            0xc619cdafac16a8f3,
+            0xe2d8058a9850b6e9,
+            0x231e7a980a587d7f,
+            0x61dfa2d80d1e49e0,
+            0x0000000000001a97,
+        ])),
+        c1: Fq(FqRepr([
+            0x161aabd15c964d10,
+            0xc8a82ac6a663c91d,
+            0x9263cdf7cf4aaaef,
+            0x8967e9d7c24fab97,
+            0x35cfabf8fdd7aaf2,
+            0x8a45ccc55b4ec5e1,
+            0xcfbc216a5056e16c,
+            0x00000000000022e0,
+        ])),
+    },
+    // Fq2(u + 2)**(((q^6) - 1) / 6)
+    Fq2 {
+        c0: Fq(FqRepr([
+            0xe5efc15421250f6b,
+            0xc79db82c013a99e0,
+            0xceacf1e1c9b47b1c,
+            0xa9c1d8afbd3693bf,
+            0xc299c4027a920164,
+            0x004ff8788c9135d3,
+            0x824e0f9fe4413ff6,
+            0x000000000000141e,
+        ])),
+        c1: Fq(FqRepr([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+    },
+    // Fq2(u + 2)**(((q^7) - 1) / 6)
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x6398406079314dc2,
+            0x5b2f13e30f0e20f2,
+            0x3eba9fefc4bb5ff6,
+            0xf04524d8e8265536,
+            0x423083d5a86d15b2,
+            0xa3e5a99dc2c9959e,
+            0x8ec3b670c74bf800,
+            0x0000000000000330,
+        ])),
+        c1: Fq(FqRepr([
+            0x5fe7f3fc4f649552,
+            0xc15a173fce4717b7,
+            0xd87c71435aa26e1a,
+            0x6854534ac89ca1a1,
+            0x687687c0b846038a,
+            0x62d1866cac8b7264,
+            0x055eb2f49d35279a,
+            0x00000000000016ab,
+        ])),
+    },
+    // Fq2(u + 2)**(((q^8) - 1) / 6)
+    Fq2 {
+        c0: Fq(FqRepr([
+            0xd1de50472b230ca3,
+            0x60b71948720b0b58,
+            0x6c2c1347370f979a,
+            0xf724644d88ea1b8e,
+            0x43036734dbadcda9,
+            0xffc7fd1e777f3f1c,
+            0x50c14b636e3f2006,
+            0x00000000000013a6,
+        ])),
+        c1: Fq(FqRepr([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+    },
+    // Fq2(u + 2)**(((q^9) - 1) / 6)
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x7cbcfb42441184fe,
+            0x744a941fe412bbd1,
+            0xd002a28f2dec1d29,
+            0x3cbbe66b0411bc42,
+            0x1f227e4b101c5fa1,
+            0x80c72604c567cff8,
+            0xad1a14b8bc6dae20,
+            0x0000000000000c9d,
+        ])),
+        c1: Fq(FqRepr([
+            0x6dcef83332e1c855,
+            0xef39e2b927e34e9a,
+            0x4618a3428abebf9a,
+            0xf17cf8b4ce4f060a,
+            0xf270dbc7ba6e596f,
+            0xd88bb0a65e33645c,
+            0xb5d892aa4f1e462d,
+            0x00000000000017ce,
+        ])),
+    },
+    // Fq2(u + 2)**(((q^10) - 1) / 6)
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x0ff03efb4a117d4b,
+            0x8fa1575c70d07178,
+            0x9d7f215c6cc218ed,
+            0x5ff31adf93b597ce,
+            0x4033a332611bcd1d,
+            0xff77fba4f7e4c122,
+            0x4ea93ce38c3de010,
+            0x000000000000238c,
+        ])),
+        c1: Fq(FqRepr([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+    },
+    // Fq2(u + 2)**(((q^11) - 1) / 6)
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x1924bae1cae0373c,
+            0x191b803cd5049adf,
+            0x9148029f6930bd33,
+            0x4c76c1921beb670c,
+            0xdcf1fa7567af49ee,
+            0xdce17c67029e3a59,
+            0x1e565e47f521b61f,
+            0x000000000000096d,
+        ])),
+        c1: Fq(FqRepr([
+            0x0de70436e37d3303,
+            0x2ddfcb79599c36e3,
+            0x6d9c31ff301c5180,
+            0x8928a56a05b26468,
+            0x89fa5407022855e5,
+            0x75ba2a39b1a7f1f8,
+            0xb079dfb5b1e91e93,
+            0x0000000000000123,
+        ])),
+    },
+];
+
+// -((2**512) mod q) mod q
+pub const NEGATIVE_ONE: Fq = Fq(FqRepr([
+    0xe5efc15421250f6b,
+    0xc79db82c013a99e0,
+    0xceacf1e1c9b47b1c,
+    0xa9c1d8afbd3693bf,
+    0xc299c4027a920164,
+    0x004ff8788c9135d3,
+    0x824e0f9fe4413ff6,
+    0x000000000000141e,
+]));
+
+#[cfg(test)]
+use rand::SeedableRng;
+
+#[cfg(test)]
+use rand_xorshift::XorShiftRng;
+
+#[test]
+fn test_b_coeff() {
+    assert_eq!(B_COEFF, Fq::from_repr(FqRepr::from(5)).unwrap());
+
+    let mut xi = Fq2::one();
+    xi.mul_by_nonresidue();
+
+    let mut b = B_COEFF_FQ2;
+    b.mul_assign(&xi);
+    assert_eq!(b, Fq2 { c0: B_COEFF, c1: Fq::zero() });
+}
+
+#[test]
+fn test_neg_one() {
+    let mut o = Fq::one();
+    o.negate();
+
+    assert_eq!(NEGATIVE_ONE, o);
+}
+
+#[test]
+fn test_frob_coeffs_from_u() {
+    use bn::BnParameters;
+    use super::Bn462;
+
+    let mut nqr = Fq::one();
+    nqr.negate();
+
+    let mut xi = Fq2::one();
+    xi.mul_by_nonresidue();
+
+    let mut xi2 = xi;
+    xi2.square();
+
+    for i in 0..2 {
+        assert_eq!(FROBENIUS_COEFF_FQ2_C1[i], Bn462::frobenius_coeff(&nqr, i, 2));
+    }
+    assert_eq!(XI_TO_Q_MINUS_1_OVER_2, Bn462::frobenius_coeff(&xi, 1, 2));
+    for i in 0..6 {
+        assert_eq!(FROBENIUS_COEFF_FQ6_C1[i], Bn462::frobenius_coeff(&xi, i, 3));
+        assert_eq!(FROBENIUS_COEFF_FQ6_C2[i], Bn462::frobenius_coeff(&xi2, i, 3));
+    }
+    for i in 0..12 {
+        assert_eq!(FROBENIUS_COEFF_FQ12_C1[i], Bn462::frobenius_coeff(&xi, i, 6));
+    }
+}
+
+#[test]
+fn test_fq_is_valid() {
+    let mut a = Fq(MODULUS);
+    assert!(!a.is_valid());
+    a.0.sub_noborrow(&FqRepr::from(1));
+    assert!(a.is_valid());
+    assert!(Fq(FqRepr::from(0)).is_valid());
+
+    let mut rng = XorShiftRng::seed_from_u64(0x5dbe62598d313d76);
+
+    for _ in 0..1000 {
+        let a = Fq::rand(&mut rng);
+        assert!(a.is_valid());
+    }
+}
+
+#[test]
+fn test_fq_num_bits() {
+    assert_eq!(Fq::NUM_BITS, 462);
+    assert_eq!(Fq::CAPACITY, 461);
+}
+
+#[test]
+fn fq_field_tests() {
+    crate::tests::field::random_field_tests::<Fq>();
+    crate::tests::field::random_sqrt_tests::<Fq>();
+    crate::tests::field::random_frobenius_tests::<Fq, _>(Fq::char(), 13);
+    crate::tests::field::from_str_tests::<Fq>();
+}
+
+#[test]
+fn fq_repr_tests() {
+    crate::tests::repr::random_repr_tests::<FqRepr>();
+}
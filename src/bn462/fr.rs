@@ -0,0 +1,31 @@
+use ff::{Field, PrimeField, PrimeFieldDecodingError, PrimeFieldRepr, Rand};
+
+#[derive(PrimeField)]
+#[PrimeFieldModulus = "6701817056313037086248947066310538444882082605308124576230408038843354961099564416871567745979441241809893679037520753402159179772451651597"]
+#[PrimeFieldGenerator = "5"]
+pub struct Fr(FrRepr);
+
+#[test]
+fn test_fr_num_bits() {
+    assert_eq!(Fr::NUM_BITS, 462);
+    assert_eq!(Fr::CAPACITY, 461);
+}
+
+#[test]
+fn test_roots_of_unity() {
+    assert_eq!(Fr::S, 2);
+    assert_eq!(Fr::root_of_unity().pow([1 << Fr::S]), Fr::one());
+}
+
+#[test]
+fn fr_field_tests() {
+    crate::tests::field::random_field_tests::<Fr>();
+    crate::tests::field::random_sqrt_tests::<Fr>();
+    crate::tests::field::random_frobenius_tests::<Fr, _>(Fr::char(), 13);
+    crate::tests::field::from_str_tests::<Fr>();
+}
+
+#[test]
+fn fr_repr_tests() {
+    crate::tests::repr::random_repr_tests::<FrRepr>();
+}
@@ -0,0 +1,58 @@
+mod ec;
+mod fq;
+mod fq12;
+mod fq2;
+mod fq6;
+mod fr;
+
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(test)]
+mod tests;
+
+pub use self::ec::{
+    G1, G1Affine, G1Compressed, G1Prepared, G1Uncompressed,
+    G2, G2Affine, G2Compressed, G2Prepared, G2Uncompressed,
+};
+pub use self::fq::{Fq, FqRepr, FROBENIUS_COEFF_FQ6_C1, XI_TO_Q_MINUS_1_OVER_2};
+pub use self::fq12::Fq12;
+pub use self::fq2::Fq2;
+pub use self::fq6::Fq6;
+pub use self::fr::{Fr, FrRepr};
+
+use super::{CurveAffine, CurveProjective, Engine};
+use bn::{BnParameters, TwistType};
+
+use ff::{Field, ScalarEngine};
+
+#[derive(Clone, Debug)]
+pub struct Bn462;
+
+// U value that originates this particular curve, u = 2^114 + 2^101 - 2^14 - 1
+pub const BN_U: [u64; 2] = [0xffffffffffffbfff, 0x0004001fffffffff];
+
+impl BnParameters for Bn462 {
+    const U: &'static [u64] = &BN_U;
+    const U_IS_NEGATIVE: bool = false;
+    const TWIST_TYPE: TwistType = TwistType::D;
+}
+
+bn_engine_impl!(Bn462);
+
+#[test]
+fn bn462_engine_tests() {
+    crate::tests::engine::engine_tests::<Bn462>();
+}
+
+#[test]
+fn test_bn_parameters() {
+    use ff::PrimeField;
+
+    assert_eq!(Bn462::base_field_modulus(), Fq::char().as_ref());
+    assert_eq!(Bn462::scalar_field_modulus(), Fr::char().as_ref());
+
+    // The digits of the NAF of 6u + 2 are non-adjacent.
+    let naf = Bn462::six_u_plus_2_naf();
+    assert_eq!(naf.len(), 118);
+    assert!(naf.windows(2).all(|w| w[0] == 0 || w[1] == 0));
+}
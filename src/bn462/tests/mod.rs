@@ -0,0 +1,181 @@
+use super::*;
+use *;
+
+#[test]
+fn test_pairing_result() {
+    // Pins the pairing of the G1 and G2 generators, which changes to the
+    // Miller loop or the final exponentiation must preserve. This is a
+    // regression value, not a published test vector.
+    assert_eq!(Bn462::pairing(G1::one(), G2::one()).into_fqk(), Fq12 {
+        c0: Fq6 {
+            c0: Fq2 {
+                c0: Fq::from_str("2413061830893488214874709290314512145884901587842533693211638615336769351471868577136640507835677759817318898341636484253769061177124748940").unwrap(),
+                c1: Fq::from_str("173840661012857885912647012460161149832728922552553678819254584984909077801470211314835531974698142146262361891365760294150571203672146825").unwrap(),
+            },
+            c1: Fq2 {
+                c0: Fq::from_str("900205937173777169009296909980571068211254873366186432561830889675446773005585696077200463614871172613937463865471905605015080481977469943").unwrap(),
+                c1: Fq::from_str("1674931512225057138772247669943441995829252842678170208865229637689818502835485571757759111737479849391455899955357256898743404245691090476").unwrap(),
+            },
+            c2: Fq2 {
+                c0: Fq::from_str("3761663621297809074160303055930019124890663148798642243823042470395702856362644828931888233780499392188760225004020204861616743034083392014").unwrap(),
+                c1: Fq::from_str("3553591371123036808019168117946887348646186906823927406819260064041133920790984951334089892003962403488730522934076658093782352781453682527").unwrap(),
+            },
+        },
+        c1: Fq6 {
+            c0: Fq2 {
+                c0: Fq::from_str("1431730940147317579209526919436211578877327213947385421420843839395378451779682937133762781118599446619938283088986281422568639656342740168").unwrap(),
+                c1: Fq::from_str("1062850771863698708557451918261434824861107280270413594109094853088104602201485033530782358460330021252392066318220477165749851146578773780").unwrap(),
+            },
+            c1: Fq2 {
+                c0: Fq::from_str("2949247929901808570824665193727258568710024664816406374803491930962347571934762701767222178095218666766395738799326763762437082681550351859").unwrap(),
+                c1: Fq::from_str("4461371576001585932067308075453504176934042576093037181717763430756606968225228999080602127828602630263150586012470645243819599891826749157").unwrap(),
+            },
+            c2: Fq2 {
+                c0: Fq::from_str("2337568964287742098833169240500657780336783056935383794126576974780597194510274316091761100834668602904204717200830710473963923501272400924").unwrap(),
+                c1: Fq::from_str("6130520849391709293114842716603066754987912333213987637910416369044596682518718352401831678537047092891961115093278205430243981852605424418").unwrap(),
+            },
+        },
+    });
+}
+
+fn test_roundtrip<G: CurveProjective, E: EncodedPoint<Affine = G::Affine>>() {
+    let mut e = G::zero();
+
+    for _ in 0..100 {
+        let e_affine = e.into_affine();
+        let encoded = E::from_affine(e_affine);
+        assert_eq!(encoded.as_ref().len(), E::size());
+        assert_eq!(encoded.into_affine().unwrap(), e_affine);
+
+        e.add_assign(&G::one());
+    }
+}
+
+#[test]
+fn test_g1_uncompressed_roundtrip() {
+    test_roundtrip::<G1, G1Uncompressed>();
+}
+
+#[test]
+fn test_g1_compressed_roundtrip() {
+    test_roundtrip::<G1, G1Compressed>();
+}
+
+#[test]
+fn test_g2_uncompressed_roundtrip() {
+    test_roundtrip::<G2, G2Uncompressed>();
+}
+
+#[test]
+fn test_g2_compressed_roundtrip() {
+    test_roundtrip::<G2, G2Compressed>();
+}
+
+#[test]
+fn test_encoding_sizes() {
+    assert_eq!(G1Uncompressed::size(), 128);
+    assert_eq!(G1Compressed::size(), 64);
+    assert_eq!(G2Uncompressed::size(), 256);
+    assert_eq!(G2Compressed::size(), 128);
+}
+
+#[test]
+fn test_g1_invalid_vectors() {
+    {
+        let mut z = G1Compressed::from_affine(G1Affine::zero());
+        z.as_mut()[10] = 1;
+        if let Err(GroupDecodingError::UnexpectedInformation) = z.into_affine() {
+            // :)
+        } else {
+            panic!("should have rejected the point because it isn't completely zero");
+        }
+    }
+
+    {
+        let mut o = G1Uncompressed::from_affine(G1Affine::one());
+        o.as_mut()[0] |= 0b1000_0000;
+        if let Err(GroupDecodingError::UnexpectedInformation) = o.into_affine() {
+            // :)
+        } else {
+            panic!("should have rejected the point because the parity bit should not be set");
+        }
+    }
+
+    {
+        let mut o = G1Uncompressed::from_affine(G1Affine::one());
+        Fq::char().write_be(&mut o.as_mut()[0..64]).unwrap();
+        if let Err(GroupDecodingError::CoordinateDecodingError(..)) = o.into_affine() {
+            // :)
+        } else {
+            panic!("should have rejected the point because the x coordinate isn't in the field");
+        }
+    }
+
+    {
+        let mut o = G1Uncompressed::from_affine(G1Affine::one());
+        o.as_mut()[127] ^= 1;
+        if let Err(GroupDecodingError::NotOnCurve) = o.into_affine() {
+            // :)
+        } else {
+            panic!("should have rejected the point because it isn't on the curve");
+        }
+    }
+
+    {
+        // Find an x-coordinate for which x^3 + 5 is not a square.
+        let mut x = Fq::zero();
+        loop {
+            let mut x3b = x;
+            x3b.square();
+            x3b.mul_assign(&x);
+            x3b.add_assign(&Fq::from_str("5").unwrap());
+
+            if x3b.sqrt().is_none() {
+                break;
+            }
+
+            x.add_assign(&Fq::one());
+        }
+
+        let mut o = G1Compressed::empty();
+        x.into_repr().write_be(&mut o.as_mut()[..]).unwrap();
+        if let Err(GroupDecodingError::NotOnCurve) = o.into_affine() {
+            // :)
+        } else {
+            panic!("should have rejected the point because it isn't on the curve");
+        }
+    }
+}
+
+#[test]
+fn test_g2_invalid_vectors() {
+    {
+        let mut z = G2Compressed::from_affine(G2Affine::zero());
+        z.as_mut()[10] = 1;
+        if let Err(GroupDecodingError::UnexpectedInformation) = z.into_affine() {
+            // :)
+        } else {
+            panic!("should have rejected the point because it isn't completely zero");
+        }
+    }
+
+    {
+        let mut o = G2Uncompressed::from_affine(G2Affine::one());
+        o.as_mut()[0] |= 0b1000_0000;
+        if let Err(GroupDecodingError::UnexpectedCompressionMode) = o.into_affine() {
+            // :)
+        } else {
+            panic!("should have rejected the point because we expected an uncompressed point");
+        }
+    }
+
+    {
+        let mut o = G2Uncompressed::from_affine(G2Affine::one());
+        o.as_mut()[255] ^= 1;
+        if let Err(GroupDecodingError::NotOnCurve) = o.into_affine() {
+            // :)
+        } else {
+            panic!("should have rejected the point because it isn't on the curve");
+        }
+    }
+}
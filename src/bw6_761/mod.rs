@@ -0,0 +1,493 @@
+mod ec;
+mod fq;
+mod fq3;
+mod fq6;
+
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(test)]
+mod tests;
+
+pub use self::ec::{
+    G1, G1Affine, G1Compressed, G1Prepared, G1Uncompressed, G2, G2Affine, G2Compressed, G2Prepared,
+    G2Uncompressed,
+};
+pub use self::fq::{Fq, FqRepr};
+pub use self::fq3::Fq3;
+pub use self::fq6::Fq6;
+// The scalar field of BW6-761 is the base field of BLS12-377.
+pub use bls12_377::{Fq as Fr, FqRepr as FrRepr};
+
+use super::{CurveAffine, Engine};
+
+use ff::{BitIterator, Field, ScalarEngine};
+
+// The parameter x of the underlying BLS12-377 curve is 0x8508c00000000001
+const BLS_X: u64 = 0x8508c00000000001;
+
+// The first Miller loop runs over x + 1
+const ATE_LOOP_COUNT_1: u64 = 0x8508c00000000002;
+
+// NAF of x^3 - x^2 - x, least significant digit first, for the second Miller loop
+pub const ATE_LOOP_COUNT_2_NAF: [i8; 190] = [
+    -1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    1, 0, 0, -1, 0, 1, 0, -1, 0, 0, 0, 0, -1, 0, 1, 0,
+    0, 0, 1, 0, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 0, 1,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -1, 0, -1, 0, 0, 0,
+    0, -1, 0, 0, 1, 0, 0, 0, -1, 0, 0, -1, 0, 1, 0, -1,
+    0, 0, 0, 1, 0, 0, 1, 0, -1, 0, 1, 0, 1, 0, 0, 0,
+    1, 0, -1, 0, -1, 0, 0, 0, 0, 0, 1, 0, 0, 1,
+];
+
+#[derive(Clone, Debug)]
+pub struct Bw6_761;
+
+impl ScalarEngine for Bw6_761 {
+    type Fr = Fr;
+}
+
+gt_impl!(Bw6_761, Fq6, [c1.c2, c1.c1, c1.c0, c0.c2, c0.c1, c0.c0]);
+
+impl Engine for Bw6_761 {
+    type G1 = G1;
+    type G1Affine = G1Affine;
+    type G2 = G2;
+    type G2Affine = G2Affine;
+    type Fq = Fq;
+    type Fqe = Fq;
+    type Fqk = Fq6;
+    type Gt = Gt;
+
+    fn miller_loop<'a, I>(i: I) -> Self::Fqk
+    where
+        I: IntoIterator<
+            Item = &'a (
+                &'a <Self::G1Affine as CurveAffine>::Prepared,
+                &'a <Self::G2Affine as CurveAffine>::Prepared,
+            ),
+        >,
+    {
+        // Shares the squarings of the loop between the pairs `i`, which the
+        // `parallel` feature splits into chunks across threads.
+        fn multi_miller_loop(i: &[(&G1Prepared, &G2Prepared)]) -> Fq6 {
+            // Optimal ate pairing of https://eprint.iacr.org/2020/351,
+            // f_{x+1,Q}(P) * f_{x^3-x^2-x,Q}(P)^q
+            let mut pairs_1 = vec![];
+            let mut pairs_2 = vec![];
+            for &(p, q) in i {
+                if !p.is_zero() && !q.is_zero() {
+                    pairs_1.push((p, q.coeffs_1.iter()));
+                    pairs_2.push((p, q.coeffs_2.iter()));
+                }
+            }
+
+            // Twisting isomorphism from E to E'
+            fn ell(f: &mut Fq6, coeffs: &(Fq, Fq, Fq), p: &G1Affine) {
+                let mut c0 = coeffs.0;
+                let mut c1 = coeffs.1;
+
+                c0.mul_assign(&p.y);
+                c1.mul_assign(&p.x);
+
+                // Sparse multiplication in Fq6
+                f.mul_by_014(&coeffs.2, &c1, &c0);
+            }
+
+            let mut f1 = Fq6::one();
+
+            let mut found_one = false;
+            for i in BitIterator::new(&[ATE_LOOP_COUNT_1]) {
+                if !found_one {
+                    found_one = i;
+                    continue;
+                }
+
+                f1.square();
+
+                for &mut (p, ref mut coeffs) in &mut pairs_1 {
+                    ell(&mut f1, coeffs.next().unwrap(), &p.0);
+                }
+
+                if i {
+                    for &mut (p, ref mut coeffs) in &mut pairs_1 {
+                        ell(&mut f1, coeffs.next().unwrap(), &p.0);
+                    }
+                }
+            }
+
+            let mut f2 = Fq6::one();
+
+            for i in (1..ATE_LOOP_COUNT_2_NAF.len()).rev() {
+                if i != ATE_LOOP_COUNT_2_NAF.len() - 1 {
+                    f2.square();
+                }
+
+                for &mut (p, ref mut coeffs) in &mut pairs_2 {
+                    ell(&mut f2, coeffs.next().unwrap(), &p.0);
+                }
+
+                match ATE_LOOP_COUNT_2_NAF[i - 1] {
+                    1 | -1 => {
+                        for &mut (p, ref mut coeffs) in &mut pairs_2 {
+                            ell(&mut f2, coeffs.next().unwrap(), &p.0);
+                        }
+                    }
+                    _ => continue,
+                }
+            }
+
+            f2.frobenius_map(1);
+            f1.mul_assign(&f2);
+
+            f1
+        }
+
+        let pairs: Vec<_> = i.into_iter().map(|&(p, q)| (p, q)).collect();
+
+        #[cfg(feature = "parallel")]
+        let f = ::parallel::miller_loop(&pairs, multi_miller_loop);
+        #[cfg(not(feature = "parallel"))]
+        let f = multi_miller_loop(&pairs);
+
+        f
+    }
+
+    fn final_exponentiation(r: &Fq6) -> Option<Fq6> {
+        let mut f1 = *r;
+        f1.conjugate();
+
+        match r.inverse() {
+            Some(mut f2) => {
+                // Easy part: (q^3 - 1) * (q + 1)
+                let mut r = f1;
+                r.mul_assign(&f2);
+                f2 = r;
+                r.frobenius_map(1);
+                r.mul_assign(&f2);
+
+                fn exp_by_x(f: &Fq6) -> Fq6 {
+                    f.pow([BLS_X])
+                }
+
+                fn frob(f: &Fq6) -> Fq6 {
+                    let mut f = *f;
+                    f.frobenius_map(1);
+                    f
+                }
+
+                fn mul(fs: &[&Fq6]) -> Fq6 {
+                    let mut res = *fs[0];
+                    for f in &fs[1..] {
+                        res.mul_assign(f);
+                    }
+                    res
+                }
+
+                fn conj(f: Fq6) -> Fq6 {
+                    let mut f = f;
+                    f.conjugate();
+                    f
+                }
+
+                fn square(f: Fq6) -> Fq6 {
+                    let mut f = f;
+                    f.square();
+                    f
+                }
+
+                // Hard part of the final exponentiation, Algorithm 6 of
+                // https://eprint.iacr.org/2020/351. Inversion in the
+                // cyclotomic subgroup is conjugation.
+                let f0 = r;
+                let f0p = frob(&f0);
+                let f1 = exp_by_x(&f0);
+                let f1p = frob(&f1);
+                let f2 = exp_by_x(&f1);
+                let f2p = frob(&f2);
+                let f3 = exp_by_x(&f2);
+                let f3p = frob(&f3);
+                let f4 = exp_by_x(&f3);
+                let f4p = frob(&f4);
+                let f5 = exp_by_x(&f4);
+                let f5p = frob(&f5);
+                let f6 = exp_by_x(&f5);
+                let f6p = frob(&f6);
+                let f7 = exp_by_x(&f6);
+                let f7p = frob(&f7);
+
+                let f8p = exp_by_x(&f7p);
+                let f9p = exp_by_x(&f8p);
+
+                let result1 = mul(&[&f3p, &f6p, &conj(f5p)]);
+
+                let result2 = square(result1);
+                let f4_2p = mul(&[&f4, &f2p]);
+                let tmp1_p3 = conj(mul(&[&f0, &f1, &f3, &f4_2p, &f8p]));
+                let result3 = mul(&[&result2, &f5, &f0p, &tmp1_p3]);
+
+                let result4 = square(result3);
+                let result5 = mul(&[&result4, &f9p, &conj(f7)]);
+
+                let result6 = square(result5);
+                let f2_4p = mul(&[&f2, &f4p]);
+                let f4_2p_5p = mul(&[&f4_2p, &f5p]);
+                let tmp2_p3 = conj(mul(&[&f2_4p, &f3, &f3p]));
+                let result7 = mul(&[&result6, &f4_2p_5p, &f6, &f7p, &tmp2_p3]);
+
+                let result8 = square(result7);
+                let tmp3_p3 = conj(mul(&[&f0p, &f9p]));
+                let result9 = mul(&[&result8, &f0, &f7, &f1p, &tmp3_p3]);
+
+                let result10 = square(result9);
+                let f6p_8p = mul(&[&f6p, &f8p]);
+                let f5_7p = mul(&[&f5, &f7p]);
+                let tmp4_p3 = conj(f6p_8p);
+                let result11 = mul(&[&result10, &f5_7p, &f2p, &tmp4_p3]);
+
+                let result12 = square(result11);
+                let f3_6 = mul(&[&f3, &f6]);
+                let f1_7 = mul(&[&f1, &f7]);
+                let tmp5_p3 = conj(mul(&[&f1_7, &f2]));
+                let result13 = mul(&[&result12, &f3_6, &f9p, &tmp5_p3]);
+
+                let result14 = square(result13);
+                let tmp6_p3 = conj(mul(&[&f4_2p, &f5_7p, &f6p_8p]));
+                let result15 = mul(&[&result14, &f0, &f0p, &f3p, &f5p, &tmp6_p3]);
+
+                let result16 = square(result15);
+                let tmp7_p3 = conj(f3_6);
+                let result17 = mul(&[&result16, &f1p, &tmp7_p3]);
+
+                let result18 = square(result17);
+                let tmp8_p3 = conj(mul(&[&f2_4p, &f4_2p_5p, &f9p]));
+                let result19 = mul(&[&result18, &f1_7, &f5_7p, &f0p, &tmp8_p3]);
+
+                Some(result19)
+            }
+            None => None,
+        }
+    }
+}
+
+impl G2Prepared {
+    pub fn is_zero(&self) -> bool {
+        self.infinity
+    }
+
+    pub fn from_affine(q: G2Affine) -> Self {
+        if q.is_zero() {
+            return G2Prepared {
+                coeffs_1: vec![],
+                coeffs_2: vec![],
+                infinity: true,
+            };
+        }
+
+        fn doubling_step(r: &mut G2) -> (Fq, Fq, Fq) {
+            // Adaptation of Algorithm 26, https://eprint.iacr.org/2010/354.pdf
+            let mut tmp0 = r.x;
+            tmp0.square();
+
+            let mut tmp1 = r.y;
+            tmp1.square();
+
+            let mut tmp2 = tmp1;
+            tmp2.square();
+
+            let mut tmp3 = tmp1;
+            tmp3.add_assign(&r.x);
+            tmp3.square();
+            tmp3.sub_assign(&tmp0);
+            tmp3.sub_assign(&tmp2);
+            tmp3.double();
+
+            let mut tmp4 = tmp0;
+            tmp4.double();
+            tmp4.add_assign(&tmp0);
+
+            let mut tmp6 = r.x;
+            tmp6.add_assign(&tmp4);
+
+            let mut tmp5 = tmp4;
+            tmp5.square();
+
+            let mut zsquared = r.z;
+            zsquared.square();
+
+            r.x = tmp5;
+            r.x.sub_assign(&tmp3);
+            r.x.sub_assign(&tmp3);
+
+            r.z.add_assign(&r.y);
+            r.z.square();
+            r.z.sub_assign(&tmp1);
+            r.z.sub_assign(&zsquared);
+
+            r.y = tmp3;
+            r.y.sub_assign(&r.x);
+            r.y.mul_assign(&tmp4);
+
+            tmp2.double();
+            tmp2.double();
+            tmp2.double();
+
+            r.y.sub_assign(&tmp2);
+
+            tmp3 = tmp4;
+            tmp3.mul_assign(&zsquared);
+            tmp3.double();
+            tmp3.negate();
+
+            tmp6.square();
+            tmp6.sub_assign(&tmp0);
+            tmp6.sub_assign(&tmp5);
+
+            tmp1.double();
+            tmp1.double();
+
+            tmp6.sub_assign(&tmp1);
+
+            tmp0 = r.z;
+            tmp0.mul_assign(&zsquared);
+            tmp0.double();
+
+            (tmp0, tmp3, tmp6)
+        }
+
+        fn addition_step(r: &mut G2, q: &G2Affine) -> (Fq, Fq, Fq) {
+            // Adaptation of Algorithm 27, https://eprint.iacr.org/2010/354.pdf
+            let mut zsquared = r.z;
+            zsquared.square();
+
+            let mut ysquared = q.y;
+            ysquared.square();
+
+            let mut t0 = zsquared;
+            t0.mul_assign(&q.x);
+
+            let mut t1 = q.y;
+            t1.add_assign(&r.z);
+            t1.square();
+            t1.sub_assign(&ysquared);
+            t1.sub_assign(&zsquared);
+            t1.mul_assign(&zsquared);
+
+            let mut t2 = t0;
+            t2.sub_assign(&r.x);
+
+            let mut t3 = t2;
+            t3.square();
+
+            let mut t4 = t3;
+            t4.double();
+            t4.double();
+
+            let mut t5 = t4;
+            t5.mul_assign(&t2);
+
+            let mut t6 = t1;
+            t6.sub_assign(&r.y);
+            t6.sub_assign(&r.y);
+
+            let mut t9 = t6;
+            t9.mul_assign(&q.x);
+
+            let mut t7 = t4;
+            t7.mul_assign(&r.x);
+
+            r.x = t6;
+            r.x.square();
+            r.x.sub_assign(&t5);
+            r.x.sub_assign(&t7);
+            r.x.sub_assign(&t7);
+
+            r.z.add_assign(&t2);
+            r.z.square();
+            r.z.sub_assign(&zsquared);
+            r.z.sub_assign(&t3);
+
+            let mut t10 = q.y;
+            t10.add_assign(&r.z);
+
+            let mut t8 = t7;
+            t8.sub_assign(&r.x);
+            t8.mul_assign(&t6);
+
+            t0 = r.y;
+            t0.mul_assign(&t5);
+            t0.double();
+
+            r.y = t8;
+            r.y.sub_assign(&t0);
+
+            t10.square();
+            t10.sub_assign(&ysquared);
+
+            let mut ztsquared = r.z;
+            ztsquared.square();
+
+            t10.sub_assign(&ztsquared);
+
+            t9.double();
+            t9.sub_assign(&t10);
+
+            t10 = r.z;
+            t10.double();
+
+            t6.negate();
+
+            t1 = t6;
+            t1.double();
+
+            (t10, t1, t9)
+        }
+
+        let mut coeffs_1 = vec![];
+        let mut r: G2 = q.into();
+
+        let mut found_one = false;
+        for i in BitIterator::new([ATE_LOOP_COUNT_1]) {
+            if !found_one {
+                found_one = i;
+                continue;
+            }
+
+            coeffs_1.push(doubling_step(&mut r));
+
+            if i {
+                coeffs_1.push(addition_step(&mut r, &q));
+            }
+        }
+
+        let mut coeffs_2 = vec![];
+        let mut r: G2 = q.into();
+        let mut negq = q;
+        negq.negate();
+
+        for i in (1..ATE_LOOP_COUNT_2_NAF.len()).rev() {
+            coeffs_2.push(doubling_step(&mut r));
+
+            match ATE_LOOP_COUNT_2_NAF[i - 1] {
+                1 => coeffs_2.push(addition_step(&mut r, &q)),
+                -1 => coeffs_2.push(addition_step(&mut r, &negq)),
+                _ => continue,
+            }
+        }
+
+        G2Prepared {
+            coeffs_1,
+            coeffs_2,
+            infinity: false,
+        }
+    }
+}
+
+#[test]
+fn bw6_761_engine_tests() {
+    ::tests::engine::engine_tests::<Bw6_761>();
+}
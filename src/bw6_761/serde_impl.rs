@@ -0,0 +1,257 @@
+use super::{Fq, FqRepr, G1Affine, G2Affine, G1, G2};
+use hex;
+use std::fmt;
+use std::marker::PhantomData;
+use {CurveAffine, CurveProjective, EncodedPoint, PrimeField};
+
+use serde::de::{Error as DeserializeError, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+const ERR_CODE: &str = "deserialized bytes do not encode a group element";
+
+impl Serialize for G1 {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        self.into_affine().serialize(s)
+    }
+}
+
+impl<'de> Deserialize<'de> for G1 {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        Ok(G1Affine::deserialize(d)?.into_projective())
+    }
+}
+
+impl Serialize for G1Affine {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        serialize_affine(self, s)
+    }
+}
+
+impl<'de> Deserialize<'de> for G1Affine {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        deserialize_affine(d)
+    }
+}
+
+impl Serialize for G2 {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        self.into_affine().serialize(s)
+    }
+}
+
+impl<'de> Deserialize<'de> for G2 {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        Ok(G2Affine::deserialize(d)?.into_projective())
+    }
+}
+
+impl Serialize for G2Affine {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        serialize_affine(self, s)
+    }
+}
+
+impl<'de> Deserialize<'de> for G2Affine {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        deserialize_affine(d)
+    }
+}
+
+/// Serializes a group element using its compressed representation.
+fn serialize_affine<S: Serializer, C: CurveAffine>(c: &C, s: S) -> Result<S::Ok, S::Error> {
+    let _len = C::Compressed::size();
+    let mut w = String::new();
+    for byte in c.into_compressed().as_ref() {
+        let t = format!("{:02x}", byte);
+        w = w + &t;
+    }
+    s.collect_str(&w)
+}
+
+/// Deserializes the compressed representation of a group element.
+fn deserialize_affine<'de, D: Deserializer<'de>, C: CurveAffine>(d: D) -> Result<C, D::Error> {
+    struct TupleVisitor<C> {
+        _ph: PhantomData<C>,
+    }
+
+    impl<'de, C: CurveAffine> Visitor<'de> for TupleVisitor<C> {
+        type Value = C;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            let len = C::Compressed::size();
+            write!(f, "a tuple of size {}", len)
+        }
+
+        #[inline]
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<C, A::Error> {
+            let mut compressed = C::Compressed::empty();
+            for (i, byte) in compressed.as_mut().iter_mut().enumerate() {
+                let len_err = || DeserializeError::invalid_length(i, &self);
+                *byte = seq.next_element()?.ok_or_else(len_err)?;
+            }
+            let to_err = |_| DeserializeError::custom(ERR_CODE);
+            compressed.into_affine().map_err(to_err)
+        }
+
+        #[inline]
+        fn visit_str<E>(self, v: &str) -> Result<C, E>
+            where
+                E: ::serde::de::Error,
+        {
+            let mut compressed = C::Compressed::empty();
+            let _len = C::Compressed::size();
+            //let len_err = || DeserializeError::invalid_length(len, &self);
+            let w = hex::decode(v).unwrap();
+            if w.len() == _len {
+                for (i, byte) in compressed.as_mut().iter_mut().enumerate() {
+                    *byte = w[i];
+                }
+            }
+            let to_err = |_| DeserializeError::custom(ERR_CODE);
+            compressed.into_affine().map_err(to_err)
+        }
+    }
+
+    d.deserialize_str(TupleVisitor { _ph: PhantomData })
+}
+
+fn transform_u64_to_array_of_u8(x: u64) -> [u8; 8] {
+    let b1: u8 = ((x >> 56) & 0xff) as u8;
+    let b2: u8 = ((x >> 48) & 0xff) as u8;
+    let b3: u8 = ((x >> 40) & 0xff) as u8;
+    let b4: u8 = ((x >> 32) & 0xff) as u8;
+    let b5: u8 = ((x >> 24) & 0xff) as u8;
+    let b6: u8 = ((x >> 16) & 0xff) as u8;
+    let b7: u8 = ((x >> 8) & 0xff) as u8;
+    let b8: u8 = (x & 0xff) as u8;
+    [b1, b2, b3, b4, b5, b6, b7, b8]
+}
+
+fn transform_bytes_to_u64(x: &[u8]) -> u64 {
+    let mut u: u64 = 0;
+    let len = x.len() - 1;
+    for i in 0..8 {
+        let t: u64 = (x[len - i] as u64) << (i * 8);
+        u += t;
+    }
+    u
+}
+
+impl Serialize for Fq {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        self.into_repr().serialize(s)
+    }
+}
+
+impl<'de> Deserialize<'de> for Fq {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        Fq::from_repr(FqRepr::deserialize(d)?).map_err(|_| D::Error::custom(ERR_CODE))
+    }
+}
+
+impl Serialize for FqRepr {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        // self.0.serialize(s)
+        let r = self.0;
+        let mut v = String::new();
+        for byte in r.as_ref() {
+            let byte_array = transform_u64_to_array_of_u8(*byte);
+            let hex_str = hex::encode(byte_array);
+            v += &hex_str;
+        }
+        s.serialize_str(&v)
+    }
+}
+
+impl<'de> Deserialize<'de> for FqRepr {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        struct FqReprTupleVisitor;
+
+        impl<'de> Visitor<'de> for FqReprTupleVisitor {
+            type Value = FqRepr;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "sequence of bytes representing FqRepr element")
+            }
+
+            #[inline]
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                where E: ::serde::de::Error,
+            {
+                match v.len() == 192 {
+                    true => {
+                        let mut bytes: Vec<u64> = Vec::new();
+                        for i in 0..12 {
+                            if let Ok(c) = hex::decode(&v[16 * i..16 * (i + 1)]) {
+                                bytes.push(transform_bytes_to_u64(&c));
+                            }
+                        }
+
+                        let mut byte_slice: [u64; 12] = [0; 12];
+                        if bytes.len() == 12 {
+                            byte_slice.copy_from_slice(&bytes[0..12]);
+                        }
+                        Ok(FqRepr(byte_slice))
+                    }
+                    false => Err(serde::de::Error::custom("invalid length: expected 192 bytes")),
+                }
+            }
+        }
+        d.deserialize_str(FqReprTupleVisitor {})
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate serde_json;
+
+    use super::*;
+    use super::super::Fq6;
+
+    use std::fmt::Debug;
+
+    use rand::{Rng, SeedableRng};
+    use rand_xorshift::XorShiftRng;
+
+    fn test_roundtrip<T: Serialize + for<'a> Deserialize<'a> + Debug + PartialEq>(t: &T) {
+        let ser = serde_json::to_vec(t).unwrap();
+        //println!("Bytes: {:?}", ser);
+        assert_eq!(*t, serde_json::from_slice(&ser).unwrap());
+
+        let ser2 = serde_json::to_string(t).unwrap();
+        //println!("String: {}", ser2);
+        assert_eq!(*t, serde_json::from_str(&ser2).unwrap());
+    }
+
+    #[test]
+    fn serde_g1() {
+        let mut rng = XorShiftRng::seed_from_u64(0x5dbe62598d313d76);
+
+        let g: G1 = rng.gen();
+        test_roundtrip(&g);
+        test_roundtrip(&g.into_affine());
+    }
+
+    #[test]
+    fn serde_g2() {
+        let mut rng = XorShiftRng::seed_from_u64(0x5dbe62598d313d76);
+        let g: G2 = rng.gen();
+        test_roundtrip(&g);
+        test_roundtrip(&g.into_affine());
+    }
+
+    #[test]
+    fn serde_fq() {
+        let mut rng = XorShiftRng::seed_from_u64(0x5dbe62598d313d76);
+        let f: Fq = rng.gen();
+        test_roundtrip(&f);
+        test_roundtrip(&f.into_repr());
+    }
+
+    #[test]
+    fn serde_fq6() {
+        let mut rng = XorShiftRng::seed_from_u64(0x5dbe62598d313d76);
+        let f: Fq6 = rng.gen();
+        test_roundtrip(&f);
+    }
+}
@@ -0,0 +1,177 @@
+use super::*;
+use *;
+
+#[test]
+fn test_pairing_result_against_zexe() {
+    // The pairing of the G1 and G2 generators, as computed by the Zexe
+    // implementation of BW6-761.
+    assert_eq!(Bw6_761::pairing(G1::one(), G2::one()).into_fqk(), Fq6 {
+        c0: Fq3 {
+            c0: Fq::from_str("4510359315642227161300715766397545556060182605403241648801789370353477309462521350998880238958915684804523297488248625719737311381543440214724826547416498637008922626849943405027704710434689744648710017398435350900380351408493227").unwrap(),
+            c1: Fq::from_str("2677421629806215500806374773494921918675260116997128590406249167607604421353143499647685615215133798883010253187869855407990593197342263560701888286465092386101436194808729665123292088377996674810813489252294255506973657256796127").unwrap(),
+            c2: Fq::from_str("3976766090859900852703463370026860279880422608326815365561040580965799094333004758322208512066826358904120163684802145676604172599221743767572376801874074766451260729508938844015867466784863819469472052110434651220443272970489651").unwrap(),
+        },
+        c1: Fq3 {
+            c0: Fq::from_str("3709287954122113183919252157974400788756466395106709646860104736567456788058033905434926535096699247546275309200430008179688078433506037355040353310086506847272033468245912942862897718410214737478825896371289449148073965790008969").unwrap(),
+            c1: Fq::from_str("3467905554472049697379056752471586471141846174914722326556383883651264453151636148118910828445448948508066344012679244660430957552527508931990570251003323298494249525000875448025475194429507092982919052644788021044516574038867088").unwrap(),
+            c2: Fq::from_str("1693568671091759495770035841457434631754426699993540132922794877875489416679169396697315733165001502917497927089762327791709905185484582930258467544801079628080551869924959221294027045295564501793709299816866379925731795960244593").unwrap(),
+        }
+    });
+}
+
+fn test_roundtrip<G: CurveProjective, E: EncodedPoint<Affine = G::Affine>>() {
+    let mut e = G::zero();
+
+    for _ in 0..100 {
+        let e_affine = e.into_affine();
+        let encoded = E::from_affine(e_affine);
+        assert_eq!(encoded.as_ref().len(), E::size());
+        assert_eq!(encoded.into_affine().unwrap(), e_affine);
+
+        e.add_assign(&G::one());
+    }
+}
+
+#[test]
+fn test_g1_uncompressed_roundtrip() {
+    test_roundtrip::<G1, G1Uncompressed>();
+}
+
+#[test]
+fn test_g1_compressed_roundtrip() {
+    test_roundtrip::<G1, G1Compressed>();
+}
+
+#[test]
+fn test_g2_uncompressed_roundtrip() {
+    test_roundtrip::<G2, G2Uncompressed>();
+}
+
+#[test]
+fn test_g2_compressed_roundtrip() {
+    test_roundtrip::<G2, G2Compressed>();
+}
+
+#[test]
+fn test_g1_compressed_invalid_vectors() {
+    {
+        let z = G1Compressed::from_affine(G1Affine::zero());
+
+        {
+            let mut z = z;
+            z.as_mut()[0] &= 0b0111_1111;
+            if let Err(GroupDecodingError::UnexpectedCompressionMode) = z.into_affine() {
+                // :)
+            } else {
+                panic!("should have rejected the point because we expected a compressed point");
+            }
+        }
+
+        {
+            let mut z = z;
+            z.as_mut()[0] |= 0b0010_0000;
+            if let Err(GroupDecodingError::UnexpectedInformation) = z.into_affine() {
+                // :)
+            } else {
+                panic!("should have rejected the point because the parity bit should not be set if the point is at infinity");
+            }
+        }
+
+        for i in 0..G1Compressed::size() {
+            let mut z = z;
+            z.as_mut()[i] |= 0b0000_0001;
+            if let Err(GroupDecodingError::UnexpectedInformation) = z.into_affine() {
+                // :)
+            } else {
+                panic!("should have rejected the point because the coordinates should be zeroes at the point at infinity");
+            }
+        }
+    }
+
+    let o = G1Compressed::from_affine(G1Affine::one());
+
+    {
+        let mut o = o;
+        o.as_mut()[0] &= 0b0111_1111;
+        if let Err(GroupDecodingError::UnexpectedCompressionMode) = o.into_affine() {
+            // :)
+        } else {
+            panic!("should have rejected the point because we expected a compressed point");
+        }
+    }
+
+    {
+        let mut o = o;
+        for b in &mut o.as_mut()[..] {
+            *b = 0xff;
+        }
+        o.as_mut()[0] = 0b1001_1111;
+        if let Err(GroupDecodingError::CoordinateDecodingError(coordinate, _)) = o.into_affine() {
+            assert_eq!(coordinate, "x coordinate");
+        } else {
+            panic!("should have rejected the point because the x coordinate isn't in the field");
+        }
+    }
+}
+
+#[test]
+fn test_g2_compressed_invalid_vectors() {
+    {
+        let z = G2Compressed::from_affine(G2Affine::zero());
+
+        {
+            let mut z = z;
+            z.as_mut()[0] &= 0b0111_1111;
+            if let Err(GroupDecodingError::UnexpectedCompressionMode) = z.into_affine() {
+                // :)
+            } else {
+                panic!("should have rejected the point because we expected a compressed point");
+            }
+        }
+
+        {
+            let mut z = z;
+            z.as_mut()[0] |= 0b0010_0000;
+            if let Err(GroupDecodingError::UnexpectedInformation) = z.into_affine() {
+                // :)
+            } else {
+                panic!("should have rejected the point because the parity bit should not be set if the point is at infinity");
+            }
+        }
+
+        for i in 0..G2Compressed::size() {
+            let mut z = z;
+            z.as_mut()[i] |= 0b0000_0001;
+            if let Err(GroupDecodingError::UnexpectedInformation) = z.into_affine() {
+                // :)
+            } else {
+                panic!("should have rejected the point because the coordinates should be zeroes at the point at infinity");
+            }
+        }
+    }
+
+    let o = G2Compressed::from_affine(G2Affine::one());
+
+    {
+        let mut o = o;
+        o.as_mut()[0] &= 0b0111_1111;
+        if let Err(GroupDecodingError::UnexpectedCompressionMode) = o.into_affine() {
+            // :)
+        } else {
+            panic!("should have rejected the point because we expected a compressed point");
+        }
+    }
+
+    {
+        let mut o = o;
+        for b in &mut o.as_mut()[..] {
+            *b = 0xff;
+        }
+        o.as_mut()[0] = 0b1001_1111;
+        if let Err(GroupDecodingError::CoordinateDecodingError(coordinate, _)) = o.into_affine() {
+            assert_eq!(coordinate, "x coordinate");
+        } else {
+            panic!("should have rejected the point because the x coordinate isn't in the field");
+        }
+    }
+}
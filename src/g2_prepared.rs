@@ -0,0 +1,117 @@
+/// Implements the encoding of `G2Prepared` for the pairings whose Miller
+/// loop consumes one line of three `Fq2` coefficients per step, given the
+/// private `G2Prepared::num_lines` of the engine.
+///
+/// The encoding lists the version, a byte set to one for the point at
+/// infinity, the number of lines as a big-endian u32, and the three
+/// coefficients of every line, each element of Fq2 as c1 then c0 in
+/// big-endian, as in the encoding of G2.
+macro_rules! g2_prepared_impl {
+    () => {
+        impl G2Prepared {
+            /// The version of the encoding of `into_bytes`.
+            pub const ENCODING_VERSION: u8 = 1;
+
+            /// Encodes the line coefficients.
+            pub fn into_bytes(&self) -> Vec<u8> {
+                let len = self.coeffs.len() as u32;
+                let mut res = vec![
+                    G2Prepared::ENCODING_VERSION,
+                    self.infinity as u8,
+                    (len >> 24) as u8,
+                    (len >> 16) as u8,
+                    (len >> 8) as u8,
+                    len as u8,
+                ];
+                for &(ref c0, ref c1, ref c2) in &self.coeffs {
+                    for c in &[c0, c1, c2] {
+                        for c in &[c.c1, c.c0] {
+                            ::ff::PrimeFieldRepr::write_be(&::ff::PrimeField::into_repr(c), &mut res)
+                                .unwrap();
+                        }
+                    }
+                }
+                res
+            }
+
+            /// Decodes line coefficients encoded by `into_bytes`, only checking
+            /// the version and that there are as many lines as the miller loop
+            /// expects, so the bytes must come from a trusted source.
+            pub fn from_bytes_unchecked(bytes: &[u8]) -> Result<Self, ::GroupDecodingError> {
+                if bytes.len() < 6 || bytes[0] != G2Prepared::ENCODING_VERSION {
+                    return Err(::GroupDecodingError::UnexpectedInformation);
+                }
+                let infinity = match bytes[1] {
+                    0 => false,
+                    1 => true,
+                    _ => return Err(::GroupDecodingError::UnexpectedInformation),
+                };
+                let len = bytes[2..6].iter().fold(0usize, |acc, &b| (acc << 8) | b as usize);
+                let expected = if infinity { 0 } else { G2Prepared::num_lines() };
+                if len != expected {
+                    return Err(::GroupDecodingError::UnexpectedInformation);
+                }
+
+                fn read_fq2(reader: &mut &[u8]) -> Result<Fq2, ::GroupDecodingError> {
+                    let mut c = [<Fq as ::ff::Field>::zero(); 2];
+                    for c in c.iter_mut().rev() {
+                        let mut repr = <Fq as ::ff::PrimeField>::Repr::default();
+                        ::ff::PrimeFieldRepr::read_be(&mut repr, &mut *reader)
+                            .map_err(|_| ::GroupDecodingError::UnexpectedInformation)?;
+                        *c = <Fq as ::ff::PrimeField>::from_repr(repr).map_err(|e| {
+                            ::GroupDecodingError::CoordinateDecodingError("line coefficient", e)
+                        })?;
+                    }
+                    Ok(Fq2 { c0: c[0], c1: c[1] })
+                }
+
+                let mut reader = &bytes[6..];
+                let mut coeffs = Vec::with_capacity(len);
+                for _ in 0..len {
+                    let c0 = read_fq2(&mut reader)?;
+                    let c1 = read_fq2(&mut reader)?;
+                    let c2 = read_fq2(&mut reader)?;
+                    coeffs.push((c0, c1, c2));
+                }
+
+                if !reader.is_empty() {
+                    return Err(::GroupDecodingError::UnexpectedInformation);
+                }
+
+                Ok(G2Prepared { coeffs, infinity })
+            }
+
+            /// Decodes line coefficients encoded by `into_bytes`, checking them
+            /// against `q` with `is_prepared_from`.
+            pub fn from_bytes(bytes: &[u8], q: &G2Affine) -> Result<Self, ::GroupDecodingError> {
+                let prepared = G2Prepared::from_bytes_unchecked(bytes)?;
+                if !prepared.is_prepared_from(q) {
+                    return Err(::GroupDecodingError::UnexpectedInformation);
+                }
+
+                Ok(prepared)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl ::serde::Serialize for G2Prepared {
+            fn serialize<S: ::serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+                s.collect_str(&::hex::encode(self.into_bytes()))
+            }
+        }
+
+        /// Deserializes line coefficients with `G2Prepared::from_bytes_unchecked`,
+        /// which callers should follow with `is_prepared_from` unless the
+        /// source is trusted.
+        #[cfg(feature = "serde")]
+        impl<'de> ::serde::Deserialize<'de> for G2Prepared {
+            fn deserialize<D: ::serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+                use serde::de::Error;
+
+                let s = <String as ::serde::Deserialize>::deserialize(d)?;
+                let bytes = ::hex::decode(&s).map_err(D::Error::custom)?;
+                G2Prepared::from_bytes_unchecked(&bytes).map_err(D::Error::custom)
+            }
+        }
+    };
+}
@@ -0,0 +1,233 @@
+//! Scalar multiplication using the Gallant-Lambert-Vanstone method, and its
+//! four-dimensional generalization by Galbraith, Lin and Scott.
+//!
+//! On curves y^2 = x^3 + b over a field containing a primitive cube root of
+//! unity β, the map φ(x, y) = (βx, y) is an endomorphism that acts on the
+//! prime order subgroup as multiplication by a cube root of unity λ modulo r.
+//! A scalar k is decomposed as k = k1 + k2 λ with k1 and k2 of about half the
+//! length of r, and [k]P = [k1]P + [k2]φ(P) is evaluated with a joint
+//! double-and-add ladder, which halves the number of doublings.
+//!
+//! On G2 of BLS12 and BN curves, the untwist-Frobenius-twist endomorphism ψ
+//! acts as multiplication by some μ, with μ^4 - μ^2 + 1 = 0 mod r, and k is
+//! decomposed as k0 + k1 μ + k2 μ^2 + k3 μ^3 with scalars of a quarter of
+//! the length of r.
+//!
+//! The results are only correct for points in the prime order subgroup.
+
+use ff::{mac_with_carry, Field, PrimeField, PrimeFieldRepr};
+use CurveProjective;
+
+/// The parameters of the GLV decomposition for a group whose scalar field
+/// has a 256-bit representation.
+///
+/// The short lattice basis (a1, b1), (a2, b2) of the vectors (a, b) with
+/// a + b λ = 0 mod r is chosen such that b1 < 0 < b2 and a1 b2 - a2 b1 = r.
+/// All the constants are little-endian limbs of nonnegative integers.
+pub(crate) trait GlvParameters: CurveProjective {
+    /// The eigenvalue λ of the endomorphism.
+    const LAMBDA: [u64; 4];
+    /// -b1.
+    const MINUS_B1: [u64; 4];
+    /// b2.
+    const B2: [u64; 4];
+    /// round(2^256 b2 / r).
+    const B2_OVER_R: [u64; 4];
+    /// round(-2^256 b1 / r).
+    const MINUS_B1_OVER_R: [u64; 4];
+
+    /// Computes φ(P).
+    fn endomorphism(&self) -> Self;
+}
+
+/// The absolute value of a scalar, together with whether it is negative.
+pub(crate) type SignedScalar<F> = (<F as PrimeField>::Repr, bool);
+
+/// Computes [k]P with the GLV method, for P in the prime order subgroup.
+pub(crate) fn mul<G: GlvParameters>(p: &G, k: <G::Scalar as PrimeField>::Repr) -> G {
+    let (k1, k2) = decompose::<G>(k);
+
+    straus(&[*p, p.endomorphism()], &[k1, k2])
+}
+
+/// Decomposes k as k1 + k2 λ mod r, returning the absolute values of k1 and
+/// k2 together with their signs.
+pub(crate) fn decompose<G: GlvParameters>(
+    k: <G::Scalar as PrimeField>::Repr,
+) -> (SignedScalar<G::Scalar>, SignedScalar<G::Scalar>) {
+    let k = reduce::<G::Scalar>(k);
+
+    // c1 = round(b2 k / r) and c2 = round(-b1 k / r).
+    let c1 = scalar::<G::Scalar>(&round_mul_shr_256(k.as_ref(), &G::B2_OVER_R));
+    let c2 = scalar::<G::Scalar>(&round_mul_shr_256(k.as_ref(), &G::MINUS_B1_OVER_R));
+
+    // k2 = -c1 b1 - c2 b2.
+    let mut k2 = c1;
+    k2.mul_assign(&scalar(&G::MINUS_B1));
+    let mut tmp = c2;
+    tmp.mul_assign(&scalar(&G::B2));
+    k2.sub_assign(&tmp);
+
+    // k1 = k - k2 λ.
+    let mut k1 = G::Scalar::from_repr(k).unwrap();
+    let mut tmp = k2;
+    tmp.mul_assign(&scalar(&G::LAMBDA));
+    k1.sub_assign(&tmp);
+
+    (abs(k1), abs(k2))
+}
+
+/// The parameters of the four-dimensional GLS decomposition for a group whose
+/// scalar field has a 256-bit representation.
+pub(crate) trait GlsParameters: CurveProjective {
+    /// The rows b_i of a short basis of the lattice of the vectors
+    /// (a0, a1, a2, a3) with a0 + a1 μ + a2 μ^2 + a3 μ^3 = 0 mod r.
+    const BASIS: [[i128; 4]; 4];
+    /// The absolute values of round(2^256 n_i / r) and whether they are
+    /// negative, where (n_0, n_1, n_2, n_3) / r is the first row of the
+    /// inverse of the basis matrix.
+    const ROUNDING: [([u64; 4], bool); 4];
+
+    /// Computes ψ(P).
+    fn endomorphism(&self) -> Self;
+}
+
+/// Computes [k]P with the GLS method, for P in the prime order subgroup.
+pub(crate) fn mul_gls<G: GlsParameters>(p: &G, k: <G::Scalar as PrimeField>::Repr) -> G {
+    let p1 = p.endomorphism();
+    let p2 = p1.endomorphism();
+    let p3 = p2.endomorphism();
+
+    straus(&[*p, p1, p2, p3], &decompose_gls::<G>(k))
+}
+
+/// Decomposes k as k0 + k1 μ + k2 μ^2 + k3 μ^3 mod r, returning the
+/// absolute values of the ki together with their signs.
+pub(crate) fn decompose_gls<G: GlsParameters>(
+    k: <G::Scalar as PrimeField>::Repr,
+) -> Vec<SignedScalar<G::Scalar>> {
+    let k = reduce::<G::Scalar>(k);
+
+    // Babai rounding: (k, 0, 0, 0) - sum_i c_i b_i, where c_i = round(k n_i / r).
+    let mut res = vec![G::Scalar::zero(); 4];
+    res[0] = G::Scalar::from_repr(k).unwrap();
+    for (&(ref rounding, neg), row) in G::ROUNDING.iter().zip(G::BASIS.iter()) {
+        let mut c = scalar::<G::Scalar>(&round_mul_shr_256(k.as_ref(), rounding));
+        if neg {
+            c.negate();
+        }
+
+        for (res, &entry) in res.iter_mut().zip(row.iter()) {
+            let mut tmp = small_scalar::<G::Scalar>(entry);
+            tmp.mul_assign(&c);
+            res.sub_assign(&tmp);
+        }
+    }
+
+    res.into_iter().map(abs).collect()
+}
+
+/// Computes sum_i [k_i] P_i with a joint double-and-add ladder, where the
+/// scalars are given as absolute values and signs.
+fn straus<G: CurveProjective>(points: &[G], scalars: &[SignedScalar<G::Scalar>]) -> G {
+    // table[m] is the sum of the points selected by the bits of m.
+    let mut table = vec![G::zero(); 1 << points.len()];
+    for m in 1..table.len() {
+        let i = m.trailing_zeros() as usize;
+        let mut p = points[i];
+        if scalars[i].1 {
+            p.negate();
+        }
+        p.add_assign(&table[m & (m - 1)]);
+        table[m] = p;
+    }
+
+    let num_bits = scalars
+        .iter()
+        .map(|(k, _)| k.num_bits())
+        .max()
+        .unwrap_or(0) as usize;
+
+    let mut res = G::zero();
+    for i in (0..num_bits).rev() {
+        res.double();
+
+        let m = scalars
+            .iter()
+            .enumerate()
+            .fold(0, |m, (j, (k, _))| m | (bit(k, i) as usize) << j);
+        if m != 0 {
+            res.add_assign(&table[m]);
+        }
+    }
+
+    res
+}
+
+fn reduce<F: PrimeField>(mut k: F::Repr) -> F::Repr {
+    let r = F::char();
+    while k >= r {
+        k.sub_noborrow(&r);
+    }
+
+    k
+}
+
+fn scalar<F: PrimeField>(limbs: &[u64; 4]) -> F {
+    let mut repr = F::Repr::default();
+    repr.as_mut().copy_from_slice(limbs);
+    F::from_repr(repr).unwrap()
+}
+
+fn small_scalar<F: PrimeField>(x: i128) -> F {
+    let abs = x.unsigned_abs();
+    let mut res = scalar::<F>(&[abs as u64, (abs >> 64) as u64, 0, 0]);
+    if x < 0 {
+        res.negate();
+    }
+
+    res
+}
+
+/// Returns the representative of `x` of smallest absolute value, as its
+/// absolute value and whether it is negative.
+fn abs<F: PrimeField>(x: F) -> SignedScalar<F> {
+    let mut neg = x;
+    neg.negate();
+
+    let (x, neg) = (x.into_repr(), neg.into_repr());
+    if neg < x {
+        (neg, true)
+    } else {
+        (x, false)
+    }
+}
+
+/// Computes round(a b / 2^256) for 256-bit a and b.
+fn round_mul_shr_256(a: &[u64], b: &[u64; 4]) -> [u64; 4] {
+    let mut product = [0u64; 8];
+    for (i, a) in a.iter().enumerate() {
+        let mut carry = 0;
+        for (j, b) in b.iter().enumerate() {
+            product[i + j] = mac_with_carry(product[i + j], *a, *b, &mut carry);
+        }
+        product[i + b.len()] = carry;
+    }
+
+    // Round to the nearest integer with the top bit of the discarded half.
+    let mut res = [product[4], product[5], product[6], product[7]];
+    if product[3] >> 63 == 1 {
+        for limb in res.iter_mut() {
+            *limb = limb.wrapping_add(1);
+            if *limb != 0 {
+                break;
+            }
+        }
+    }
+
+    res
+}
+
+fn bit<R: PrimeFieldRepr>(repr: &R, i: usize) -> bool {
+    (repr.as_ref()[i / 64] >> (i % 64)) & 1 == 1
+}
@@ -0,0 +1,166 @@
+/// Implements `TargetGroup` for the type `Gt` of the engine `$engine`, which
+/// wraps the elements of `$fqk` in the image of the final exponentiation.
+///
+/// The encoding of an element lists its coefficients over `Fq` in the given
+/// order, as big-endian integers of the size of `FqRepr`. For `Fq12` they
+/// are listed from the most significant, as for the coordinates of G2, and
+/// doubling and scalar multiplication use the cyclotomic squaring of `Fq12`.
+macro_rules! gt_impl {
+    ($engine:ident, Fq12) => {
+        gt_impl!(
+            $engine,
+            Fq12,
+            [
+                c1.c2.c1, c1.c2.c0, c1.c1.c1, c1.c1.c0, c1.c0.c1, c1.c0.c0,
+                c0.c2.c1, c0.c2.c0, c0.c1.c1, c0.c1.c0, c0.c0.c1, c0.c0.c0
+            ],
+            cyclotomic
+        );
+    };
+    ($engine:ident, $fqk:ident, [$($($coeff:ident).+),*]) => {
+        gt_impl!($engine, $fqk, [$($($coeff).+),*], generic);
+    };
+    (@square generic, $f:expr) => {
+        ::ff::Field::square(&mut $f)
+    };
+    (@square cyclotomic, $f:expr) => {
+        $f.cyclotomic_square()
+    };
+    (@exp generic, $f:expr, $e:expr) => {
+        ::ff::Field::pow(&$f, $e)
+    };
+    (@exp cyclotomic, $f:expr, $e:expr) => {
+        $f.cyclotomic_exp($e)
+    };
+    ($engine:ident, $fqk:ident, [$($($coeff:ident).+),*], $kind:ident) => {
+        /// An element of the target group of the pairing, the subgroup of
+        /// order r of the multiplicative group of the extension field.
+        #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+        pub struct Gt($fqk);
+
+        impl ::std::fmt::Display for Gt {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                write!(f, "Gt({})", self.0)
+            }
+        }
+
+        impl ::ff::Rand for Gt {
+            fn rand<R: ::rand::Rng + ?Sized>(rng: &mut R) -> Self {
+                loop {
+                    // The final exponentiation maps onto the subgroup of order r.
+                    let f = <$fqk as ::ff::Rand>::rand(rng);
+                    if let Some(f) = <$engine as ::Engine>::final_exponentiation(&f) {
+                        return Gt(f);
+                    }
+                }
+            }
+        }
+
+        impl ::rand::distributions::Distribution<Gt> for ::rand::distributions::Standard {
+            fn sample<R: ::rand::Rng + ?Sized>(&self, rng: &mut R) -> Gt {
+                <Gt as ::ff::Rand>::rand(rng)
+            }
+        }
+
+        impl ::TargetGroup for Gt {
+            type Engine = $engine;
+            type Scalar = <$engine as ::ff::ScalarEngine>::Fr;
+            type Fqk = $fqk;
+
+            fn zero() -> Self {
+                Gt(<$fqk as ::ff::Field>::one())
+            }
+
+            fn generator() -> Self {
+                <$engine as ::Engine>::pairing(
+                    <<$engine as ::Engine>::G1Affine as ::CurveAffine>::one(),
+                    <<$engine as ::Engine>::G2Affine as ::CurveAffine>::one(),
+                )
+            }
+
+            fn is_zero(&self) -> bool {
+                self.0 == <$fqk as ::ff::Field>::one()
+            }
+
+            fn double(&mut self) {
+                gt_impl!(@square $kind, self.0);
+            }
+
+            fn add_assign(&mut self, other: &Self) {
+                ::ff::Field::mul_assign(&mut self.0, &other.0);
+            }
+
+            fn negate(&mut self) {
+                // The elements of the subgroup are unitary, so that their
+                // inverse is their conjugate.
+                self.0.conjugate();
+            }
+
+            fn mul_assign<S: Into<<Self::Scalar as ::ff::PrimeField>::Repr>>(&mut self, other: S) {
+                self.0 = gt_impl!(@exp $kind, self.0, other.into());
+            }
+
+            fn from_fqk_unchecked(f: $fqk) -> Self {
+                Gt(f)
+            }
+
+            fn into_fqk(self) -> $fqk {
+                self.0
+            }
+
+            fn is_torsion_free(&self) -> bool {
+                let r = <Self::Scalar as ::ff::PrimeField>::char();
+                ::ff::Field::pow(&self.0, r) == <$fqk as ::ff::Field>::one()
+            }
+
+            fn into_bytes(self) -> Vec<u8> {
+                let mut res = vec![];
+                $(
+                    ::ff::PrimeFieldRepr::write_be(
+                        &::ff::PrimeField::into_repr(&self.0.$($coeff).+),
+                        &mut res,
+                    ).unwrap();
+                )*
+                res
+            }
+
+            fn from_bytes_unchecked(bytes: &[u8]) -> Result<Self, ::GroupDecodingError> {
+                type Fq = <$engine as ::Engine>::Fq;
+
+                let mut f = <$fqk as ::ff::Field>::zero();
+                let mut reader = bytes;
+                $(
+                    let mut repr = <Fq as ::ff::PrimeField>::Repr::default();
+                    ::ff::PrimeFieldRepr::read_be(&mut repr, &mut reader)
+                        .map_err(|_| ::GroupDecodingError::UnexpectedInformation)?;
+                    f.$($coeff).+ = <Fq as ::ff::PrimeField>::from_repr(repr)
+                        .map_err(|e| ::GroupDecodingError::CoordinateDecodingError("coefficient", e))?;
+                )*
+
+                if !reader.is_empty() {
+                    return Err(::GroupDecodingError::UnexpectedInformation);
+                }
+
+                Ok(Gt(f))
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl ::serde::Serialize for Gt {
+            fn serialize<S: ::serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+                s.collect_str(&::hex::encode(::TargetGroup::into_bytes(*self)))
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> ::serde::Deserialize<'de> for Gt {
+            fn deserialize<D: ::serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+                use serde::de::Error;
+
+                let s = <String as ::serde::Deserialize>::deserialize(d)?;
+                let bytes = ::hex::decode(&s).map_err(D::Error::custom)?;
+                <Gt as ::TargetGroup>::from_bytes(&bytes).map_err(D::Error::custom)
+            }
+        }
+    };
+}
@@ -0,0 +1,491 @@
+//! Hashing of arbitrary byte strings to elliptic curve groups, following
+//! [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html).
+//!
+//! This module provides the curve-independent parts of the construction:
+//! `expand_message_xmd` instantiated with SHA-256, `hash_to_field`, and the
+//! `hash_to_curve` / `encode_to_curve` encodings built on top of a
+//! curve-specific `MapToCurve` implementation.
+
+use ff::{Field, PrimeField, PrimeFieldRepr, SqrtField};
+use sha2::{Digest, Sha256};
+use CurveProjective;
+
+/// The output size of SHA-256 in bytes, `b_in_bytes` in RFC 9380.
+const B_IN_BYTES: usize = 32;
+
+/// The input block size of SHA-256 in bytes, `s_in_bytes` in RFC 9380.
+const S_IN_BYTES: usize = 64;
+
+/// Expands `msg` into `len_in_bytes` uniformly random bytes, using
+/// `expand_message_xmd` (RFC 9380, section 5.3.1) with SHA-256.
+///
+/// Domain separation tags longer than 255 bytes are hashed first, as
+/// described in section 5.3.3.
+///
+/// # Panics
+///
+/// Panics if `len_in_bytes` is greater than 8160, the most the construction
+/// can output.
+pub fn expand_message_xmd(msg: &[u8], dst: &[u8], len_in_bytes: usize) -> Vec<u8> {
+    let ell = len_in_bytes.div_ceil(B_IN_BYTES);
+    assert!(ell <= 255 && len_in_bytes <= 0xffff, "requested output is too long");
+
+    let long_dst;
+    let dst = if dst.len() > 255 {
+        long_dst = Sha256::new()
+            .chain(b"H2C-OVERSIZE-DST-")
+            .chain(dst)
+            .finalize();
+        &long_dst[..]
+    } else {
+        dst
+    };
+    let dst_len = [dst.len() as u8];
+
+    let b_0 = Sha256::new()
+        .chain(&[0u8; S_IN_BYTES][..])
+        .chain(msg)
+        .chain(&[(len_in_bytes >> 8) as u8, len_in_bytes as u8, 0u8][..])
+        .chain(dst)
+        .chain(&dst_len[..])
+        .finalize();
+
+    let mut b_i = Sha256::new()
+        .chain(&b_0[..])
+        .chain(&[1u8][..])
+        .chain(dst)
+        .chain(&dst_len[..])
+        .finalize();
+
+    let mut uniform_bytes = Vec::with_capacity(ell * B_IN_BYTES);
+    uniform_bytes.extend_from_slice(&b_i[..]);
+
+    for i in 2..(ell + 1) {
+        let mut xored = [0u8; B_IN_BYTES];
+        for (x, (a, b)) in xored.iter_mut().zip(b_0.iter().zip(b_i.iter())) {
+            *x = a ^ b;
+        }
+
+        b_i = Sha256::new()
+            .chain(&xored[..])
+            .chain(&[i as u8][..])
+            .chain(dst)
+            .chain(&dst_len[..])
+            .finalize();
+        uniform_bytes.extend_from_slice(&b_i[..]);
+    }
+
+    uniform_bytes.truncate(len_in_bytes);
+    uniform_bytes
+}
+
+/// A field that elements can be hashed to, as described in section 5 of
+/// RFC 9380.
+pub trait HashToField: SqrtField {
+    /// The number of uniform bytes needed to produce one element, that is
+    /// `m * L` in RFC 9380.
+    const OKM_BYTES: usize;
+
+    /// Converts `OKM_BYTES` uniform bytes into a field element, with a
+    /// statistically negligible bias.
+    fn from_okm(okm: &[u8]) -> Self;
+
+    /// Returns the "sign" of this element, `sgn0` in RFC 9380, section 4.1.
+    fn sgn0(&self) -> bool;
+
+    /// Hashes `msg` to `count` field elements using `expand_message_xmd`
+    /// with SHA-256 and the domain separation tag `dst`.
+    fn hash_to_field(msg: &[u8], dst: &[u8], count: usize) -> Vec<Self> {
+        expand_message_xmd(msg, dst, count * Self::OKM_BYTES)
+            .chunks(Self::OKM_BYTES)
+            .map(Self::from_okm)
+            .collect()
+    }
+}
+
+/// A curve group with a deterministic map from its hash field, and the
+/// cofactor clearing that goes with it.
+pub trait MapToCurve: CurveProjective {
+    /// The field elements are hashed to before being mapped to the curve.
+    type Field: HashToField;
+
+    /// The identifier of the suite of `hash_to_curve`, such as
+    /// `BLS12381G2_XMD:SHA-256_SSWU_RO_`, which protocols built on it embed in
+    /// their domain separation tags.
+    const SUITE_ID: &'static [u8];
+
+    /// Maps a field element to a point on the curve, which is not
+    /// necessarily in the prime order subgroup.
+    fn map_to_curve(u: &Self::Field) -> Self;
+
+    /// Sends a point on the curve to the prime order subgroup, multiplying
+    /// it by the effective cofactor `h_eff` of the suite.
+    fn clear_cofactor(&self) -> Self;
+}
+
+/// The `hash_to_curve` and `encode_to_curve` encodings of RFC 9380,
+/// section 3.
+pub trait HashToCurve: CurveProjective {
+    /// Hashes `msg` to a point in the prime order subgroup. The output
+    /// distribution is indistinguishable from uniformly random; this is the
+    /// `_RO_` variant of a suite.
+    fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Self;
+
+    /// Encodes `msg` as a point in the prime order subgroup. This is cheaper
+    /// than `hash_to_curve` but its output is not uniformly distributed; it
+    /// is the `_NU_` variant of a suite.
+    fn encode_to_curve(msg: &[u8], dst: &[u8]) -> Self;
+}
+
+impl<G: MapToCurve> HashToCurve for G {
+    fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Self {
+        let u = G::Field::hash_to_field(msg, dst, 2);
+        let mut q = G::map_to_curve(&u[0]);
+        q.add_assign(&G::map_to_curve(&u[1]));
+        q.clear_cofactor()
+    }
+
+    fn encode_to_curve(msg: &[u8], dst: &[u8]) -> Self {
+        let u = G::Field::hash_to_field(msg, dst, 1);
+        G::map_to_curve(&u[0]).clear_cofactor()
+    }
+}
+
+/// Reduces the big-endian integer `okm` modulo the characteristic of `F`.
+pub(crate) fn prime_field_from_okm<F: PrimeField>(okm: &[u8]) -> F {
+    fn from_u64<F: PrimeField>(v: u64) -> F {
+        F::from_repr(F::Repr::from(v)).unwrap()
+    }
+
+    // 2^64, built from 2^32 so that the representation is always valid
+    let mut shift = from_u64::<F>(1 << 32);
+    shift.square();
+
+    let head = okm.len() % 8;
+    let mut res = F::zero();
+    if head != 0 {
+        res = from_u64(okm[..head].iter().fold(0, |acc, &b| (acc << 8) | b as u64));
+    }
+    for chunk in okm[head..].chunks(8) {
+        res.mul_assign(&shift);
+        res.add_assign(&from_u64(
+            chunk.iter().fold(0, |acc, &b| (acc << 8) | b as u64),
+        ));
+    }
+
+    res
+}
+
+/// `sgn0` of an element of a prime field: the parity of its canonical
+/// representation.
+pub(crate) fn prime_field_sgn0<F: PrimeField>(f: &F) -> bool {
+    f.into_repr().is_odd()
+}
+
+/// The simplified Shallue-van de Woestijne-Ulas map of RFC 9380, section
+/// 6.6.2, onto the curve y^2 = x^3 + a * x + b with non-square `z`. Returns
+/// the affine coordinates of the point.
+pub(crate) fn simplified_swu<F: HashToField>(u: &F, a: &F, b: &F, z: &F) -> (F, F) {
+    let g = |x: &F| {
+        let mut gx = *x;
+        gx.square();
+        gx.add_assign(a);
+        gx.mul_assign(x);
+        gx.add_assign(b);
+        gx
+    };
+
+    // tv1 = z * u^2
+    let mut tv1 = *u;
+    tv1.square();
+    tv1.mul_assign(z);
+
+    // tv2 = z^2 * u^4 + z * u^2
+    let mut tv2 = tv1;
+    tv2.square();
+    tv2.add_assign(&tv1);
+
+    let x1 = match tv2.inverse() {
+        // x1 = (-b / a) * (1 + 1 / tv2)
+        Some(mut x1) => {
+            x1.add_assign(&F::one());
+            x1.mul_assign(b);
+            x1.mul_assign(&a.inverse().unwrap());
+            x1.negate();
+            x1
+        }
+        // Exceptional case: x1 = b / (z * a)
+        None => {
+            let mut za = *z;
+            za.mul_assign(a);
+            let mut x1 = *b;
+            x1.mul_assign(&za.inverse().unwrap());
+            x1
+        }
+    };
+
+    let (x, mut y) = match g(&x1).sqrt() {
+        Some(y1) => (x1, y1),
+        None => {
+            // x2 = z * u^2 * x1, and g(x2) is then guaranteed to be square
+            let mut x2 = tv1;
+            x2.mul_assign(&x1);
+            let y2 = g(&x2).sqrt().unwrap();
+            (x2, y2)
+        }
+    };
+
+    if u.sgn0() != y.sgn0() {
+        y.negate();
+    }
+
+    (x, y)
+}
+
+/// The Shallue-van de Woestijne map of RFC 9380, section 6.6.1, onto the
+/// curve y^2 = x^3 + a * x + b, with `z` chosen as in appendix H.1 and the
+/// constants `[c1, c2, c3, c4]` of the map given by `svdw_constants`.
+/// Returns the affine coordinates of the point.
+pub(crate) fn svdw<F: HashToField>(u: &F, a: &F, b: &F, z: &F, c: &[F; 4]) -> (F, F) {
+    let g = |x: &F| {
+        let mut gx = *x;
+        gx.square();
+        gx.add_assign(a);
+        gx.mul_assign(x);
+        gx.add_assign(b);
+        gx
+    };
+    let [c1, c2, c3, c4] = *c;
+
+    // tv1 = 1 - u^2 * c1, tv2 = 1 + u^2 * c1
+    let mut tv1 = *u;
+    tv1.square();
+    tv1.mul_assign(&c1);
+    let mut tv2 = F::one();
+    tv2.add_assign(&tv1);
+    tv1.negate();
+    tv1.add_assign(&F::one());
+
+    // tv3 = inv0(tv1 * tv2)
+    let mut tv3 = tv1;
+    tv3.mul_assign(&tv2);
+    let tv3 = tv3.inverse().unwrap_or(F::zero());
+
+    // tv4 = u * tv1 * tv3 * c3
+    let mut tv4 = *u;
+    tv4.mul_assign(&tv1);
+    tv4.mul_assign(&tv3);
+    tv4.mul_assign(&c3);
+
+    let mut x1 = c2;
+    x1.sub_assign(&tv4);
+    let mut x2 = c2;
+    x2.add_assign(&tv4);
+
+    let (x, mut y) = if let Some(y1) = g(&x1).sqrt() {
+        (x1, y1)
+    } else if let Some(y2) = g(&x2).sqrt() {
+        (x2, y2)
+    } else {
+        // x3 = (tv2^2 * tv3)^2 * c4 + z, and g(x3) is then guaranteed to be
+        // square
+        let mut x3 = tv2;
+        x3.square();
+        x3.mul_assign(&tv3);
+        x3.square();
+        x3.mul_assign(&c4);
+        x3.add_assign(z);
+        let y3 = g(&x3).sqrt().unwrap();
+        (x3, y3)
+    };
+
+    if u.sgn0() != y.sgn0() {
+        y.negate();
+    }
+
+    (x, y)
+}
+
+/// Computes the constants of `svdw`, which only depend on the curve and z:
+/// c1 = g(z), c2 = -z / 2,
+/// c3 = sqrt(-g(z) * (3 * z^2 + 4 * a)) with sgn0(c3) = 0,
+/// c4 = -4 * g(z) / (3 * z^2 + 4 * a)
+#[cfg(test)]
+pub(crate) fn svdw_constants<F: HashToField>(a: &F, b: &F, z: &F) -> [F; 4] {
+    let mut c1 = *z;
+    c1.square();
+    c1.add_assign(a);
+    c1.mul_assign(z);
+    c1.add_assign(b);
+    let mut c2 = *z;
+    c2.negate();
+    c2.mul_assign(&{
+        let mut two = F::one();
+        two.double();
+        two.inverse().unwrap()
+    });
+    let mut h = *z;
+    h.square();
+    h.mul_assign(&{
+        let mut three = F::one();
+        three.double();
+        three.add_assign(&F::one());
+        three
+    });
+    let mut four_a = *a;
+    four_a.double();
+    four_a.double();
+    h.add_assign(&four_a);
+    let mut c3 = c1;
+    c3.mul_assign(&h);
+    c3.negate();
+    let mut c3 = c3.sqrt().unwrap();
+    if c3.sgn0() {
+        c3.negate();
+    }
+    let mut c4 = c1;
+    c4.double();
+    c4.double();
+    c4.negate();
+    c4.mul_assign(&h.inverse().unwrap());
+
+    [c1, c2, c3, c4]
+}
+
+/// Evaluates the rational map of an isogeny at the affine point (x, y),
+/// given the coefficients of its polynomials in ascending degree, as in
+/// appendix E of RFC 9380. Returns `None` if the point is in the kernel.
+pub(crate) fn isogeny_map<F: Field>(
+    x: &F,
+    y: &F,
+    x_num: &[F],
+    x_den: &[F],
+    y_num: &[F],
+    y_den: &[F],
+) -> Option<(F, F)> {
+    let eval = |coeffs: &[F]| {
+        coeffs.iter().rev().fold(F::zero(), |mut acc, c| {
+            acc.mul_assign(x);
+            acc.add_assign(c);
+            acc
+        })
+    };
+
+    let x_num = eval(x_num);
+    let x_den = eval(x_den);
+    let mut y_num = eval(y_num);
+    let y_den = eval(y_den);
+
+    // Invert both denominators at once
+    let mut inv = x_den;
+    inv.mul_assign(&y_den);
+    let inv = inv.inverse()?;
+
+    let mut x_res = x_num;
+    x_res.mul_assign(&y_den);
+    x_res.mul_assign(&inv);
+
+    y_num.mul_assign(y);
+    y_num.mul_assign(&x_den);
+    y_num.mul_assign(&inv);
+
+    Some((x_res, y_num))
+}
+
+/// Multiplies `p` by the scalar whose little-endian limbs are `scalar`,
+/// which can be larger than the group order.
+#[cfg(test)]
+pub(crate) fn mul_by_limbs<G: CurveProjective>(p: &G, scalar: &[u64]) -> G {
+    let mut res = G::zero();
+    for bit in ::ff::BitIterator::new(scalar) {
+        res.double();
+        if bit {
+            res.add_assign(p);
+        }
+    }
+    res
+}
+
+#[test]
+fn test_expand_message_xmd() {
+    // RFC 9380, appendix K.1
+    const DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHA256-128";
+
+    let q128 = format!("q128_{}", "q".repeat(128));
+    let a512 = format!("a512_{}", "a".repeat(512));
+
+    let cases: [(&[u8], usize, &str); 10] = [
+        (
+            b"",
+            0x20,
+            "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235",
+        ),
+        (
+            b"abc",
+            0x20,
+            "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615",
+        ),
+        (
+            b"abcdef0123456789",
+            0x20,
+            "eff31487c770a893cfb36f912fbfcbff40d5661771ca4b2cb4eafe524333f5c1",
+        ),
+        (
+            q128.as_bytes(),
+            0x20,
+            "b23a1d2b4d97b2ef7785562a7e8bac7eed54ed6e97e29aa51bfe3f12ddad1ff9",
+        ),
+        (
+            a512.as_bytes(),
+            0x20,
+            "4623227bcc01293b8c130bf771da8c298dede7383243dc0993d2d94823958c4c",
+        ),
+        (
+            b"",
+            0x80,
+            "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbee0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dcc541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced",
+        ),
+        (
+            b"abc",
+            0x80,
+            "abba86a6129e366fc877aab32fc4ffc70120d8996c88aee2fe4b32d6c7b6437a647e6c3163d40b76a73cf6a5674ef1d890f95b664ee0afa5359a5c4e07985635bbecbac65d747d3d2da7ec2b8221b17b0ca9dc8a1ac1c07ea6a1e60583e2cb00058e77b7b72a298425cd1b941ad4ec65e8afc50303a22c0f99b0509b4c895f40",
+        ),
+        (
+            b"abcdef0123456789",
+            0x80,
+            "ef904a29bffc4cf9ee82832451c946ac3c8f8058ae97d8d629831a74c6572bd9ebd0df635cd1f208e2038e760c4994984ce73f0d55ea9f22af83ba4734569d4bc95e18350f740c07eef653cbb9f87910d833751825f0ebefa1abe5420bb52be14cf489b37fe1a72f7de2d10be453b2c9d9eb20c7e3f6edc5a60629178d9478df",
+        ),
+        (
+            q128.as_bytes(),
+            0x80,
+            "80be107d0884f0d881bb460322f0443d38bd222db8bd0b0a5312a6fedb49c1bbd88fd75d8b9a09486c60123dfa1d73c1cc3169761b17476d3c6b7cbbd727acd0e2c942f4dd96ae3da5de368d26b32286e32de7e5a8cb2949f866a0b80c58116b29fa7fabb3ea7d520ee603e0c25bcaf0b9a5e92ec6a1fe4e0391d1cdbce8c68a",
+        ),
+        (
+            a512.as_bytes(),
+            0x80,
+            "546aff5444b5b79aa6148bd81728704c32decb73a3ba76e9e75885cad9def1d06d6792f8a7d12794e90efed817d96920d728896a4510864370c207f99bd4a608ea121700ef01ed879745ee3e4ceef777eda6d9e5e38b90c86ea6fb0b36504ba4a45d22e86f6db5dd43d98a294bebb9125d5b794e9d2a81181066eb954966a487",
+        ),
+    ];
+
+    for &(msg, len_in_bytes, expected) in cases.iter() {
+        assert_eq!(
+            ::hex::encode(expand_message_xmd(msg, DST, len_in_bytes)),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_expand_message_xmd_long_dst() {
+    // RFC 9380, appendix K.2
+    let dst = format!("QUUX-V01-CS02-with-expander-SHA256-128-long-DST-{}", "1".repeat(208));
+
+    assert_eq!(
+        ::hex::encode(expand_message_xmd(b"", dst.as_bytes(), 0x20)),
+        "e8dc0c8b686b7ef2074086fbdd2f30e3f8bfbd3bdf177f73f04b97ce618a3ed3"
+    );
+    assert_eq!(
+        ::hex::encode(expand_message_xmd(b"abc", dst.as_bytes(), 0x20)),
+        "52dbf4f36cf560fca57dedec2ad924ee9c266341d8f3d6afe5171733b16bbb12"
+    );
+}
@@ -15,19 +15,40 @@ extern crate byteorder;
 extern crate ff_bl as ff;
 extern crate rand;
 extern crate rand_xorshift;
+extern crate sha2;
 
 #[cfg(feature = "serde")]
 #[macro_use(Serialize, Deserialize)]
 extern crate serde;
 extern crate hex;
+#[cfg(feature = "parallel")]
+extern crate rayon;
 
 #[cfg(test)]
 pub mod tests;
 
+#[macro_use]
+mod g2_prepared;
+#[macro_use]
+mod gt;
+#[macro_use]
+pub mod bls12;
+pub mod bls12_377;
 pub mod bls12_381;
+#[macro_use]
+pub mod bn;
 pub mod bn256;
-
+pub mod bn462;
+pub mod bw6_761;
+
+mod glv;
+pub mod hash_to_curve;
+mod multiexp;
+#[cfg(feature = "parallel")]
+mod parallel;
+pub mod signature;
 mod wnaf;
+pub use self::multiexp::multiexp;
 pub use self::wnaf::Wnaf;
 
 use ff::{Rand, Field, PrimeField, PrimeFieldDecodingError, PrimeFieldRepr, ScalarEngine, SqrtField};
@@ -54,7 +75,7 @@ pub trait Engine: ScalarEngine {
             Scalar = Self::Fr,
             Projective = Self::G1,
             Pair = Self::G2Affine,
-            PairingResult = Self::Fqk,
+            PairingResult = Self::Gt,
         >
         + From<Self::G1>;
 
@@ -74,7 +95,7 @@ pub trait Engine: ScalarEngine {
             Scalar = Self::Fr,
             Projective = Self::G2,
             Pair = Self::G1Affine,
-            PairingResult = Self::Fqk,
+            PairingResult = Self::Gt,
         >
         + From<Self::G2>;
 
@@ -87,6 +108,9 @@ pub trait Engine: ScalarEngine {
     /// The extension field that hosts the target group of the pairing.
     type Fqk: Field;
 
+    /// The target group of the pairing.
+    type Gt: TargetGroup<Engine = Self, Scalar = Self::Fr, Fqk = Self::Fqk>;
+
     /// Perform a miller loop with some number of (G1, G2) pairs.
     fn miller_loop<'a, I>(i: I) -> Self::Fqk
     where
@@ -101,17 +125,47 @@ pub trait Engine: ScalarEngine {
     fn final_exponentiation(&Self::Fqk) -> Option<Self::Fqk>;
 
     /// Performs a complete pairing operation `(p, q)`.
-    fn pairing<G1, G2>(p: G1, q: G2) -> Self::Fqk
+    fn pairing<G1, G2>(p: G1, q: G2) -> Self::Gt
     where
         G1: Into<Self::G1Affine>,
         G2: Into<Self::G2Affine>,
     {
-        Self::final_exponentiation(&Self::miller_loop(
-            [(&(p.into().prepare()), &(q.into().prepare()))].into_iter(),
-        )).unwrap()
+        Self::Gt::from_fqk_unchecked(
+            Self::final_exponentiation(&Self::miller_loop(
+                [(&(p.into().prepare()), &(q.into().prepare()))].into_iter(),
+            )).unwrap(),
+        )
+    }
+
+    /// Checks whether the product of the pairings of the pairs `(p, q)` is
+    /// one, with a single miller loop and final exponentiation.
+    fn pairing_product_is_one(pairs: &[(Self::G1Affine, Self::G2Affine)]) -> bool {
+        let prepared: Vec<_> = pairs
+            .iter()
+            .map(|(p, q)| (p.prepare(), q.prepare()))
+            .collect();
+        let refs: Vec<_> = prepared.iter().map(|(p, q)| (p, q)).collect();
+
+        Self::prepared_pairing_product_is_one(&refs)
+    }
+
+    /// Checks whether the product of the pairings of the prepared pairs
+    /// `(p, q)` is one, for callers that reuse prepared points.
+    fn prepared_pairing_product_is_one(pairs: &[PreparedPair<Self>]) -> bool {
+        match Self::final_exponentiation(&Self::miller_loop(pairs)) {
+            Some(f) => f == Self::Fqk::one(),
+            None => false,
+        }
     }
 }
 
+/// A pair of prepared G1 and G2 points of the engine `E`, as taken by
+/// `Engine::prepared_pairing_product_is_one`.
+pub type PreparedPair<'a, E> = (
+    &'a <<E as Engine>::G1Affine as CurveAffine>::Prepared,
+    &'a <<E as Engine>::G2Affine as CurveAffine>::Prepared,
+);
+
 /// Projective representation of an elliptic curve point guaranteed to be
 /// in the correct prime order subgroup.
 pub trait CurveProjective:
@@ -196,7 +250,7 @@ pub trait CurveAffine:
     type Uncompressed: EncodedPoint<Affine = Self>;
     type Compressed: EncodedPoint<Affine = Self>;
     type Pair: CurveAffine<Pair = Self>;
-    type PairingResult: Field;
+    type PairingResult: TargetGroup;
 
     /// Returns the additive identity.
     fn zero() -> Self;
@@ -236,6 +290,104 @@ pub trait CurveAffine:
     }
 }
 
+/// An element of the target group of a pairing, the subgroup of order `r` of
+/// the multiplicative group of `Fqk`. Like the curve groups, the group law is
+/// written additively: addition is multiplication in `Fqk`, and the identity
+/// is one.
+pub trait TargetGroup:
+    PartialEq
+    + Eq
+    + Sized
+    + Copy
+    + Clone
+    + Send
+    + Sync
+    + fmt::Debug
+    + fmt::Display
+    + Rand
+    + 'static
+{
+    type Engine: Engine<Fr = Self::Scalar>;
+    type Scalar: PrimeField + SqrtField;
+    type Fqk: Field;
+
+    /// Returns the identity.
+    fn zero() -> Self;
+
+    /// Returns the pairing of the generators of G1 and G2.
+    fn generator() -> Self;
+
+    /// Determines if this element is the identity.
+    fn is_zero(&self) -> bool;
+
+    /// Doubles this element.
+    fn double(&mut self);
+
+    /// Adds another element to this element.
+    fn add_assign(&mut self, other: &Self);
+
+    /// Subtracts another element from this element.
+    fn sub_assign(&mut self, other: &Self) {
+        let mut tmp = *other;
+        tmp.negate();
+        self.add_assign(&tmp);
+    }
+
+    /// Negates this element, which is cheap as the elements of the target
+    /// group are unitary.
+    fn negate(&mut self);
+
+    /// Performs scalar multiplication of this element.
+    fn mul_assign<S: Into<<Self::Scalar as PrimeField>::Repr>>(&mut self, other: S);
+
+    /// Converts an element of `Fqk` into an element of the target group, if
+    /// it lies in the subgroup of order `r`.
+    fn from_fqk(f: Self::Fqk) -> Option<Self> {
+        let res = Self::from_fqk_unchecked(f);
+        if res.is_torsion_free() {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Converts an element of `Fqk` into an element of the target group,
+    /// without checking that it lies in the subgroup of order `r`.
+    ///
+    /// If it does not, this can break API invariants, so caution is strongly
+    /// encouraged.
+    fn from_fqk_unchecked(f: Self::Fqk) -> Self;
+
+    /// Returns this element as an element of `Fqk`.
+    fn into_fqk(self) -> Self::Fqk;
+
+    /// Determines if this element, which may come from one of the unchecked
+    /// conversions, lies in the subgroup of order `r`.
+    fn is_torsion_free(&self) -> bool;
+
+    /// Converts this element into its canonical encoding, which lists the
+    /// coefficients of the element over `Fq` as big-endian integers.
+    fn into_bytes(self) -> Vec<u8>;
+
+    /// Decodes an element from its canonical encoding, checking that it lies
+    /// in the subgroup of order `r`.
+    fn from_bytes(bytes: &[u8]) -> Result<Self, GroupDecodingError> {
+        let res = Self::from_bytes_unchecked(bytes)?;
+        if res.is_torsion_free() {
+            Ok(res)
+        } else {
+            Err(GroupDecodingError::NotInSubgroup)
+        }
+    }
+
+    /// Decodes an element from its canonical encoding, without checking that
+    /// it lies in the subgroup of order `r`.
+    ///
+    /// If it does not, this can break API invariants, so caution is strongly
+    /// encouraged.
+    fn from_bytes_unchecked(bytes: &[u8]) -> Result<Self, GroupDecodingError>;
+}
+
 /// An encoded elliptic curve point, which should essentially wrap a `[u8; N]`.
 pub trait EncodedPoint:
     Sized + Send + Sync + AsRef<[u8]> + AsMut<[u8]> + Clone + Copy + 'static
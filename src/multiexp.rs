@@ -0,0 +1,145 @@
+use super::{CurveAffine, CurveProjective, PrimeField, PrimeFieldRepr};
+
+/// Computes the multi-scalar multiplication `sum_i [scalars[i]] bases[i]` using
+/// Pippenger's bucket method.
+///
+/// The scalars are given in their canonical representation, as returned by
+/// `PrimeField::into_repr`. The window size is chosen from the number of
+/// terms.
+///
+/// # Panics
+///
+/// Panics if `bases` and `scalars` do not have the same length.
+pub fn multiexp<G: CurveProjective>(
+    bases: &[G::Affine],
+    scalars: &[<G::Scalar as PrimeField>::Repr],
+) -> G {
+    assert_eq!(bases.len(), scalars.len());
+
+    multiexp_inner(bases, scalars)
+}
+
+#[cfg(not(feature = "parallel"))]
+fn multiexp_inner<G: CurveProjective>(
+    bases: &[G::Affine],
+    scalars: &[<G::Scalar as PrimeField>::Repr],
+) -> G {
+    let c = window_size(bases.len());
+    let num_bits = G::Scalar::NUM_BITS as usize;
+
+    let windows = (0..num_bits)
+        .step_by(c)
+        .map(|skip| multiexp_window::<G>(bases, scalars, skip, c))
+        .collect::<Vec<_>>();
+
+    combine_windows(&windows, c)
+}
+
+/// Computes every window on its own thread and, for large inputs, splits the
+/// bases into chunks whose window sums are added together.
+#[cfg(feature = "parallel")]
+fn multiexp_inner<G: CurveProjective>(
+    bases: &[G::Affine],
+    scalars: &[<G::Scalar as PrimeField>::Repr],
+) -> G {
+    use parallel::{chunk_len, MIN_MULTIEXP_CHUNK};
+    use rayon::prelude::*;
+
+    let chunk_len = chunk_len(bases.len(), MIN_MULTIEXP_CHUNK).unwrap_or(bases.len());
+    if chunk_len == 0 {
+        return G::zero();
+    }
+
+    let c = window_size(chunk_len);
+    let num_bits = G::Scalar::NUM_BITS as usize;
+
+    let windows = (0..num_bits)
+        .step_by(c)
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .map(|skip| {
+            bases
+                .par_chunks(chunk_len)
+                .zip(scalars.par_chunks(chunk_len))
+                .map(|(bases, scalars)| multiexp_window::<G>(bases, scalars, skip, c))
+                .reduce(G::zero, |mut acc, window| {
+                    acc.add_assign(&window);
+                    acc
+                })
+        })
+        .collect::<Vec<_>>();
+
+    combine_windows(&windows, c)
+}
+
+/// Returns the window size that minimizes the number of group additions for
+/// `n` terms, `ln(n)` for large inputs.
+pub(crate) fn window_size(n: usize) -> usize {
+    if n < 32 {
+        3
+    } else {
+        (n as f64).ln().ceil() as usize
+    }
+}
+
+/// Computes `sum_i [w_i] bases[i]`, where `w_i` is the `c`-bit window of
+/// `scalars[i]` that starts at bit `skip`.
+pub(crate) fn multiexp_window<G: CurveProjective>(
+    bases: &[G::Affine],
+    scalars: &[<G::Scalar as PrimeField>::Repr],
+    skip: usize,
+    c: usize,
+) -> G {
+    // Bucket i holds the sum of the bases whose window is i + 1.
+    let mut buckets = vec![G::zero(); (1 << c) - 1];
+
+    for (base, scalar) in bases.iter().zip(scalars.iter()) {
+        let index = window_value(scalar, skip, c);
+        if index != 0 && !base.is_zero() {
+            buckets[index - 1].add_assign_mixed(base);
+        }
+    }
+
+    // sum_i (i + 1) * buckets[i], using a running sum from the top bucket
+    // down.
+    let mut running_sum = G::zero();
+    let mut acc = G::zero();
+    for bucket in buckets.iter().rev() {
+        running_sum.add_assign(bucket);
+        acc.add_assign(&running_sum);
+    }
+
+    acc
+}
+
+/// Combines the window sums computed by `multiexp_window`, the lowest window
+/// first, into the final result.
+pub(crate) fn combine_windows<G: CurveProjective>(windows: &[G], c: usize) -> G {
+    let mut acc = G::zero();
+    for window in windows.iter().rev() {
+        for _ in 0..c {
+            acc.double();
+        }
+        acc.add_assign(window);
+    }
+
+    acc
+}
+
+/// Extracts the `c` bits of `scalar` that start at bit `skip`.
+fn window_value<R: PrimeFieldRepr>(scalar: &R, skip: usize, c: usize) -> usize {
+    let limbs = scalar.as_ref();
+    let limb = skip / 64;
+    let shift = skip % 64;
+
+    if limb >= limbs.len() {
+        return 0;
+    }
+
+    let mut bits = limbs[limb] >> shift;
+    if shift + c > 64 && limb + 1 < limbs.len() {
+        bits |= limbs[limb + 1] << (64 - shift);
+    }
+
+    (bits & ((1 << c) - 1)) as usize
+}
@@ -0,0 +1,55 @@
+//! Helpers for splitting work across the rayon thread pool when the
+//! `parallel` feature is enabled.
+
+use ff::Field;
+use rayon;
+
+/// Slices are only split for batch normalization if every chunk gets at
+/// least this many points, so that the extra field inversions stay cheap
+/// compared to the work that is saved.
+pub(crate) const MIN_BATCH_NORMALIZATION_CHUNK: usize = 256;
+
+/// Multi-scalar multiplications are only split into chunks of bases if every
+/// chunk gets at least this many terms.
+pub(crate) const MIN_MULTIEXP_CHUNK: usize = 1024;
+
+/// Returns the chunk length to split `len` items across the current thread
+/// pool, so that every chunk has at least `min_chunk_len` items, or `None` if
+/// the work should not be split at all.
+pub(crate) fn chunk_len(len: usize, min_chunk_len: usize) -> Option<usize> {
+    let num_chunks = ::std::cmp::min(rayon::current_num_threads(), len / min_chunk_len);
+
+    if num_chunks < 2 {
+        None
+    } else {
+        Some(len.div_ceil(num_chunks))
+    }
+}
+
+/// Pairs are only split across threads for the miller loop if every chunk gets
+/// at least this many pairs, as every chunk repeats the squarings of the loop.
+pub(crate) const MIN_MILLER_LOOP_CHUNK: usize = 8;
+
+/// Runs the miller loop `serial` on chunks of `pairs` across the current
+/// thread pool and multiplies the partial results. The miller loop of several
+/// pairs is the product of their own miller loops, so that this matches
+/// `serial(pairs)` exactly.
+pub(crate) fn miller_loop<P, Q, F>(pairs: &[(&P, &Q)], serial: fn(&[(&P, &Q)]) -> F) -> F
+where
+    P: Sync,
+    Q: Sync,
+    F: Field,
+{
+    use rayon::prelude::*;
+
+    match chunk_len(pairs.len(), MIN_MILLER_LOOP_CHUNK) {
+        Some(chunk_len) => pairs
+            .par_chunks(chunk_len)
+            .map(serial)
+            .reduce(F::one, |mut acc, f| {
+                acc.mul_assign(&f);
+                acc
+            }),
+        None => serial(pairs),
+    }
+}
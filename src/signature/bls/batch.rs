@@ -0,0 +1,219 @@
+//! Batch verification of independent signatures.
+//!
+//! A batch of triples `(pk_i, msg_i, sig_i)` is checked at once by weighting
+//! every triple with a random nonzero scalar `r_i` and checking that the
+//! product of e([r_i] pk_i, H(msg_i)) and e(-P, sum_i [r_i] sig_i) is one,
+//! which costs a single multi Miller loop and a single final exponentiation.
+//! A batch that contains an invalid signature only passes with probability
+//! at most 2^-b for weights of b bits.
+//!
+//! When the batch fails, it is bisected with the same weights to find the
+//! invalid signatures, so that a few of them cost a logarithmic number of
+//! further checks.
+
+use ff::{PrimeField, PrimeFieldRepr, ScalarEngine};
+use rand::Rng;
+
+use super::{augment, PublicKey, PublicKeyAffine, Scheme, Signature, SignatureAffine, Variant};
+use hash_to_curve::HashToCurve;
+use {multiexp, CurveAffine, CurveProjective};
+
+/// The size of the random weights, which bounds the probability that a
+/// batch with an invalid signature passes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WeightSize {
+    /// 64-bit weights, for a soundness error of 2^-64.
+    Bits64,
+    /// 128-bit weights, for a soundness error of 2^-128.
+    Bits128,
+}
+
+impl WeightSize {
+    fn limbs(&self) -> usize {
+        match *self {
+            WeightSize::Bits64 => 1,
+            WeightSize::Bits128 => 2,
+        }
+    }
+}
+
+/// Samples a nonzero weight, which is smaller than the order of every scalar
+/// field of this crate.
+fn random_weight<F: PrimeField, R: Rng>(size: WeightSize, rng: &mut R) -> F::Repr {
+    let mut repr = F::Repr::default();
+    loop {
+        for limb in repr.as_mut()[..size.limbs()].iter_mut() {
+            *limb = rng.gen();
+        }
+        if !repr.is_zero() {
+            return repr;
+        }
+    }
+}
+
+/// The weighted terms of a batch.
+struct Batch<V: Variant> {
+    weighted_pks: Vec<PublicKeyAffine<V>>,
+    hashes: Vec<SignatureAffine<V>>,
+    sigs: Vec<SignatureAffine<V>>,
+    weights: Vec<<<V::Engine as ScalarEngine>::Fr as PrimeField>::Repr>,
+}
+
+impl<V: Variant> Batch<V> {
+    /// Checks the terms in `start..end` with one pairing product.
+    fn check(&self, start: usize, end: usize) -> bool {
+        let sig: V::SignatureGroup =
+            multiexp(&self.sigs[start..end], &self.weights[start..end]);
+
+        let mut minus_p = V::PublicKeyGroup::one().into_affine();
+        minus_p.negate();
+
+        let mut pairs: Vec<_> = self.weighted_pks[start..end]
+            .iter()
+            .cloned()
+            .zip(self.hashes[start..end].iter().cloned())
+            .collect();
+        pairs.push((minus_p, sig.into_affine()));
+
+        V::pairing_product_is_one(&pairs)
+    }
+
+    /// Collects the invalid signatures in `start..end`, which is known to
+    /// fail its check.
+    fn bisect(&self, start: usize, end: usize, invalid: &mut Vec<usize>) {
+        if end - start == 1 {
+            // The weight is invertible, so the unweighted check fails too.
+            invalid.push(start);
+            return;
+        }
+
+        let mid = start + (end - start) / 2;
+        if self.check(start, mid) {
+            // The product of the checks of the halves is the check of the
+            // whole range, so the second half must fail.
+            self.bisect(mid, end, invalid);
+        } else {
+            self.bisect(start, mid, invalid);
+            if !self.check(mid, end) {
+                self.bisect(mid, end, invalid);
+            }
+        }
+    }
+}
+
+/// Verifies every signature `sig` on `msg` by `pk` of the triples in `items`
+/// with the ciphersuite of `scheme`, weighting them with random weights of
+/// `size` drawn from `rng`.
+///
+/// The signatures are independent, so that the basic scheme allows repeated
+/// messages here. Returns the indices of the invalid signatures, in
+/// increasing order, if there are any.
+pub fn verify<V: Variant, R: Rng>(
+    scheme: Scheme,
+    items: &[(PublicKey<V>, &[u8], Signature<V>)],
+    size: WeightSize,
+    rng: &mut R,
+) -> Result<(), Vec<usize>> {
+    if items.is_empty() {
+        return Ok(());
+    }
+
+    let dst = scheme.ciphersuite_id::<V>();
+    let weights: Vec<_> = items
+        .iter()
+        .map(|_| random_weight::<<V::Engine as ScalarEngine>::Fr, _>(size, rng))
+        .collect();
+
+    let mut weighted_pks: Vec<_> = items
+        .iter()
+        .zip(weights.iter())
+        .map(|((pk, _, _), weight)| pk.into_affine().mul(*weight))
+        .collect();
+    V::PublicKeyGroup::batch_normalization(&mut weighted_pks);
+
+    let batch = Batch::<V> {
+        weighted_pks: weighted_pks.iter().map(|pk| pk.into_affine()).collect(),
+        hashes: items
+            .iter()
+            .map(|&(ref pk, msg, _)| {
+                let msg = match scheme {
+                    Scheme::MessageAugmentation => augment(pk, msg),
+                    _ => msg.to_vec(),
+                };
+                V::SignatureGroup::hash_to_curve(&msg, &dst).into_affine()
+            })
+            .collect(),
+        sigs: items.iter().map(|(_, _, sig)| sig.into_affine()).collect(),
+        weights,
+    };
+
+    if batch.check(0, items.len()) {
+        return Ok(());
+    }
+
+    let mut invalid = vec![];
+    batch.bisect(0, items.len(), &mut invalid);
+    Err(invalid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::random_keys;
+    use super::*;
+
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    fn batch_tests<V: Variant>() {
+        let mut rng = XorShiftRng::seed_from_u64(0x3a9c1d58e6f20b47);
+
+        let (sks, pks) = random_keys::<V>(6, &mut rng);
+        // Repeated messages are fine for independent signatures.
+        let msgs: [&[u8]; 6] = [b"foo", b"bar", b"baz", b"foo", b"qux", b"quux"];
+
+        for &scheme in &[Scheme::Basic, Scheme::MessageAugmentation, Scheme::ProofOfPossession] {
+            for &size in &[WeightSize::Bits64, WeightSize::Bits128] {
+                let items: Vec<_> = sks
+                    .iter()
+                    .zip(pks.iter())
+                    .zip(msgs.iter())
+                    .map(|((sk, pk), msg)| (*pk, *msg, sk.sign::<V>(scheme, msg)))
+                    .collect();
+                assert_eq!(verify(scheme, &items, size, &mut rng), Ok(()));
+                assert_eq!(verify(scheme, &items[..1], size, &mut rng), Ok(()));
+                assert_eq!(verify::<V, _>(scheme, &[], size, &mut rng), Ok(()));
+
+                // A wrong message, a wrong key and a wrong signature
+                let mut bad = items.clone();
+                bad[1].1 = b"wrong";
+                bad[4].0 = pks[0];
+                bad[5].2 = items[2].2;
+                assert_eq!(verify(scheme, &bad, size, &mut rng), Err(vec![1, 4, 5]));
+
+                let mut bad = items.clone();
+                bad[0].1 = b"wrong";
+                assert_eq!(verify(scheme, &bad, size, &mut rng), Err(vec![0]));
+
+                // Errors that cancel out in the sum of the signatures are
+                // caught by the weights.
+                let mut delta = V::SignatureGroup::one();
+                delta.mul_assign(<V::Engine as ScalarEngine>::Fr::from_str("42").unwrap());
+                let mut bad = items.clone();
+                let mut sig = bad[2].2.into_affine().into_projective();
+                sig.add_assign(&delta);
+                bad[2].2 = Signature::from_affine(sig.into_affine());
+                let mut sig = bad[3].2.into_affine().into_projective();
+                sig.sub_assign(&delta);
+                bad[3].2 = Signature::from_affine(sig.into_affine());
+                let sigs: Vec<_> = bad.iter().map(|item| item.2).collect();
+                let agg = Signature::aggregate(&sigs).unwrap();
+                if scheme != Scheme::Basic {
+                    assert!(agg.aggregate_verify(scheme, &pks, &msgs));
+                }
+                assert_eq!(verify(scheme, &bad, size, &mut rng), Err(vec![2, 3]));
+            }
+        }
+    }
+
+    variant_tests!(batch_tests);
+}
@@ -0,0 +1,186 @@
+//! Multisignatures of Boneh, Drijvers and Neven
+//! ([eprint 2018/483](https://eprint.iacr.org/2018/483)), which aggregate
+//! signatures on a common message without proofs of possession.
+//!
+//! Every public key `pk_i` of a set is weighted with the coefficient
+//! `a_i = H(pk_i, {pk_1, ..., pk_n})`, so that the aggregate public key is
+//! `sum_i [a_i] pk_i` and the aggregate signature `sum_i [a_i] sig_i`. An
+//! attacker cannot choose a rogue key that cancels the keys of honest
+//! signers, as the coefficients depend on the rogue key itself.
+//!
+//! The signatures of the signers are those of the basic scheme, and the
+//! aggregate signature verifies as a signature of the basic scheme by the
+//! aggregate public key.
+
+use ff::{PrimeField, ScalarEngine};
+use sha2::{Digest, Sha256};
+
+use super::{
+    domain_separation_tag, Error, PublicKey, PublicKeyAffine, Scheme, SecretKey, Signature,
+    SignatureAffine, Variant,
+};
+use hash_to_curve::{expand_message_xmd, prime_field_from_okm};
+use {multiexp, CurveProjective};
+
+/// Computes the coefficients of the keys of `pks`.
+///
+/// The coefficients do not depend on the order of the keys: `pks` is
+/// hashed as a set, by the digest of the sorted encodings of the keys.
+pub fn coefficients<V: Variant>(pks: &[PublicKey<V>]) -> Vec<<V::Engine as ScalarEngine>::Fr> {
+    let encodings: Vec<_> = pks.iter().map(|pk| pk.into_bytes()).collect();
+
+    let mut sorted: Vec<_> = encodings.iter().collect();
+    sorted.sort();
+    let mut hasher = Sha256::new();
+    for pk in sorted {
+        hasher.update(pk);
+    }
+    let set_digest = hasher.finalize();
+
+    let dst = domain_separation_tag::<V>(b"BLS_BDN_", b"COEF_");
+    // The length of hash_to_field, with 128 bits of security.
+    let len = (<V::Engine as ScalarEngine>::Fr::NUM_BITS as usize + 128).div_ceil(8);
+
+    encodings
+        .iter()
+        .map(|pk| {
+            let mut msg = set_digest.to_vec();
+            msg.extend_from_slice(pk);
+            prime_field_from_okm(&expand_message_xmd(&msg, &dst, len))
+        })
+        .collect()
+}
+
+fn coefficient_reprs<V: Variant>(
+    pks: &[PublicKey<V>],
+) -> Vec<<<V::Engine as ScalarEngine>::Fr as PrimeField>::Repr> {
+    coefficients(pks)
+        .into_iter()
+        .map(|a| a.into_repr())
+        .collect()
+}
+
+/// Signs `msg` as a signer of a multisignature, with the ciphersuite of the
+/// basic scheme.
+pub fn sign<V: Variant>(sk: &SecretKey<V::Engine>, msg: &[u8]) -> Signature<V> {
+    sk.sign(Scheme::Basic, msg)
+}
+
+/// Aggregates the keys of `pks` into the key that verifies their
+/// multisignatures.
+pub fn aggregate_public_keys<V: Variant>(pks: &[PublicKey<V>]) -> Result<PublicKey<V>, Error> {
+    if pks.is_empty() {
+        return Err(Error::EmptyAggregate);
+    }
+
+    let bases: Vec<PublicKeyAffine<V>> = pks.iter().map(|pk| pk.into_affine()).collect();
+    let apk: V::PublicKeyGroup = multiexp(&bases, &coefficient_reprs(pks));
+
+    PublicKey::from_subgroup_point(apk.into_affine())
+}
+
+/// Aggregates the signature `sigs[i]` by the key `pks[i]` for every `i`,
+/// into a multisignature of the keys of `pks`.
+pub fn aggregate_signatures<V: Variant>(
+    pks: &[PublicKey<V>],
+    sigs: &[Signature<V>],
+) -> Result<Signature<V>, Error> {
+    if pks.len() != sigs.len() {
+        return Err(Error::LengthMismatch);
+    }
+    if sigs.is_empty() {
+        return Err(Error::EmptyAggregate);
+    }
+
+    let bases: Vec<SignatureAffine<V>> = sigs.iter().map(|sig| sig.into_affine()).collect();
+    let sig: V::SignatureGroup = multiexp(&bases, &coefficient_reprs(pks));
+
+    Ok(Signature::from_affine(sig.into_affine()))
+}
+
+/// Verifies the multisignature `sig` on `msg` by the keys of `pks`.
+pub fn verify<V: Variant>(pks: &[PublicKey<V>], msg: &[u8], sig: &Signature<V>) -> bool {
+    match aggregate_public_keys(pks) {
+        Ok(apk) => apk.verify(Scheme::Basic, msg, sig),
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::random_keys;
+    use super::*;
+    use CurveAffine;
+
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    fn bdn_tests<V: Variant>() {
+        let mut rng = XorShiftRng::seed_from_u64(0x8e1f7d2c49b3a605);
+
+        let (sks, pks) = random_keys::<V>(4, &mut rng);
+        let msg = b"multisignature";
+
+        let sigs: Vec<_> = sks.iter().map(|sk| sign::<V>(sk, msg)).collect();
+        let sig = aggregate_signatures(&pks, &sigs).unwrap();
+        assert!(verify(&pks, msg, &sig));
+        assert!(!verify(&pks, b"other", &sig));
+        assert!(!verify(&pks[..3], msg, &sig));
+        assert!(!verify(&[], msg, &sig));
+        assert!(aggregate_public_keys(&pks)
+            .unwrap()
+            .verify(Scheme::Basic, msg, &sig));
+
+        // The plain aggregate is not a multisignature.
+        let plain = Signature::aggregate(&sigs).unwrap();
+        assert!(!verify(&pks, msg, &plain));
+
+        // The order of the keys does not matter.
+        let permuted = [pks[2], pks[0], pks[3], pks[1]];
+        assert_eq!(
+            aggregate_public_keys(&permuted).unwrap(),
+            aggregate_public_keys(&pks).unwrap()
+        );
+        let permuted_sigs = [sigs[2], sigs[0], sigs[3], sigs[1]];
+        assert_eq!(
+            aggregate_signatures(&permuted, &permuted_sigs).unwrap(),
+            sig
+        );
+        let swapped = [sigs[1], sigs[0], sigs[2], sigs[3]];
+        assert!(!verify(
+            &pks,
+            msg,
+            &aggregate_signatures(&pks, &swapped).unwrap()
+        ));
+
+        assert_eq!(coefficients(&pks).len(), pks.len());
+        match aggregate_signatures(&pks, &sigs[..3]) {
+            Err(Error::LengthMismatch) => {}
+            _ => panic!("mismatched lengths were accepted"),
+        }
+        match aggregate_signatures::<V>(&[], &[]) {
+            Err(Error::EmptyAggregate) => {}
+            _ => panic!("an empty set of signatures was aggregated"),
+        }
+
+        // A rogue key pk_r = [x] P - pk_0 makes the plain aggregate of the
+        // keys [x] P, without knowledge of its secret key.
+        let x = SecretKey::<V::Engine>::key_gen(&[7u8; 32], b"").unwrap();
+        let mut rogue = x.public_key::<V>().into_affine().into_projective();
+        rogue.sub_assign(&pks[0].into_affine().into_projective());
+        let rogue = PublicKey::<V>::from_affine(rogue.into_affine()).unwrap();
+        let forgery = x.sign::<V>(Scheme::Basic, msg);
+        assert_eq!(
+            PublicKey::aggregate(&[pks[0], rogue]).unwrap(),
+            x.public_key::<V>()
+        );
+        assert!(PublicKey::aggregate(&[pks[0], rogue]).unwrap().verify(
+            Scheme::Basic,
+            msg,
+            &forgery
+        ));
+        assert!(!verify(&[pks[0], rogue], msg, &forgery));
+    }
+
+    variant_tests!(bdn_tests);
+}
@@ -0,0 +1,895 @@
+//! Distributed key generation for threshold signatures, without a trusted
+//! dealer.
+//!
+//! This is the Joint-Feldman protocol, with the complaints and
+//! disqualification of Gennaro, Jarecki, Krawczyk and Rabin. Each of the `n`
+//! parties deals a random secret with Feldman's verifiable secret sharing:
+//! it broadcasts commitments `[a_k] P` to the coefficients of its sharing
+//! polynomial, in the public key group, and sends the share of index `j`
+//! privately to party `j`. The parties complain about the dealers whose
+//! shares do not match their commitments, the dealers answer by revealing
+//! the disputed shares, and the dealers that do not answer correctly, or
+//! that receive complaints from at least `threshold` parties, are
+//! disqualified. The secret key is the sum of the secrets of the qualified
+//! dealers, and every party ends up with a share of it, as dealt by
+//! `threshold::split`.
+//!
+//! The state machine does not send anything itself: every round returns the
+//! messages to send and consumes the messages received, so that it can run
+//! over any transport. Broadcast messages must go over a reliable broadcast
+//! channel, and the `Share` messages over private and authenticated
+//! channels. Messages from the party itself, from unknown indices, or
+//! repeating an earlier message of the same sender are ignored.
+//!
+//! An active adversary can bias the distribution of the public key of
+//! Joint-Feldman, which does not harm the security of threshold BLS
+//! signatures (Gennaro et al., *Secure applications of Pedersen's
+//! distributed key generation protocol*, CT-RSA 2003).
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use ff::{Field, PrimeField, PrimeFieldRepr, Rand, ScalarEngine};
+use rand::Rng;
+
+use super::threshold::{scalar_from_index, Polynomial, PublicShare, SecretShare};
+use super::{decode, Error, PublicKey, PublicKeyAffine, Variant};
+use {multiexp, CurveAffine, CurveProjective, EncodedPoint, Engine, GroupDecodingError};
+
+type Scalar<V> = <<V as Variant>::Engine as ScalarEngine>::Fr;
+
+/// Reads the fields of an encoded message.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        if self.0.len() < len {
+            return Err(Error::Decoding(GroupDecodingError::UnexpectedInformation));
+        }
+        let (res, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(res)
+    }
+
+    fn u32(&mut self) -> Result<u32, Error> {
+        let bytes = self.take(4)?;
+        Ok(bytes.iter().fold(0, |acc, &b| (acc << 8) | b as u32))
+    }
+
+    fn scalar<F: PrimeField>(&mut self) -> Result<F, Error> {
+        let mut repr = F::Repr::default();
+        let bytes = self.take(repr.as_ref().len() * 8)?;
+        repr.read_be(bytes).unwrap();
+        F::from_repr(repr)
+            .map_err(|e| Error::Decoding(GroupDecodingError::CoordinateDecodingError("share", e)))
+    }
+
+    fn point<G: CurveAffine>(&mut self) -> Result<G, Error> {
+        decode(self.take(G::Compressed::size())?)
+    }
+
+    fn finish(self) -> Result<(), Error> {
+        if !self.0.is_empty() {
+            return Err(Error::Decoding(GroupDecodingError::UnexpectedInformation));
+        }
+
+        Ok(())
+    }
+}
+
+fn write_u32(res: &mut Vec<u8>, v: u32) {
+    res.extend_from_slice(&[(v >> 24) as u8, (v >> 16) as u8, (v >> 8) as u8, v as u8]);
+}
+
+fn write_scalar<F: PrimeField>(res: &mut Vec<u8>, v: &F) {
+    v.into_repr().write_be(res).unwrap();
+}
+
+macro_rules! message_impls {
+    ($name:ident<$param:ident: $bound:ident> { $($field:ident),* }) => {
+        impl<$param: $bound> Clone for $name<$param> {
+            fn clone(&self) -> Self {
+                $name {
+                    $($field: self.$field.clone()),*
+                }
+            }
+        }
+
+        impl<$param: $bound> PartialEq for $name<$param> {
+            fn eq(&self, other: &Self) -> bool {
+                $(self.$field == other.$field)&&*
+            }
+        }
+
+        impl<$param: $bound> Eq for $name<$param> {}
+
+        message_impls!(@serde $name<$param: $bound>);
+    };
+    (@serde $name:ident$(<$param:ident: $bound:ident>)*) => {
+        #[cfg(feature = "serde")]
+        impl<$($param: $bound)*> ::serde::Serialize for $name<$($param)*> {
+            fn serialize<S: ::serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+                s.collect_str(&::hex::encode(self.into_bytes()))
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, $($param: $bound)*> ::serde::Deserialize<'de> for $name<$($param)*> {
+            fn deserialize<D: ::serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+                use serde::de::Error;
+
+                let s = <String as ::serde::Deserialize>::deserialize(d)?;
+                let bytes = ::hex::decode(&s).map_err(D::Error::custom)?;
+                $name::from_bytes(&bytes).map_err(D::Error::custom)
+            }
+        }
+    };
+}
+
+/// The broadcast commitments of a dealer to the coefficients of its sharing
+/// polynomial, from the constant term up.
+pub struct Commitments<V: Variant> {
+    dealer: u32,
+    points: Vec<PublicKeyAffine<V>>,
+}
+
+message_impls!(Commitments<V: Variant> { dealer, points });
+
+impl<V: Variant> fmt::Debug for Commitments<V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Commitments")
+            .field("dealer", &self.dealer)
+            .field("points", &self.points)
+            .finish()
+    }
+}
+
+impl<V: Variant> Commitments<V> {
+    /// Returns the index of the dealer.
+    pub fn dealer(&self) -> u32 {
+        self.dealer
+    }
+
+    /// Returns the commitments.
+    pub fn points(&self) -> &[PublicKeyAffine<V>] {
+        &self.points
+    }
+
+    /// Encodes the message as the index of the dealer and the number of
+    /// commitments, as big-endian `u32`s, followed by the compressed
+    /// commitments.
+    pub fn into_bytes(&self) -> Vec<u8> {
+        let mut res = vec![];
+        write_u32(&mut res, self.dealer);
+        write_u32(&mut res, self.points.len() as u32);
+        for p in &self.points {
+            res.extend_from_slice(p.into_compressed().as_ref());
+        }
+        res
+    }
+
+    /// Decodes a message encoded by `into_bytes`, checking that the
+    /// commitments are in the prime order subgroup.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let mut r = Reader(bytes);
+        let dealer = r.u32()?;
+        let len = r.u32()?;
+        let points = (0..len).map(|_| r.point()).collect::<Result<_, _>>()?;
+        r.finish()?;
+
+        Ok(Commitments { dealer, points })
+    }
+}
+
+/// The share that a dealer sends privately to a recipient.
+pub struct Share<E: Engine> {
+    dealer: u32,
+    recipient: u32,
+    value: E::Fr,
+}
+
+message_impls!(Share<E: Engine> { dealer, recipient, value });
+
+impl<E: Engine> fmt::Debug for Share<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Share {{ dealer: {}, recipient: {}, .. }}",
+            self.dealer, self.recipient
+        )
+    }
+}
+
+impl<E: Engine> Share<E> {
+    /// Returns the index of the dealer.
+    pub fn dealer(&self) -> u32 {
+        self.dealer
+    }
+
+    /// Returns the index of the party to send the share to.
+    pub fn recipient(&self) -> u32 {
+        self.recipient
+    }
+
+    /// Encodes the message as the indices of the dealer and the recipient,
+    /// as big-endian `u32`s, followed by the big-endian share.
+    pub fn into_bytes(&self) -> Vec<u8> {
+        let mut res = vec![];
+        write_u32(&mut res, self.dealer);
+        write_u32(&mut res, self.recipient);
+        write_scalar(&mut res, &self.value);
+        res
+    }
+
+    /// Decodes a message encoded by `into_bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let mut r = Reader(bytes);
+        let dealer = r.u32()?;
+        let recipient = r.u32()?;
+        let value = r.scalar()?;
+        r.finish()?;
+
+        Ok(Share {
+            dealer,
+            recipient,
+            value,
+        })
+    }
+}
+
+/// The broadcast complaints of a party about the dealers whose shares are
+/// missing or do not match their commitments.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Complaints {
+    sender: u32,
+    against: Vec<u32>,
+}
+
+message_impls!(@serde Complaints);
+
+impl Complaints {
+    /// Returns the index of the party complaining.
+    pub fn sender(&self) -> u32 {
+        self.sender
+    }
+
+    /// Returns the indices of the dealers complained about.
+    pub fn against(&self) -> &[u32] {
+        &self.against
+    }
+
+    /// Encodes the message as the index of the sender, the number of
+    /// complaints and the indices of the dealers, as big-endian `u32`s.
+    pub fn into_bytes(&self) -> Vec<u8> {
+        let mut res = vec![];
+        write_u32(&mut res, self.sender);
+        write_u32(&mut res, self.against.len() as u32);
+        for &dealer in &self.against {
+            write_u32(&mut res, dealer);
+        }
+        res
+    }
+
+    /// Decodes a message encoded by `into_bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let mut r = Reader(bytes);
+        let sender = r.u32()?;
+        let len = r.u32()?;
+        let against = (0..len).map(|_| r.u32()).collect::<Result<_, _>>()?;
+        r.finish()?;
+
+        Ok(Complaints { sender, against })
+    }
+}
+
+/// The broadcast answer of a dealer to the complaints about it, which
+/// reveals the disputed shares.
+pub struct Justifications<E: Engine> {
+    dealer: u32,
+    shares: Vec<(u32, E::Fr)>,
+}
+
+message_impls!(Justifications<E: Engine> { dealer, shares });
+
+impl<E: Engine> fmt::Debug for Justifications<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Justifications")
+            .field("dealer", &self.dealer)
+            .field("shares", &self.shares)
+            .finish()
+    }
+}
+
+impl<E: Engine> Justifications<E> {
+    /// Returns the index of the dealer.
+    pub fn dealer(&self) -> u32 {
+        self.dealer
+    }
+
+    /// Encodes the message as the index of the dealer and the number of
+    /// shares, as big-endian `u32`s, followed by the index of the recipient
+    /// and the big-endian value of every share.
+    pub fn into_bytes(&self) -> Vec<u8> {
+        let mut res = vec![];
+        write_u32(&mut res, self.dealer);
+        write_u32(&mut res, self.shares.len() as u32);
+        for &(recipient, ref value) in &self.shares {
+            write_u32(&mut res, recipient);
+            write_scalar(&mut res, value);
+        }
+        res
+    }
+
+    /// Decodes a message encoded by `into_bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let mut r = Reader(bytes);
+        let dealer = r.u32()?;
+        let len = r.u32()?;
+        let shares = (0..len)
+            .map(|_| Ok((r.u32()?, r.scalar()?)))
+            .collect::<Result<_, Error>>()?;
+        r.finish()?;
+
+        Ok(Justifications { dealer, shares })
+    }
+}
+
+/// Evaluates the polynomial committed to by `points` at `index`, in the
+/// exponent.
+fn evaluate_commitments<V: Variant>(
+    points: &[PublicKeyAffine<V>],
+    index: u32,
+) -> V::PublicKeyGroup {
+    let x: Scalar<V> = scalar_from_index(index);
+    let mut power = Scalar::<V>::one();
+    let powers: Vec<_> = points
+        .iter()
+        .map(|_| {
+            let res = power.into_repr();
+            power.mul_assign(&x);
+            res
+        })
+        .collect();
+
+    multiexp(points, &powers)
+}
+
+/// Checks that the share `value` of index `index` matches the commitments.
+fn verify_share<V: Variant>(points: &[PublicKeyAffine<V>], index: u32, value: &Scalar<V>) -> bool {
+    let mut expected = V::PublicKeyGroup::one();
+    expected.mul_assign(*value);
+    expected == evaluate_commitments::<V>(points, index)
+}
+
+macro_rules! state_impls {
+    ($name:ident) => {
+        impl<V: Variant> fmt::Debug for $name<V> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{} {{ index: {}, .. }}", stringify!($name), self.index)
+            }
+        }
+
+        impl<V: Variant> $name<V> {
+            /// Returns the index of this party.
+            pub fn index(&self) -> u32 {
+                self.index
+            }
+        }
+    };
+}
+
+/// The first round, in which every party deals its secret.
+pub struct Dealing<V: Variant> {
+    index: u32,
+    threshold: usize,
+    n: usize,
+    poly: Polynomial<Scalar<V>>,
+    points: Vec<PublicKeyAffine<V>>,
+}
+
+state_impls!(Dealing);
+
+impl<V: Variant> Dealing<V> {
+    /// Starts the protocol as the party of index `index`, between 1 and `n`,
+    /// for a key that any `threshold` of the `n` parties can sign with.
+    pub fn new<R: Rng>(index: u32, threshold: usize, n: usize, rng: &mut R) -> Result<Self, Error> {
+        if threshold == 0 || threshold > n || n > u32::MAX as usize {
+            return Err(Error::InvalidThreshold);
+        }
+        if index == 0 || index as usize > n {
+            return Err(Error::InvalidShareIndex);
+        }
+
+        let poly = Polynomial::random(Scalar::<V>::rand(rng), threshold - 1, rng);
+        let mut points: Vec<_> = poly
+            .coefficients()
+            .iter()
+            .map(|c| {
+                let mut p = V::PublicKeyGroup::one();
+                p.mul_assign(*c);
+                p
+            })
+            .collect();
+        V::PublicKeyGroup::batch_normalization(&mut points);
+
+        Ok(Dealing {
+            index,
+            threshold,
+            n,
+            poly,
+            points: points.iter().map(|p| p.into_affine()).collect(),
+        })
+    }
+
+    /// Returns the commitments to broadcast.
+    pub fn commitments(&self) -> Commitments<V> {
+        Commitments {
+            dealer: self.index,
+            points: self.points.clone(),
+        }
+    }
+
+    /// Returns the shares to send privately to every other party.
+    pub fn shares(&self) -> Vec<Share<V::Engine>> {
+        (1..=self.n as u32)
+            .filter(|&j| j != self.index)
+            .map(|j| Share {
+                dealer: self.index,
+                recipient: j,
+                value: self.poly.evaluate(j),
+            })
+            .collect()
+    }
+
+    /// Verifies the commitments and shares received from the other parties,
+    /// and moves on to the complaints about the dealers whose shares are
+    /// missing or invalid, which must be broadcast.
+    ///
+    /// The dealers whose commitments are missing or do not have `threshold`
+    /// points are disqualified without complaints.
+    pub fn receive(
+        self,
+        commitments: &[Commitments<V>],
+        shares: &[Share<V::Engine>],
+    ) -> (Complaining<V>, Complaints) {
+        let mut dealt = BTreeMap::new();
+        dealt.insert(self.index, self.points.clone());
+        for c in commitments {
+            if c.dealer != 0 && c.dealer as usize <= self.n && c.points.len() == self.threshold {
+                dealt.entry(c.dealer).or_insert_with(|| c.points.clone());
+            }
+        }
+
+        let mut received = BTreeMap::new();
+        received.insert(self.index, self.poly.evaluate(self.index));
+        for s in shares {
+            if s.recipient == self.index && dealt.contains_key(&s.dealer) {
+                received.entry(s.dealer).or_insert(s.value);
+            }
+        }
+
+        let mut against = vec![];
+        for (&dealer, points) in &dealt {
+            let valid = match received.get(&dealer) {
+                Some(value) => verify_share::<V>(points, self.index, value),
+                None => false,
+            };
+            if !valid {
+                received.remove(&dealer);
+                against.push(dealer);
+            }
+        }
+
+        let complaints = Complaints {
+            sender: self.index,
+            against,
+        };
+        let next = Complaining {
+            index: self.index,
+            threshold: self.threshold,
+            n: self.n,
+            poly: self.poly,
+            dealt,
+            received,
+            complaints: complaints.clone(),
+        };
+
+        (next, complaints)
+    }
+}
+
+/// The second round, in which the parties complain about the dealers.
+pub struct Complaining<V: Variant> {
+    index: u32,
+    threshold: usize,
+    n: usize,
+    poly: Polynomial<Scalar<V>>,
+    dealt: BTreeMap<u32, Vec<PublicKeyAffine<V>>>,
+    received: BTreeMap<u32, Scalar<V>>,
+    complaints: Complaints,
+}
+
+state_impls!(Complaining);
+
+impl<V: Variant> Complaining<V> {
+    /// Collects the complaints of the other parties, and moves on to the
+    /// justifications, which reveal the shares disputed by complaints about
+    /// this party and must be broadcast.
+    pub fn receive(self, complaints: &[Complaints]) -> (Justifying<V>, Justifications<V::Engine>) {
+        let mut against: BTreeMap<u32, BTreeSet<u32>> = BTreeMap::new();
+        let mut senders = BTreeSet::new();
+        for c in Some(&self.complaints).into_iter().chain(complaints) {
+            if c.sender == 0 || c.sender as usize > self.n || !senders.insert(c.sender) {
+                continue;
+            }
+            for &dealer in &c.against {
+                if dealer != c.sender && self.dealt.contains_key(&dealer) {
+                    against.entry(dealer).or_default().insert(c.sender);
+                }
+            }
+        }
+
+        let justifications = Justifications {
+            dealer: self.index,
+            shares: against
+                .get(&self.index)
+                .into_iter()
+                .flat_map(|complainers| complainers.iter())
+                .map(|&j| (j, self.poly.evaluate(j)))
+                .collect(),
+        };
+        let next = Justifying {
+            index: self.index,
+            threshold: self.threshold,
+            dealt: self.dealt,
+            received: self.received,
+            against,
+        };
+
+        (next, justifications)
+    }
+}
+
+/// The third round, in which the dealers answer the complaints.
+pub struct Justifying<V: Variant> {
+    index: u32,
+    threshold: usize,
+    dealt: BTreeMap<u32, Vec<PublicKeyAffine<V>>>,
+    received: BTreeMap<u32, Scalar<V>>,
+    against: BTreeMap<u32, BTreeSet<u32>>,
+}
+
+state_impls!(Justifying);
+
+impl<V: Variant> Justifying<V> {
+    /// Checks the justifications of the other parties and derives the keys
+    /// from the dealers that are not disqualified.
+    ///
+    /// A dealer is disqualified if at least `threshold` parties complain
+    /// about it, or if it does not reveal a valid share for every complaint.
+    /// All the parties reach the same set of qualified dealers from the same
+    /// broadcast messages.
+    pub fn finish(self, justifications: &[Justifications<V::Engine>]) -> Result<Output<V>, Error> {
+        let index = self.index;
+        let mut received = self.received;
+
+        let mut revealed = BTreeMap::new();
+        for j in justifications {
+            if j.dealer != index {
+                revealed.entry(j.dealer).or_insert(&j.shares);
+            }
+        }
+
+        let no_complaints = BTreeSet::new();
+        let mut qualified = vec![];
+        for (&dealer, points) in &self.dealt {
+            let complainers = self.against.get(&dealer).unwrap_or(&no_complaints);
+            if complainers.len() >= self.threshold {
+                continue;
+            }
+
+            // The shares that this party deals are valid.
+            if dealer != index {
+                let shares = revealed.get(&dealer).map(|s| &s[..]).unwrap_or(&[]);
+                let justified = complainers.iter().all(|&j| {
+                    match shares.iter().find(|&&(recipient, _)| recipient == j) {
+                        Some(&(_, value)) if verify_share::<V>(points, j, &value) => {
+                            if j == index {
+                                received.insert(dealer, value);
+                            }
+                            true
+                        }
+                        _ => false,
+                    }
+                });
+                if !justified {
+                    continue;
+                }
+            }
+
+            qualified.push(dealer);
+        }
+
+        if qualified.is_empty() {
+            return Err(Error::NoQualifiedDealers);
+        }
+
+        let mut value = Scalar::<V>::zero();
+        let mut points = vec![V::PublicKeyGroup::zero(); self.threshold];
+        for dealer in &qualified {
+            value.add_assign(&received[dealer]);
+            for (sum, p) in points.iter_mut().zip(self.dealt[dealer].iter()) {
+                sum.add_assign_mixed(p);
+            }
+        }
+        V::PublicKeyGroup::batch_normalization(&mut points);
+        let points: Vec<_> = points.iter().map(|p| p.into_affine()).collect();
+
+        Ok(Output {
+            public_key: PublicKey::from_affine(points[0])?,
+            share: SecretShare::new(index, value)?,
+            qualified,
+            points,
+        })
+    }
+}
+
+/// The result of the protocol for one party.
+pub struct Output<V: Variant> {
+    qualified: Vec<u32>,
+    points: Vec<PublicKeyAffine<V>>,
+    public_key: PublicKey<V>,
+    share: SecretShare<V::Engine>,
+}
+
+impl<V: Variant> fmt::Debug for Output<V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Output")
+            .field("qualified", &self.qualified)
+            .field("public_key", &self.public_key)
+            .field("share", &self.share)
+            .finish()
+    }
+}
+
+impl<V: Variant> Output<V> {
+    /// Returns the indices of the qualified dealers, in increasing order.
+    pub fn qualified(&self) -> &[u32] {
+        &self.qualified
+    }
+
+    /// Returns the commitments to the polynomial that shares the secret key,
+    /// the sums of the commitments of the qualified dealers.
+    pub fn commitments(&self) -> &[PublicKeyAffine<V>] {
+        &self.points
+    }
+
+    /// Returns the public key of the shared secret key.
+    pub fn public_key(&self) -> PublicKey<V> {
+        self.public_key
+    }
+
+    /// Returns the share of this party.
+    pub fn share(&self) -> &SecretShare<V::Engine> {
+        &self.share
+    }
+
+    /// Computes the public key of the share of any party, which verifies its
+    /// partial signatures.
+    pub fn public_share(&self, index: u32) -> Result<PublicShare<V>, Error> {
+        let pk = evaluate_commitments::<V>(&self.points, index);
+        PublicShare::new(index, pk.into_affine())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bls12_381::Bls12;
+    use bn256::Bn256;
+    use signature::bls::threshold::{combine, PartialSignature};
+    use signature::bls::{MinPk, MinSig, Scheme};
+
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    /// Runs the protocol between `n` parties in-process, sending every
+    /// message through its encoding. The dealers of `absent` broadcast no
+    /// commitments, the shares of `bad_shares` from a dealer to a recipient
+    /// are wrong, and the dealers of `unjustified` do not answer
+    /// complaints.
+    fn run<V: Variant>(
+        n: usize,
+        threshold: usize,
+        absent: &[u32],
+        bad_shares: &[(u32, u32)],
+        unjustified: &[u32],
+        rng: &mut XorShiftRng,
+    ) -> Vec<Output<V>> {
+        let parties: Vec<Dealing<V>> = (1..=n as u32)
+            .map(|i| Dealing::new(i, threshold, n, rng).unwrap())
+            .collect();
+
+        let commitments: Vec<_> = parties
+            .iter()
+            .filter(|p| !absent.contains(&p.index()))
+            .map(|p| Commitments::from_bytes(&p.commitments().into_bytes()).unwrap())
+            .collect();
+        let shares: Vec<_> = parties
+            .iter()
+            .filter(|p| !absent.contains(&p.index()))
+            .flat_map(|p| p.shares())
+            .map(|mut s| {
+                if bad_shares.contains(&(s.dealer(), s.recipient())) {
+                    s.value.add_assign(&Scalar::<V>::one());
+                }
+                Share::from_bytes(&s.into_bytes()).unwrap()
+            })
+            .collect();
+
+        let (parties, complaints): (Vec<_>, Vec<_>) = parties
+            .into_iter()
+            .map(|p| p.receive(&commitments, &shares))
+            .unzip();
+        let complaints: Vec<_> = complaints
+            .iter()
+            .map(|c| Complaints::from_bytes(&c.into_bytes()).unwrap())
+            .collect();
+
+        let (parties, justifications): (Vec<_>, Vec<_>) =
+            parties.into_iter().map(|p| p.receive(&complaints)).unzip();
+        let justifications: Vec<_> = justifications
+            .iter()
+            .filter(|j| !unjustified.contains(&j.dealer()))
+            .map(|j| Justifications::from_bytes(&j.into_bytes()).unwrap())
+            .collect();
+
+        parties
+            .into_iter()
+            .map(|p| p.finish(&justifications).unwrap())
+            .collect()
+    }
+
+    /// Checks that the `honest` parties agree on the keys and that any
+    /// `threshold` of them sign together.
+    fn check_outputs<V: Variant>(
+        outputs: &[Output<V>],
+        honest: &[u32],
+        threshold: usize,
+        qualified: &[u32],
+    ) {
+        let outputs: Vec<_> = outputs
+            .iter()
+            .filter(|o| honest.contains(&o.share().index()))
+            .collect();
+        for o in &outputs {
+            assert_eq!(o.qualified(), qualified);
+            assert_eq!(o.public_key(), outputs[0].public_key());
+            assert_eq!(o.commitments(), outputs[0].commitments());
+            assert_eq!(o.commitments().len(), threshold);
+            assert_eq!(
+                o.public_share(o.share().index()).unwrap(),
+                o.share().public_share()
+            );
+        }
+
+        let msg = b"distributed key";
+        let partials: Vec<PartialSignature<V>> =
+            outputs.iter().map(|o| o.share().sign(msg)).collect();
+        for p in &partials {
+            assert!(outputs[0].public_share(p.index()).unwrap().verify(msg, p));
+        }
+        let sig = combine(threshold, &partials).unwrap();
+        assert!(outputs[0].public_key().verify(Scheme::Basic, msg, &sig));
+        let sig = combine(threshold, &partials[partials.len() - threshold..]).unwrap();
+        assert!(outputs[0].public_key().verify(Scheme::Basic, msg, &sig));
+    }
+
+    fn dkg_tests<V: Variant>() {
+        let mut rng = XorShiftRng::seed_from_u64(0x2f4a7c15e093b6d8);
+
+        let outputs = run::<V>(5, 3, &[], &[], &[], &mut rng);
+        check_outputs(&outputs, &[1, 2, 3, 4, 5], 3, &[1, 2, 3, 4, 5]);
+
+        // Dealer 2 answers the complaint of party 4 and stays qualified,
+        // dealer 3 does not answer and dealer 5 does not deal.
+        let outputs = run::<V>(5, 3, &[5], &[(2, 4), (3, 1), (3, 4)], &[3], &mut rng);
+        check_outputs(&outputs, &[1, 2, 4], 3, &[1, 2, 4]);
+
+        // Dealer 1 answers the complaints, but there are too many of them.
+        let outputs = run::<V>(5, 3, &[], &[(1, 2), (1, 3), (1, 4)], &[], &mut rng);
+        check_outputs(&outputs, &[2, 3, 4, 5], 3, &[2, 3, 4, 5]);
+
+        // A threshold of one
+        let outputs = run::<V>(3, 1, &[], &[], &[], &mut rng);
+        check_outputs(&outputs, &[1, 2, 3], 1, &[1, 2, 3]);
+
+        for &(index, threshold, n) in &[(0, 3, 5), (6, 3, 5), (1, 0, 5), (1, 6, 5)] {
+            assert!(Dealing::<V>::new(index, threshold, n, &mut rng).is_err());
+        }
+    }
+
+    variant_tests!(dkg_tests);
+
+    #[test]
+    fn test_message_encodings() {
+        let mut rng = XorShiftRng::seed_from_u64(0x9b05688c2b3e6c1f);
+        let party = Dealing::<MinPk<Bls12>>::new(2, 2, 3, &mut rng).unwrap();
+
+        let commitments = party.commitments();
+        let bytes = commitments.into_bytes();
+        assert_eq!(bytes.len(), 8 + 2 * 48);
+        assert_eq!(Commitments::from_bytes(&bytes).unwrap(), commitments);
+        assert!(Commitments::<MinPk<Bls12>>::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        let mut extra = bytes.clone();
+        extra.push(0);
+        assert!(Commitments::<MinPk<Bls12>>::from_bytes(&extra).is_err());
+
+        let share = party.shares()[0].clone();
+        assert_eq!(share.recipient(), 1);
+        let bytes = share.into_bytes();
+        assert_eq!(bytes.len(), 8 + 32);
+        assert_eq!(Share::from_bytes(&bytes).unwrap(), share);
+        let mut invalid = bytes.clone();
+        for b in &mut invalid[8..] {
+            *b = 0xff;
+        }
+        assert!(Share::<Bls12>::from_bytes(&invalid).is_err());
+
+        let complaints = Complaints {
+            sender: 1,
+            against: vec![2, 3],
+        };
+        assert_eq!(
+            Complaints::from_bytes(&complaints.into_bytes()).unwrap(),
+            complaints
+        );
+        assert!(Complaints::from_bytes(&complaints.into_bytes()[..11]).is_err());
+
+        let (party, _) = party.receive(&[], &[]);
+        let (_, justifications) = party.receive(&[complaints]);
+        assert_eq!(justifications.shares.len(), 1);
+        let bytes = justifications.into_bytes();
+        assert_eq!(Justifications::from_bytes(&bytes).unwrap(), justifications);
+        assert!(Justifications::<Bls12>::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_messages() {
+        extern crate serde_json;
+
+        let mut rng = XorShiftRng::seed_from_u64(0x7d3e2a61c4b8f905);
+        let party = Dealing::<MinSig<Bn256>>::new(1, 2, 2, &mut rng).unwrap();
+
+        let commitments = party.commitments();
+        let json = serde_json::to_string(&commitments).unwrap();
+        assert_eq!(
+            json,
+            format!("\"{}\"", ::hex::encode(commitments.into_bytes()))
+        );
+        let de: Commitments<MinSig<Bn256>> = serde_json::from_str(&json).unwrap();
+        assert_eq!(de, commitments);
+
+        let share = party.shares()[0].clone();
+        let json = serde_json::to_string(&share).unwrap();
+        let de: Share<Bn256> = serde_json::from_str(&json).unwrap();
+        assert_eq!(de, share);
+
+        let (party, complaints) = party.receive(&[], &[]);
+        let json = serde_json::to_string(&complaints).unwrap();
+        let de: Complaints = serde_json::from_str(&json).unwrap();
+        assert_eq!(de, complaints);
+
+        let (_, justifications) = party.receive(&[Complaints {
+            sender: 2,
+            against: vec![1],
+        }]);
+        let json = serde_json::to_string(&justifications).unwrap();
+        let de: Justifications<Bn256> = serde_json::from_str(&json).unwrap();
+        assert_eq!(de, justifications);
+
+        assert!(serde_json::from_str::<Complaints>("\"00\"").is_err());
+        assert!(serde_json::from_str::<Complaints>("\"zz\"").is_err());
+    }
+}
@@ -0,0 +1,773 @@
+//! BLS signatures, following
+//! [draft-irtf-cfrg-bls-signature-05](https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-bls-signature-05),
+//! generic over the pairing engine.
+//!
+//! Both variants of the draft are available: `MinPk`, with public keys in G1
+//! and signatures in G2, and `MinSig`, with public keys in G2 and signatures
+//! in G1. Messages are hashed to the group of signatures with
+//! `hash_to_curve`, so that this group must implement `MapToCurve`, and its
+//! suite identifier goes into the domain separation tags of the ciphersuites.
+//!
+//! The three schemes of the draft are selected with `Scheme`. They differ in
+//! how they prevent rogue key attacks on aggregate signatures: the basic
+//! scheme requires distinct messages, message augmentation prepends the
+//! public key to every message, and the proof of possession scheme requires
+//! every public key to come with a proof that its owner knows the secret key.
+
+use std::error::Error as StdError;
+use std::fmt;
+use std::marker::PhantomData;
+
+use ff::{Field, PrimeField, PrimeFieldRepr, ScalarEngine};
+use sha2::{Digest, Sha256};
+
+use hash_to_curve::{prime_field_from_okm, HashToCurve, MapToCurve};
+use {CurveAffine, CurveProjective, EncodedPoint, Engine, GroupDecodingError};
+
+/// Defines the tests `test_bls12_381` and `test_bn256`, which run the
+/// generic tests `$tests` with both variants of the engine.
+#[cfg(test)]
+macro_rules! variant_tests {
+    ($tests:ident) => {
+        #[test]
+        fn test_bls12_381() {
+            $tests::<::signature::bls::MinPk<::bls12_381::Bls12>>();
+            $tests::<::signature::bls::MinSig<::bls12_381::Bls12>>();
+        }
+
+        #[test]
+        fn test_bn256() {
+            $tests::<::signature::bls::MinPk<::bn256::Bn256>>();
+            $tests::<::signature::bls::MinSig<::bn256::Bn256>>();
+        }
+    };
+}
+
+pub mod batch;
+pub mod bdn;
+pub mod dkg;
+pub mod threshold;
+
+/// The affine representation of the public keys of the variant `V`.
+pub type PublicKeyAffine<V> = <<V as Variant>::PublicKeyGroup as CurveProjective>::Affine;
+
+/// The affine representation of the signatures of the variant `V`.
+pub type SignatureAffine<V> = <<V as Variant>::SignatureGroup as CurveProjective>::Affine;
+
+/// The groups that public keys and signatures live in.
+pub trait Variant: 'static {
+    /// The engine computing the pairing.
+    type Engine: Engine;
+
+    /// The group of public keys, whose generator is the `P` of the draft.
+    type PublicKeyGroup: CurveProjective<
+        Engine = Self::Engine,
+        Scalar = <Self::Engine as ScalarEngine>::Fr,
+    >;
+
+    /// The group of signatures, that messages are hashed to.
+    type SignatureGroup: CurveProjective<
+            Engine = Self::Engine,
+            Scalar = <Self::Engine as ScalarEngine>::Fr,
+        > + MapToCurve;
+
+    /// Checks whether the product of the pairings of the pairs of a public
+    /// key and a signature is one.
+    fn pairing_product_is_one(pairs: &[(PublicKeyAffine<Self>, SignatureAffine<Self>)]) -> bool;
+}
+
+/// Public keys in G1 and signatures in G2, the variant with the smallest
+/// public keys.
+#[derive(Debug)]
+pub struct MinPk<E>(PhantomData<E>);
+
+impl<E: Engine> Variant for MinPk<E>
+where
+    E::G2: MapToCurve,
+{
+    type Engine = E;
+    type PublicKeyGroup = E::G1;
+    type SignatureGroup = E::G2;
+
+    fn pairing_product_is_one(pairs: &[(E::G1Affine, E::G2Affine)]) -> bool {
+        E::pairing_product_is_one(pairs)
+    }
+}
+
+/// Public keys in G2 and signatures in G1, the variant with the smallest
+/// signatures.
+#[derive(Debug)]
+pub struct MinSig<E>(PhantomData<E>);
+
+impl<E: Engine> Variant for MinSig<E>
+where
+    E::G1: MapToCurve,
+{
+    type Engine = E;
+    type PublicKeyGroup = E::G2;
+    type SignatureGroup = E::G1;
+
+    fn pairing_product_is_one(pairs: &[(E::G2Affine, E::G1Affine)]) -> bool {
+        let pairs: Vec<_> = pairs.iter().map(|&(pk, sig)| (sig, pk)).collect();
+        E::pairing_product_is_one(&pairs)
+    }
+}
+
+/// The schemes of the draft, section 3, which prevent rogue key attacks on
+/// aggregate signatures in different ways.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Scheme {
+    /// Aggregate signatures must be on distinct messages.
+    Basic,
+    /// Every message is signed together with the public key.
+    MessageAugmentation,
+    /// Public keys come with a proof of possession of their secret key.
+    ProofOfPossession,
+}
+
+impl Scheme {
+    fn tag(&self) -> &'static [u8] {
+        match *self {
+            Scheme::Basic => b"NUL_",
+            Scheme::MessageAugmentation => b"AUG_",
+            Scheme::ProofOfPossession => b"POP_",
+        }
+    }
+
+    /// The identifier of the ciphersuite of this scheme and the variant `V`,
+    /// such as `BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_`, which is the
+    /// domain separation tag of its signatures.
+    pub fn ciphersuite_id<V: Variant>(&self) -> Vec<u8> {
+        domain_separation_tag::<V>(b"BLS_SIG_", self.tag())
+    }
+}
+
+fn domain_separation_tag<V: Variant>(prefix: &[u8], tag: &[u8]) -> Vec<u8> {
+    let mut dst = prefix.to_vec();
+    dst.extend_from_slice(V::SignatureGroup::SUITE_ID);
+    dst.extend_from_slice(tag);
+    dst
+}
+
+/// An error when creating or decoding keys and signatures.
+#[derive(Debug)]
+pub enum Error {
+    /// The input keying material of `KeyGen` is shorter than 32 bytes.
+    ShortInputKeyingMaterial,
+    /// The secret key is zero or not the encoding of a scalar.
+    InvalidSecretKey,
+    /// The public key is the identity.
+    IdentityPublicKey,
+    /// There is nothing to aggregate.
+    EmptyAggregate,
+    /// The numbers of keys and signatures to aggregate differ.
+    LengthMismatch,
+    /// The threshold is zero or larger than the number of shares.
+    InvalidThreshold,
+    /// The index of a share is zero or repeated.
+    InvalidShareIndex,
+    /// There are fewer shares than the threshold.
+    NotEnoughShares,
+    /// Every dealer of the key generation was disqualified.
+    NoQualifiedDealers,
+    /// The public key or signature could not be decoded.
+    Decoding(GroupDecodingError),
+}
+
+impl From<GroupDecodingError> for Error {
+    fn from(e: GroupDecodingError) -> Error {
+        Error::Decoding(e)
+    }
+}
+
+impl StdError for Error {
+    fn description(&self) -> &str {
+        match *self {
+            Error::ShortInputKeyingMaterial => "input keying material is shorter than 32 bytes",
+            Error::InvalidSecretKey => "secret key is not a nonzero scalar",
+            Error::IdentityPublicKey => "public key is the identity",
+            Error::EmptyAggregate => "nothing to aggregate",
+            Error::LengthMismatch => "numbers of keys and signatures differ",
+            Error::InvalidThreshold => "threshold is zero or larger than the number of shares",
+            Error::InvalidShareIndex => "share index is zero or repeated",
+            Error::NotEnoughShares => "fewer shares than the threshold",
+            Error::NoQualifiedDealers => "every dealer was disqualified",
+            Error::Decoding(..) => "public key or signature could not be decoded",
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    #[allow(deprecated)]
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            Error::Decoding(ref e) => write!(f, "decoding error: {}", e),
+            _ => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+/// HMAC with SHA-256 of the concatenation of `data`, as in RFC 2104.
+fn hmac_sha256(key: &[u8], data: &[&[u8]]) -> [u8; 32] {
+    let mut padded_key = [0u8; 64];
+    if key.len() > 64 {
+        padded_key[..32].copy_from_slice(&Sha256::digest(key));
+    } else {
+        padded_key[..key.len()].copy_from_slice(key);
+    }
+
+    let mut inner = Sha256::new();
+    inner.update(padded_key.iter().map(|k| k ^ 0x36).collect::<Vec<_>>());
+    for d in data {
+        inner.update(d);
+    }
+
+    let mut outer = Sha256::new();
+    outer.update(padded_key.iter().map(|k| k ^ 0x5c).collect::<Vec<_>>());
+    outer.update(inner.finalize());
+
+    let mut res = [0u8; 32];
+    res.copy_from_slice(&outer.finalize());
+    res
+}
+
+/// HKDF-Expand with SHA-256 of the concatenation of `info`, as in RFC 5869.
+fn hkdf_expand(prk: &[u8], info: &[&[u8]], len: usize) -> Vec<u8> {
+    let mut okm = Vec::with_capacity(len + 32);
+    let mut t = vec![];
+    let mut i = 1u8;
+    while okm.len() < len {
+        let mut data = vec![&t[..]];
+        data.extend_from_slice(info);
+        let counter = [i];
+        data.push(&counter[..]);
+        t = hmac_sha256(prk, &data).to_vec();
+        okm.extend_from_slice(&t);
+        i += 1;
+    }
+    okm.truncate(len);
+    okm
+}
+
+/// A secret key, a nonzero scalar.
+pub struct SecretKey<E: Engine>(E::Fr);
+
+impl<E: Engine> Clone for SecretKey<E> {
+    fn clone(&self) -> Self {
+        SecretKey(self.0)
+    }
+}
+
+impl<E: Engine> PartialEq for SecretKey<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<E: Engine> Eq for SecretKey<E> {}
+
+impl<E: Engine> fmt::Debug for SecretKey<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SecretKey(..)")
+    }
+}
+
+impl<E: Engine> SecretKey<E> {
+    /// Derives a secret key from at least 32 bytes of secret, uniformly
+    /// random input keying material `ikm` and an optional `key_info`, with
+    /// `KeyGen` of the draft, section 2.3.
+    pub fn key_gen(ikm: &[u8], key_info: &[u8]) -> Result<Self, Error> {
+        if ikm.len() < 32 {
+            return Err(Error::ShortInputKeyingMaterial);
+        }
+
+        // L = ceil((3 * ceil(log2(r))) / 16)
+        let len = (3 * E::Fr::NUM_BITS as usize).div_ceil(16);
+        let len_bytes = [(len >> 8) as u8, len as u8];
+
+        let mut salt = Sha256::digest(b"BLS-SIG-KEYGEN-SALT-");
+        loop {
+            let prk = hmac_sha256(&salt, &[ikm, &[0u8]]);
+            let okm = hkdf_expand(&prk, &[key_info, &len_bytes], len);
+            let sk: E::Fr = prime_field_from_okm(&okm);
+            if !sk.is_zero() {
+                return Ok(SecretKey(sk));
+            }
+            salt = Sha256::digest(&salt[..]);
+        }
+    }
+
+    /// Wraps a nonzero scalar.
+    pub fn from_scalar(sk: E::Fr) -> Result<Self, Error> {
+        if sk.is_zero() {
+            return Err(Error::InvalidSecretKey);
+        }
+
+        Ok(SecretKey(sk))
+    }
+
+    /// Returns the scalar of this key.
+    pub fn into_scalar(&self) -> E::Fr {
+        self.0
+    }
+
+    /// Encodes the key as a big-endian integer, `I2OSP(SK, 32)` for the
+    /// scalar fields of this crate.
+    pub fn into_bytes(&self) -> Vec<u8> {
+        let mut res = vec![];
+        self.0.into_repr().write_be(&mut res).unwrap();
+        res
+    }
+
+    /// Decodes a key encoded by `into_bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let mut repr = <E::Fr as PrimeField>::Repr::default();
+        if bytes.len() != repr.as_ref().len() * 8 {
+            return Err(Error::InvalidSecretKey);
+        }
+        repr.read_be(bytes).map_err(|_| Error::InvalidSecretKey)?;
+        let sk = E::Fr::from_repr(repr).map_err(|_| Error::InvalidSecretKey)?;
+
+        SecretKey::from_scalar(sk)
+    }
+
+    /// Computes the public key, `SkToPk` of the draft.
+    pub fn public_key<V: Variant<Engine = E>>(&self) -> PublicKey<V> {
+        let mut pk = V::PublicKeyGroup::one();
+        pk.mul_assign(self.0);
+        PublicKey(pk.into_affine())
+    }
+
+    /// Signs `msg` with the ciphersuite of `scheme`.
+    pub fn sign<V: Variant<Engine = E>>(&self, scheme: Scheme, msg: &[u8]) -> Signature<V> {
+        let msg = match scheme {
+            Scheme::MessageAugmentation => augment(&self.public_key::<V>(), msg),
+            _ => msg.to_vec(),
+        };
+
+        self.sign_with_dst(&msg, &scheme.ciphersuite_id::<V>())
+    }
+
+    /// Proves the possession of this key, `PopProve` of the draft, by signing
+    /// the public key with the domain separation tag of proofs.
+    pub fn pop_prove<V: Variant<Engine = E>>(&self) -> Signature<V> {
+        let pk = self.public_key::<V>();
+        self.sign_with_dst(&pk.into_bytes(), &pop_dst::<V>())
+    }
+
+    fn sign_with_dst<V: Variant<Engine = E>>(&self, msg: &[u8], dst: &[u8]) -> Signature<V> {
+        let mut sig = V::SignatureGroup::hash_to_curve(msg, dst);
+        sig.mul_assign(self.0);
+        Signature(sig.into_affine())
+    }
+}
+
+fn pop_dst<V: Variant>() -> Vec<u8> {
+    domain_separation_tag::<V>(b"BLS_POP_", Scheme::ProofOfPossession.tag())
+}
+
+/// Prepends the encoding of `pk` to `msg`, for message augmentation.
+fn augment<V: Variant>(pk: &PublicKey<V>, msg: &[u8]) -> Vec<u8> {
+    let mut res = pk.into_bytes();
+    res.extend_from_slice(msg);
+    res
+}
+
+macro_rules! point_wrapper_impls {
+    ($name:ident) => {
+        impl<V: Variant> Clone for $name<V> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<V: Variant> Copy for $name<V> {}
+
+        impl<V: Variant> PartialEq for $name<V> {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl<V: Variant> Eq for $name<V> {}
+
+        impl<V: Variant> fmt::Debug for $name<V> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{}({:?})", stringify!($name), self.0)
+            }
+        }
+    };
+}
+
+/// Decodes the compressed encoding of a point, checking that it is in the
+/// prime order subgroup.
+fn decode<G: CurveAffine>(bytes: &[u8]) -> Result<G, Error> {
+    let mut encoded = G::Compressed::empty();
+    if bytes.len() != encoded.as_ref().len() {
+        return Err(Error::Decoding(GroupDecodingError::UnexpectedInformation));
+    }
+    encoded.as_mut().copy_from_slice(bytes);
+
+    Ok(encoded.into_affine()?)
+}
+
+/// A public key, a point of the public key group other than the identity.
+pub struct PublicKey<V: Variant>(PublicKeyAffine<V>);
+
+point_wrapper_impls!(PublicKey);
+
+impl<V: Variant> PublicKey<V> {
+    /// Wraps a point of the public key group, checking that it is in the
+    /// prime order subgroup and not the identity as `KeyValidate` of the
+    /// draft does.
+    pub fn from_affine(pk: PublicKeyAffine<V>) -> Result<Self, Error> {
+        // Decoding the uncompressed point runs the subgroup check of the
+        // group, which `CurveAffine` does not expose.
+        PublicKey::from_subgroup_point(pk.into_uncompressed().into_affine()?)
+    }
+
+    /// Wraps a point already known to be in the prime order subgroup,
+    /// checking that it is not the identity.
+    pub(crate) fn from_subgroup_point(pk: PublicKeyAffine<V>) -> Result<Self, Error> {
+        if pk.is_zero() {
+            return Err(Error::IdentityPublicKey);
+        }
+
+        Ok(PublicKey(pk))
+    }
+
+    /// Returns the point of this key.
+    pub fn into_affine(&self) -> PublicKeyAffine<V> {
+        self.0
+    }
+
+    /// Encodes the key as a compressed point.
+    pub fn into_bytes(&self) -> Vec<u8> {
+        self.0.into_compressed().as_ref().to_vec()
+    }
+
+    /// Decodes a key encoded by `into_bytes`, with the checks of
+    /// `KeyValidate` of the draft.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        PublicKey::from_subgroup_point(decode(bytes)?)
+    }
+
+    /// Aggregates public keys into the public key that verifies the
+    /// aggregate of their signatures on a common message.
+    pub fn aggregate(pks: &[PublicKey<V>]) -> Result<Self, Error> {
+        if pks.is_empty() {
+            return Err(Error::EmptyAggregate);
+        }
+
+        let mut res = V::PublicKeyGroup::zero();
+        for pk in pks {
+            res.add_assign_mixed(&pk.0);
+        }
+
+        PublicKey::from_subgroup_point(res.into_affine())
+    }
+
+    /// Verifies the signature `sig` on `msg` with the ciphersuite of
+    /// `scheme`.
+    pub fn verify(&self, scheme: Scheme, msg: &[u8], sig: &Signature<V>) -> bool {
+        sig.aggregate_verify(scheme, &[*self], &[msg])
+    }
+
+    /// Verifies a proof of possession of the secret key, `PopVerify` of the
+    /// draft.
+    pub fn pop_verify(&self, proof: &Signature<V>) -> bool {
+        proof.core_aggregate_verify(&[(*self, self.into_bytes())], &pop_dst::<V>())
+    }
+}
+
+/// A signature, a point of the signature group.
+pub struct Signature<V: Variant>(SignatureAffine<V>);
+
+point_wrapper_impls!(Signature);
+
+impl<V: Variant> Signature<V> {
+    /// Wraps a point of the signature group.
+    pub fn from_affine(sig: SignatureAffine<V>) -> Self {
+        Signature(sig)
+    }
+
+    /// Returns the point of this signature.
+    pub fn into_affine(&self) -> SignatureAffine<V> {
+        self.0
+    }
+
+    /// Encodes the signature as a compressed point.
+    pub fn into_bytes(&self) -> Vec<u8> {
+        self.0.into_compressed().as_ref().to_vec()
+    }
+
+    /// Decodes a signature encoded by `into_bytes`, checking that it is in
+    /// the prime order subgroup.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Ok(Signature(decode(bytes)?))
+    }
+
+    /// Aggregates signatures into one, `Aggregate` of the draft.
+    pub fn aggregate(sigs: &[Signature<V>]) -> Result<Self, Error> {
+        if sigs.is_empty() {
+            return Err(Error::EmptyAggregate);
+        }
+
+        let mut res = V::SignatureGroup::zero();
+        for sig in sigs {
+            res.add_assign_mixed(&sig.0);
+        }
+
+        Ok(Signature(res.into_affine()))
+    }
+
+    /// Verifies this aggregate signature on the message `msgs[i]` by the key
+    /// `pks[i]` for every `i`, with the ciphersuite of `scheme`. The basic
+    /// scheme rejects repeated messages.
+    pub fn aggregate_verify(&self, scheme: Scheme, pks: &[PublicKey<V>], msgs: &[&[u8]]) -> bool {
+        if pks.is_empty() || pks.len() != msgs.len() {
+            return false;
+        }
+
+        if scheme == Scheme::Basic {
+            let mut sorted = msgs.to_vec();
+            sorted.sort();
+            sorted.dedup();
+            if sorted.len() != msgs.len() {
+                return false;
+            }
+        }
+
+        let pairs: Vec<_> = pks
+            .iter()
+            .zip(msgs.iter())
+            .map(|(pk, msg)| match scheme {
+                Scheme::MessageAugmentation => (*pk, augment(pk, msg)),
+                _ => (*pk, msg.to_vec()),
+            })
+            .collect();
+
+        self.core_aggregate_verify(&pairs, &scheme.ciphersuite_id::<V>())
+    }
+
+    /// Verifies this aggregate signature on `msg` by all of `pks`, which must
+    /// come with verified proofs of possession, `FastAggregateVerify` of the
+    /// draft.
+    pub fn fast_aggregate_verify(&self, pks: &[PublicKey<V>], msg: &[u8]) -> bool {
+        match PublicKey::aggregate(pks) {
+            Ok(pk) => pk.verify(Scheme::ProofOfPossession, msg, self),
+            Err(_) => false,
+        }
+    }
+
+    /// Checks that the product of e(pk, H(msg)) over the pairs `(pk, msg)` is
+    /// e(P, sig), `CoreAggregateVerify` of the draft.
+    fn core_aggregate_verify(&self, pairs: &[(PublicKey<V>, Vec<u8>)], dst: &[u8]) -> bool {
+        let mut minus_p = V::PublicKeyGroup::one().into_affine();
+        minus_p.negate();
+
+        let mut terms: Vec<_> = pairs
+            .iter()
+            .map(|(pk, msg)| {
+                (pk.0, V::SignatureGroup::hash_to_curve(msg, dst).into_affine())
+            })
+            .collect();
+        terms.push((minus_p, self.0));
+
+        V::pairing_product_is_one(&terms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bls12_381::Bls12;
+    use bn256::Bn256;
+    use hex;
+
+    use rand::{Rng, SeedableRng};
+    use rand_xorshift::XorShiftRng;
+
+    #[test]
+    fn test_key_gen_vectors() {
+        // The master keys of the test vectors of EIP-2333, which derives them
+        // with KeyGen from the seed and an empty key_info.
+        let vectors: [(&str, &str); 3] = [
+            (
+                "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e5349553\
+                 1f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+                "6083874454709270928345386274498605044986640685124978867557563392430687146096",
+            ),
+            (
+                "3141592653589793238462643383279502884197169399375105820974944592",
+                "29757020647961307431480504535336562678282505419141012933316116377660817309383",
+            ),
+            (
+                "0099ff991111002299dd7744ee3355bbdd8844115566cc55663355668888cc00",
+                "27580842291869792442942448775674722299803720648445448686099262467207037398656",
+            ),
+        ];
+
+        for &(seed, sk) in vectors.iter() {
+            let seed = hex::decode(seed).unwrap();
+            let expected = <Bls12 as ScalarEngine>::Fr::from_str(sk).unwrap();
+            assert_eq!(SecretKey::<Bls12>::key_gen(&seed, b"").unwrap().into_scalar(), expected);
+        }
+
+        match SecretKey::<Bls12>::key_gen(&[0u8; 31], b"") {
+            Err(Error::ShortInputKeyingMaterial) => {}
+            _ => panic!("short input keying material was accepted"),
+        }
+    }
+
+    #[test]
+    fn test_sign_vectors() {
+        // BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_, from the BLS test
+        // vectors of the Ethereum consensus specifications.
+        let sk = SecretKey::<Bls12>::from_bytes(
+            &hex::decode("263dbd792f5b1be47ed85f8938c0f29586af0d3ac7b977f21c278fe1462040e3")
+                .unwrap(),
+        ).unwrap();
+        let pk = sk.public_key::<MinPk<Bls12>>();
+        assert_eq!(
+            hex::encode(pk.into_bytes()),
+            "a491d1b0ecd9bb917989f0e74f0dea0422eac4a873e5e2644f368dffb9a6e20f\
+             d6e10c1b77654d067c0618f6e5a7f79a"
+        );
+
+        let msg = [0u8; 32];
+        let sig = sk.sign::<MinPk<Bls12>>(Scheme::ProofOfPossession, &msg);
+        assert_eq!(
+            hex::encode(sig.into_bytes()),
+            "b6ed936746e01f8ecf281f020953fbf1f01debd5657c4a383940b020b26507f6\
+             076334f91e2366c96e9ab279fb5158090352ea1c5b0c9274504f4f0e7053af24\
+             802e51e4568d164fe986834f41e55c8e850ce1f98458c0cfc9ab380b55285a55"
+        );
+        assert!(pk.verify(Scheme::ProofOfPossession, &msg, &sig));
+    }
+
+    #[test]
+    fn test_ciphersuite_ids() {
+        assert_eq!(
+            Scheme::ProofOfPossession.ciphersuite_id::<MinPk<Bls12>>(),
+            b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_".to_vec()
+        );
+        assert_eq!(
+            Scheme::Basic.ciphersuite_id::<MinSig<Bls12>>(),
+            b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_".to_vec()
+        );
+        assert_eq!(
+            Scheme::MessageAugmentation.ciphersuite_id::<MinPk<Bn256>>(),
+            b"BLS_SIG_BN254G2_XMD:SHA-256_SVDW_RO_AUG_".to_vec()
+        );
+        assert_eq!(pop_dst::<MinPk<Bls12>>(), b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_".to_vec());
+    }
+
+    #[test]
+    fn test_public_key_subgroup() {
+        use bls12_381::{Fq, Fq2, G1, G2};
+
+        // Points mapped to the curves without clearing the cofactor.
+        let pk = <G1 as MapToCurve>::map_to_curve(&Fq::one()).into_affine();
+        assert!(!pk.is_torsion_free());
+        match PublicKey::<MinPk<Bls12>>::from_affine(pk) {
+            Err(Error::Decoding(GroupDecodingError::NotInSubgroup)) => {}
+            _ => panic!("a point outside the subgroup was accepted as a public key"),
+        }
+        let pk = <G2 as MapToCurve>::map_to_curve(&Fq2::one()).into_affine();
+        assert!(!pk.is_torsion_free());
+        match PublicKey::<MinSig<Bls12>>::from_affine(pk) {
+            Err(Error::Decoding(GroupDecodingError::NotInSubgroup)) => {}
+            _ => panic!("a point outside the subgroup was accepted as a public key"),
+        }
+    }
+
+    /// Derives a secret key from random input keying material.
+    pub(super) fn random_key<E: Engine>(rng: &mut XorShiftRng) -> SecretKey<E> {
+        let ikm: [u8; 32] = rng.gen();
+        SecretKey::key_gen(&ikm, b"").unwrap()
+    }
+
+    /// Derives `n` random secret keys, together with their public keys.
+    pub(super) fn random_keys<V: Variant>(
+        n: usize,
+        rng: &mut XorShiftRng,
+    ) -> (Vec<SecretKey<V::Engine>>, Vec<PublicKey<V>>) {
+        let sks: Vec<_> = (0..n).map(|_| random_key(rng)).collect();
+        let pks = sks.iter().map(|sk| sk.public_key()).collect();
+        (sks, pks)
+    }
+
+    fn scheme_tests<V: Variant>() {
+        let mut rng = XorShiftRng::seed_from_u64(0x5dbe62598d313d76);
+
+        let (sks, pks) = random_keys::<V>(3, &mut rng);
+        let msgs: [&[u8]; 3] = [b"foo", b"bar", b"baz"];
+
+        for &scheme in &[Scheme::Basic, Scheme::MessageAugmentation, Scheme::ProofOfPossession] {
+            let sig = sks[0].sign::<V>(scheme, msgs[0]);
+            assert!(pks[0].verify(scheme, msgs[0], &sig));
+            assert!(!pks[0].verify(scheme, msgs[1], &sig));
+            assert!(!pks[1].verify(scheme, msgs[0], &sig));
+            for &other in &[Scheme::Basic, Scheme::MessageAugmentation, Scheme::ProofOfPossession] {
+                assert_eq!(pks[0].verify(other, msgs[0], &sig), other == scheme);
+            }
+
+            let sigs: Vec<_> = sks
+                .iter()
+                .zip(msgs.iter())
+                .map(|(sk, msg)| sk.sign::<V>(scheme, msg))
+                .collect();
+            let agg = Signature::aggregate(&sigs).unwrap();
+            assert!(agg.aggregate_verify(scheme, &pks, &msgs));
+            assert!(!agg.aggregate_verify(scheme, &pks[..2], &msgs[..2]));
+            assert!(!agg.aggregate_verify(scheme, &[pks[1], pks[0], pks[2]], &msgs));
+            assert!(!agg.aggregate_verify(scheme, &[], &[]));
+
+            // Signatures of a common message only aggregate in the schemes
+            // that do not rely on distinct messages.
+            let sigs: Vec<_> = sks.iter().map(|sk| sk.sign::<V>(scheme, msgs[0])).collect();
+            let agg = Signature::aggregate(&sigs).unwrap();
+            let same = [msgs[0]; 3];
+            assert_eq!(agg.aggregate_verify(scheme, &pks, &same), scheme != Scheme::Basic);
+        }
+
+        // Proofs of possession and fast aggregate verification
+        for (sk, pk) in sks.iter().zip(pks.iter()) {
+            let proof = sk.pop_prove::<V>();
+            assert!(pk.pop_verify(&proof));
+            assert!(!pks[0].pop_verify(&proof) || *pk == pks[0]);
+            // A proof is not a signature of the encoding of the key.
+            assert!(!pk.verify(Scheme::ProofOfPossession, &pk.into_bytes(), &proof));
+        }
+        let sigs: Vec<_> = sks
+            .iter()
+            .map(|sk| sk.sign::<V>(Scheme::ProofOfPossession, msgs[0]))
+            .collect();
+        let agg = Signature::aggregate(&sigs).unwrap();
+        assert!(agg.fast_aggregate_verify(&pks, msgs[0]));
+        assert!(!agg.fast_aggregate_verify(&pks, msgs[1]));
+        assert!(!agg.fast_aggregate_verify(&pks[..2], msgs[0]));
+        assert!(!agg.fast_aggregate_verify(&[], msgs[0]));
+        assert!(Signature::<V>::aggregate(&[]).is_err());
+
+        // Encodings
+        let sig = sks[0].sign::<V>(Scheme::Basic, msgs[0]);
+        assert_eq!(Signature::<V>::from_bytes(&sig.into_bytes()).unwrap(), sig);
+        assert_eq!(PublicKey::<V>::from_bytes(&pks[0].into_bytes()).unwrap(), pks[0]);
+        assert_eq!(SecretKey::<V::Engine>::from_bytes(&sks[0].into_bytes()).unwrap(), sks[0]);
+        assert!(PublicKey::<V>::from_bytes(&pks[0].into_bytes()[1..]).is_err());
+        match PublicKey::<V>::from_affine(PublicKeyAffine::<V>::zero()) {
+            Err(Error::IdentityPublicKey) => {}
+            _ => panic!("the identity was accepted as a public key"),
+        }
+        let zero = PublicKeyAffine::<V>::zero().into_compressed();
+        match PublicKey::<V>::from_bytes(zero.as_ref()) {
+            Err(Error::IdentityPublicKey) => {}
+            _ => panic!("the identity was accepted as a public key"),
+        }
+        assert!(SecretKey::<V::Engine>::from_bytes(&[0u8; 32]).is_err());
+    }
+
+    variant_tests!(scheme_tests);
+}
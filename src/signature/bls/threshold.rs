@@ -0,0 +1,398 @@
+//! Threshold signatures, where any `t` of `n` holders of shares of a secret
+//! key sign together.
+//!
+//! The secret key is shared with Shamir's scheme: the share of index `i` is
+//! `f(i)` for a random polynomial `f` of degree `t - 1` over the scalar
+//! field whose constant term is the secret key. Every holder signs with its
+//! share, and `t` partial signatures combine into the signature of the
+//! secret key by Lagrange interpolation at zero in the signature group.
+//!
+//! The partial and combined signatures are those of the basic scheme, so
+//! that the combined signature verifies as a signature of the basic scheme
+//! by the public key of the shared secret key.
+
+use std::fmt;
+
+use ff::PrimeField;
+use rand::Rng;
+
+use super::{Error, PublicKey, PublicKeyAffine, Scheme, SecretKey, Signature, Variant};
+use {multiexp, CurveAffine, CurveProjective, Engine};
+
+/// A polynomial over a prime field, by its coefficients from the constant
+/// term up.
+pub(crate) struct Polynomial<F: PrimeField>(Vec<F>);
+
+impl<F: PrimeField> Polynomial<F> {
+    /// Samples a polynomial of degree `degree` with the constant term
+    /// `constant`.
+    pub(crate) fn random<R: Rng>(constant: F, degree: usize, rng: &mut R) -> Self {
+        let mut coeffs = vec![constant];
+        coeffs.extend((0..degree).map(|_| F::rand(rng)));
+        Polynomial(coeffs)
+    }
+
+    pub(crate) fn coefficients(&self) -> &[F] {
+        &self.0
+    }
+
+    /// Evaluates the polynomial at the index `index`.
+    pub(crate) fn evaluate(&self, index: u32) -> F {
+        let x = scalar_from_index::<F>(index);
+        let mut res = F::zero();
+        for c in self.0.iter().rev() {
+            res.mul_assign(&x);
+            res.add_assign(c);
+        }
+        res
+    }
+}
+
+pub(crate) fn scalar_from_index<F: PrimeField>(index: u32) -> F {
+    F::from_repr(F::Repr::from(index as u64)).unwrap()
+}
+
+/// Computes the Lagrange coefficients for the interpolation at zero from the
+/// values at `indices`, which must be distinct and nonzero.
+fn lagrange_coefficients<F: PrimeField>(indices: &[u32]) -> Vec<F> {
+    let xs: Vec<F> = indices.iter().map(|&i| scalar_from_index(i)).collect();
+
+    xs.iter()
+        .enumerate()
+        .map(|(i, x_i)| {
+            let mut num = F::one();
+            let mut den = F::one();
+            for (j, x_j) in xs.iter().enumerate() {
+                if i != j {
+                    num.mul_assign(x_j);
+                    let mut diff = *x_j;
+                    diff.sub_assign(x_i);
+                    den.mul_assign(&diff);
+                }
+            }
+            num.mul_assign(&den.inverse().unwrap());
+            num
+        })
+        .collect()
+}
+
+/// Interpolates at zero the first `threshold` of the points `(indices[i],
+/// values[i])` in the exponent.
+fn interpolate<G: CurveProjective>(
+    threshold: usize,
+    indices: &[u32],
+    values: &[G::Affine],
+) -> Result<G, Error> {
+    if threshold == 0 {
+        return Err(Error::InvalidThreshold);
+    }
+    if indices.len() < threshold {
+        return Err(Error::NotEnoughShares);
+    }
+    let indices = &indices[..threshold];
+    for (i, &index) in indices.iter().enumerate() {
+        if index == 0 || indices[..i].contains(&index) {
+            return Err(Error::InvalidShareIndex);
+        }
+    }
+
+    let coeffs: Vec<_> = lagrange_coefficients::<G::Scalar>(indices)
+        .into_iter()
+        .map(|l| l.into_repr())
+        .collect();
+
+    Ok(multiexp(&values[..threshold], &coeffs))
+}
+
+/// A share of a secret key, the value at a nonzero index of the sharing
+/// polynomial.
+pub struct SecretShare<E: Engine> {
+    index: u32,
+    value: E::Fr,
+}
+
+impl<E: Engine> Clone for SecretShare<E> {
+    fn clone(&self) -> Self {
+        SecretShare {
+            index: self.index,
+            value: self.value,
+        }
+    }
+}
+
+impl<E: Engine> PartialEq for SecretShare<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index && self.value == other.value
+    }
+}
+
+impl<E: Engine> Eq for SecretShare<E> {}
+
+impl<E: Engine> fmt::Debug for SecretShare<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SecretShare {{ index: {}, .. }}", self.index)
+    }
+}
+
+impl<E: Engine> SecretShare<E> {
+    /// Creates the share of value `value` at the index `index`, which must
+    /// not be zero.
+    pub fn new(index: u32, value: E::Fr) -> Result<Self, Error> {
+        if index == 0 {
+            return Err(Error::InvalidShareIndex);
+        }
+
+        Ok(SecretShare { index, value })
+    }
+
+    /// Returns the index of this share.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Returns the value of this share.
+    pub fn into_scalar(&self) -> E::Fr {
+        self.value
+    }
+
+    /// Computes the public key of this share.
+    pub fn public_share<V: Variant<Engine = E>>(&self) -> PublicShare<V> {
+        let mut pk = V::PublicKeyGroup::one();
+        pk.mul_assign(self.value);
+        PublicShare {
+            index: self.index,
+            pk: pk.into_affine(),
+        }
+    }
+
+    /// Signs `msg` with this share, with the ciphersuite of the basic
+    /// scheme.
+    pub fn sign<V: Variant<Engine = E>>(&self, msg: &[u8]) -> PartialSignature<V> {
+        PartialSignature {
+            index: self.index,
+            sig: SecretKey(self.value).sign(Scheme::Basic, msg),
+        }
+    }
+}
+
+/// Splits `sk` into `n` shares, at the indices `1` to `n`, any `threshold`
+/// of which recover it.
+pub fn split<E: Engine, R: Rng>(
+    sk: &SecretKey<E>,
+    threshold: usize,
+    n: usize,
+    rng: &mut R,
+) -> Result<Vec<SecretShare<E>>, Error> {
+    if threshold == 0 || threshold > n || n > u32::MAX as usize {
+        return Err(Error::InvalidThreshold);
+    }
+
+    let poly = Polynomial::random(sk.into_scalar(), threshold - 1, rng);
+    Ok((1..=n as u32)
+        .map(|index| SecretShare {
+            index,
+            value: poly.evaluate(index),
+        })
+        .collect())
+}
+
+macro_rules! indexed_impls {
+    ($name:ident, $field:ident) => {
+        impl<V: Variant> Clone for $name<V> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<V: Variant> Copy for $name<V> {}
+
+        impl<V: Variant> PartialEq for $name<V> {
+            fn eq(&self, other: &Self) -> bool {
+                self.index == other.index && self.$field == other.$field
+            }
+        }
+
+        impl<V: Variant> Eq for $name<V> {}
+
+        impl<V: Variant> fmt::Debug for $name<V> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field("index", &self.index)
+                    .field(stringify!($field), &self.$field)
+                    .finish()
+            }
+        }
+    };
+}
+
+/// The public key of a share, which verifies its partial signatures.
+pub struct PublicShare<V: Variant> {
+    index: u32,
+    pk: PublicKeyAffine<V>,
+}
+
+indexed_impls!(PublicShare, pk);
+
+impl<V: Variant> PublicShare<V> {
+    /// Creates the public key `pk` of the share at the index `index`, which
+    /// must not be zero.
+    pub fn new(index: u32, pk: PublicKeyAffine<V>) -> Result<Self, Error> {
+        if index == 0 {
+            return Err(Error::InvalidShareIndex);
+        }
+
+        Ok(PublicShare { index, pk })
+    }
+
+    /// Returns the index of the share.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Returns the point of this key.
+    pub fn into_affine(&self) -> PublicKeyAffine<V> {
+        self.pk
+    }
+
+    /// Verifies that `partial` is a signature on `msg` by this share.
+    pub fn verify(&self, msg: &[u8], partial: &PartialSignature<V>) -> bool {
+        partial.index == self.index
+            && !self.pk.is_zero()
+            && PublicKey(self.pk).verify(Scheme::Basic, msg, &partial.sig)
+    }
+}
+
+/// A signature by a share.
+pub struct PartialSignature<V: Variant> {
+    index: u32,
+    sig: Signature<V>,
+}
+
+indexed_impls!(PartialSignature, sig);
+
+impl<V: Variant> PartialSignature<V> {
+    /// Creates the signature `sig` by the share at the index `index`, which
+    /// must not be zero.
+    pub fn new(index: u32, sig: Signature<V>) -> Result<Self, Error> {
+        if index == 0 {
+            return Err(Error::InvalidShareIndex);
+        }
+
+        Ok(PartialSignature { index, sig })
+    }
+
+    /// Returns the index of the share.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Returns the signature.
+    pub fn signature(&self) -> Signature<V> {
+        self.sig
+    }
+}
+
+/// Combines the first `threshold` of `partials`, which must have distinct
+/// indices, into the signature of the shared secret key.
+///
+/// The partial signatures are not verified: an invalid one makes an invalid
+/// signature, so that they should be verified against their public shares
+/// first.
+pub fn combine<V: Variant>(
+    threshold: usize,
+    partials: &[PartialSignature<V>],
+) -> Result<Signature<V>, Error> {
+    let indices: Vec<_> = partials.iter().map(|p| p.index).collect();
+    let sigs: Vec<_> = partials.iter().map(|p| p.sig.into_affine()).collect();
+    let sig: V::SignatureGroup = interpolate(threshold, &indices, &sigs)?;
+
+    Ok(Signature::from_affine(sig.into_affine()))
+}
+
+/// Combines the first `threshold` of `shares`, which must have distinct
+/// indices, into the public key of the shared secret key.
+pub fn combine_public_shares<V: Variant>(
+    threshold: usize,
+    shares: &[PublicShare<V>],
+) -> Result<PublicKey<V>, Error> {
+    let indices: Vec<_> = shares.iter().map(|s| s.index).collect();
+    let pks: Vec<_> = shares.iter().map(|s| s.pk).collect();
+    let pk: V::PublicKeyGroup = interpolate(threshold, &indices, &pks)?;
+
+    PublicKey::from_affine(pk.into_affine())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::random_key;
+    use super::*;
+
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    fn threshold_tests<V: Variant>() {
+        let mut rng = XorShiftRng::seed_from_u64(0x61c8864680b583eb);
+
+        let sk: SecretKey<V::Engine> = random_key(&mut rng);
+        let pk = sk.public_key::<V>();
+        let msg = b"threshold";
+
+        let shares = split(&sk, 3, 5, &mut rng).unwrap();
+        assert_eq!(shares.len(), 5);
+        let public_shares: Vec<PublicShare<V>> = shares.iter().map(|s| s.public_share()).collect();
+        let partials: Vec<PartialSignature<V>> = shares.iter().map(|s| s.sign(msg)).collect();
+
+        for (i, public_share) in public_shares.iter().enumerate() {
+            for (j, partial) in partials.iter().enumerate() {
+                assert_eq!(public_share.verify(msg, partial), i == j);
+            }
+            assert!(!public_share.verify(b"other", &partials[i]));
+        }
+
+        let expected = sk.sign::<V>(Scheme::Basic, msg);
+        for subset in &[[0, 1, 2], [4, 2, 0], [1, 3, 4], [3, 4, 2]] {
+            let chosen: Vec<_> = subset.iter().map(|&i| partials[i]).collect();
+            let sig = combine(3, &chosen).unwrap();
+            assert_eq!(sig, expected);
+            assert!(pk.verify(Scheme::Basic, msg, &sig));
+
+            let chosen: Vec<_> = subset.iter().map(|&i| public_shares[i]).collect();
+            assert_eq!(combine_public_shares(3, &chosen).unwrap(), pk);
+        }
+        // Only the first threshold partial signatures are used.
+        assert_eq!(combine(3, &partials).unwrap(), expected);
+
+        // Two shares reveal nothing about the key.
+        match combine(3, &partials[..2]) {
+            Err(Error::NotEnoughShares) => {}
+            _ => panic!("too few partial signatures were combined"),
+        }
+        assert_ne!(combine(2, &partials[..2]).unwrap(), expected);
+
+        match combine(3, &[partials[0], partials[1], partials[0]]) {
+            Err(Error::InvalidShareIndex) => {}
+            _ => panic!("a repeated index was accepted"),
+        }
+        match combine(0, &partials) {
+            Err(Error::InvalidThreshold) => {}
+            _ => panic!("a zero threshold was accepted"),
+        }
+
+        // An invalid partial signature makes an invalid signature.
+        let forged = PartialSignature::new(2, partials[0].signature()).unwrap();
+        assert!(!public_shares[1].verify(msg, &forged));
+        let sig = combine(3, &[partials[0], forged, partials[2]]).unwrap();
+        assert!(!pk.verify(Scheme::Basic, msg, &sig));
+
+        assert!(split(&sk, 0, 5, &mut rng).is_err());
+        assert!(split(&sk, 6, 5, &mut rng).is_err());
+        assert!(SecretShare::<V::Engine>::new(0, shares[0].into_scalar()).is_err());
+        assert!(PartialSignature::<V>::new(0, expected).is_err());
+
+        // A threshold of one shares the key itself.
+        let shares = split(&sk, 1, 3, &mut rng).unwrap();
+        assert!(shares.iter().all(|s| s.into_scalar() == sk.into_scalar()));
+    }
+
+    variant_tests!(threshold_tests);
+}
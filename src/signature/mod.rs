@@ -0,0 +1,3 @@
+//! Signature schemes built on the pairing engines of this crate.
+
+pub mod bls;
@@ -65,9 +65,84 @@ pub fn curve_tests<G: CurveProjective>() {
     random_negation_tests::<G>();
     random_transformation_tests::<G>();
     random_wnaf_tests::<G>();
+    random_multiexp_tests::<G>();
+    #[cfg(feature = "parallel")]
+    random_parallel_tests::<G>();
     random_encoding_tests::<G::Affine>();
 }
 
+fn random_multiexp_tests<G: CurveProjective>() {
+    use ff::PrimeField;
+    use multiexp::multiexp;
+
+    let mut rng = XorShiftRng::seed_from_u64(0x5dbe62598d313d76);
+
+    for &n in &[0, 1, 2, 3, 31, 32, 100] {
+        let mut bases = (0..n)
+            .map(|_| G::rand(&mut rng).into_affine())
+            .collect::<Vec<_>>();
+        let mut scalars = (0..n)
+            .map(|_| G::Scalar::rand(&mut rng).into_repr())
+            .collect::<Vec<_>>();
+
+        // Exercise the identity as a base and zero as a scalar.
+        if n > 2 {
+            bases[0] = G::Affine::zero();
+            scalars[1] = G::Scalar::zero().into_repr();
+        }
+
+        let mut expected = G::zero();
+        for (base, scalar) in bases.iter().zip(scalars.iter()) {
+            expected.add_assign(&base.mul(*scalar));
+        }
+
+        assert_eq!(multiexp::<G>(&bases, &scalars), expected);
+    }
+}
+
+#[cfg(feature = "parallel")]
+fn random_parallel_tests<G: CurveProjective>() {
+    use ff::PrimeField;
+    use multiexp::multiexp;
+    use rayon::ThreadPoolBuilder;
+
+    let mut rng = XorShiftRng::seed_from_u64(0x5dbe62598d313d76);
+
+    // Run the same work on one thread and on several, so that the chunked
+    // code paths are taken even on a single-core machine.
+    let serial = ThreadPoolBuilder::new().num_threads(1).build().unwrap();
+    let parallel = ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+
+    // Enough terms to split both the batch normalization and the
+    // multi-scalar multiplication into several chunks.
+    let n = 3000;
+    let g = G::rand(&mut rng);
+    let mut points = Vec::with_capacity(n);
+    let mut acc = G::rand(&mut rng);
+    for i in 0..n {
+        acc.add_assign(&g);
+        points.push(if i % 100 == 0 { G::zero() } else { acc });
+    }
+
+    let mut expected = points.clone();
+    serial.install(|| G::batch_normalization(&mut expected));
+    parallel.install(|| G::batch_normalization(&mut points));
+    assert_eq!(points, expected);
+    for (p, e) in points.iter().zip(expected.iter()) {
+        assert!(p.is_normalized());
+        assert_eq!(p.into_affine(), e.into_affine());
+    }
+
+    let bases = points.iter().map(|p| p.into_affine()).collect::<Vec<_>>();
+    let scalars = (0..n)
+        .map(|_| G::Scalar::rand(&mut rng).into_repr())
+        .collect::<Vec<_>>();
+
+    let expected = serial.install(|| multiexp::<G>(&bases, &scalars));
+    let result = parallel.install(|| multiexp::<G>(&bases, &scalars));
+    assert_eq!(result.into_affine(), expected.into_affine());
+}
+
 fn random_wnaf_tests<G: CurveProjective>() {
     use ff::PrimeField;
     use wnaf::*;
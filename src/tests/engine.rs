@@ -1,6 +1,6 @@
 use rand::{SeedableRng};
 use rand_xorshift::XorShiftRng;
-use {CurveAffine, CurveProjective, Engine, Field, PrimeField, Rand};
+use {CurveAffine, CurveProjective, Engine, Field, GroupDecodingError, PrimeField, Rand, TargetGroup};
 
 pub fn engine_tests<E: Engine>() {
     let mut rng = XorShiftRng::seed_from_u64(0x5dbe62598d313d76);
@@ -45,6 +45,8 @@ pub fn engine_tests<E: Engine>() {
 
     random_bilinearity_tests::<E>();
     random_miller_loop_tests::<E>();
+    random_gt_tests::<E>();
+    random_pairing_product_tests::<E>();
 }
 
 fn random_miller_loop_tests<E: Engine>() {
@@ -62,7 +64,7 @@ fn random_miller_loop_tests<E: Engine>() {
 
         let p1 = E::final_exponentiation(&E::miller_loop(&[(&a, &b)])).unwrap();
 
-        assert_eq!(p1, p2);
+        assert_eq!(p1, p2.into_fqk());
     }
 
     // Exercise a double miller loop
@@ -76,7 +78,7 @@ fn random_miller_loop_tests<E: Engine>() {
         let cd = E::pairing(c, d);
 
         let mut abcd = ab;
-        abcd.mul_assign(&cd);
+        abcd.add_assign(&cd);
 
         let a = a.into_affine().prepare();
         let b = b.into_affine().prepare();
@@ -86,7 +88,29 @@ fn random_miller_loop_tests<E: Engine>() {
         let abcd_with_double_loop =
             E::final_exponentiation(&E::miller_loop(&[(&a, &b), (&c, &d)])).unwrap();
 
-        assert_eq!(abcd, abcd_with_double_loop);
+        assert_eq!(abcd.into_fqk(), abcd_with_double_loop);
+    }
+
+    // Exercise a multi miller loop, which matches the product of the miller
+    // loops of its pairs before the final exponentiation.
+    let mut pairs = vec![(E::G1Affine::zero().prepare(), E::G2Affine::one().prepare())];
+    for _ in 0..40 {
+        let a = E::G1::rand(&mut rng).into_affine().prepare();
+        let b = E::G2::rand(&mut rng).into_affine().prepare();
+        pairs.push((a, b));
+    }
+    let pairs: Vec<_> = pairs.iter().map(|(a, b)| (a, b)).collect();
+
+    let mut product = E::Fqk::one();
+    for pair in &pairs {
+        product.mul_assign(&E::miller_loop(&[*pair]));
+    }
+    assert_eq!(E::miller_loop(&pairs), product);
+
+    #[cfg(feature = "parallel")]
+    {
+        let pool = ::rayon::ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+        assert_eq!(pool.install(|| E::miller_loop(&pairs)), product);
     }
 }
 
@@ -118,9 +142,139 @@ fn random_bilinearity_tests<E: Engine>() {
         let mut cd = c;
         cd.mul_assign(&d);
 
-        let abcd = E::pairing(a, b).pow(cd.into_repr());
+        let mut abcd = E::pairing(a, b);
+        abcd.mul_assign(cd);
 
         assert_eq!(acbd, adbc);
         assert_eq!(acbd, abcd);
     }
 }
+
+fn random_gt_tests<E: Engine>() {
+    let mut rng = XorShiftRng::seed_from_u64(0x5dbe62598d313d76);
+
+    let g = E::Gt::generator();
+    assert_eq!(g, E::pairing(E::G1Affine::one(), E::G2Affine::one()));
+    assert!(!g.is_zero());
+    assert!(E::Gt::zero().is_zero());
+    assert!(E::Gt::zero().is_torsion_free());
+
+    let mut r = g;
+    r.mul_assign(E::Fr::char());
+    assert!(r.is_zero());
+
+    for _ in 0..10 {
+        let a = E::G1::rand(&mut rng);
+        let b = E::G2::rand(&mut rng);
+        let c = E::Fr::rand(&mut rng);
+        let d = E::Fr::rand(&mut rng);
+
+        let p = E::pairing(a, b);
+        assert!(p.is_torsion_free());
+        assert_eq!(E::Gt::from_fqk(p.into_fqk()), Some(p));
+
+        // e(-a, b) = -e(a, b) and e(a, b) + e(-a, b) = 0.
+        let mut minus_a = a;
+        minus_a.negate();
+        let mut minus_p = p;
+        minus_p.negate();
+        assert_eq!(E::pairing(minus_a, b), minus_p);
+        let mut zero = p;
+        zero.add_assign(&minus_p);
+        assert!(zero.is_zero());
+
+        // e([c]a, b) - e([d]a, b) = [c - d] e(a, b).
+        let mut ca = a;
+        ca.mul_assign(c);
+        let mut da = a;
+        da.mul_assign(d);
+        let mut lhs = E::pairing(ca, b);
+        lhs.sub_assign(&E::pairing(da, b));
+        let mut c_minus_d = c;
+        c_minus_d.sub_assign(&d);
+        let mut rhs = p;
+        rhs.mul_assign(c_minus_d);
+        assert_eq!(lhs, rhs);
+
+        let mut doubled = p;
+        doubled.double();
+        let mut added = p;
+        added.add_assign(&p);
+        assert_eq!(doubled, added);
+
+        // Random elements of Fqk are almost never in the target group.
+        let f = E::Fqk::rand(&mut rng);
+        assert!(E::Gt::from_fqk(f).is_none());
+        assert!(!E::Gt::from_fqk_unchecked(f).is_torsion_free());
+
+        // Encodings.
+        let bytes = p.into_bytes();
+        assert_eq!(E::Gt::from_bytes(&bytes).unwrap(), p);
+        assert!(matches!(
+            E::Gt::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(GroupDecodingError::UnexpectedInformation)
+        ));
+        let mut longer = bytes.clone();
+        longer.push(0);
+        assert!(matches!(
+            E::Gt::from_bytes(&longer),
+            Err(GroupDecodingError::UnexpectedInformation)
+        ));
+        let mut invalid = bytes.clone();
+        invalid[0] = 0xff;
+        assert!(matches!(
+            E::Gt::from_bytes(&invalid),
+            Err(GroupDecodingError::CoordinateDecodingError(..))
+        ));
+        let bytes = E::Gt::from_fqk_unchecked(f).into_bytes();
+        assert!(matches!(
+            E::Gt::from_bytes(&bytes),
+            Err(GroupDecodingError::NotInSubgroup)
+        ));
+        assert_eq!(E::Gt::from_bytes_unchecked(&bytes).unwrap().into_fqk(), f);
+    }
+
+    // The random elements are in the target group.
+    for _ in 0..10 {
+        assert!(E::Gt::rand(&mut rng).is_torsion_free());
+    }
+}
+
+fn random_pairing_product_tests<E: Engine>() {
+    let mut rng = XorShiftRng::seed_from_u64(0x5dbe62598d313d76);
+
+    assert!(E::pairing_product_is_one(&[]));
+    assert!(E::pairing_product_is_one(&[(E::G1Affine::zero(), E::G2Affine::one())]));
+    assert!(!E::pairing_product_is_one(&[(E::G1Affine::one(), E::G2Affine::one())]));
+
+    for _ in 0..10 {
+        let a = E::G1::rand(&mut rng);
+        let b = E::G2::rand(&mut rng);
+        let c = E::Fr::rand(&mut rng);
+
+        // e([c]a, b) e(-a, [c]b) = 1
+        let mut ca = a;
+        ca.mul_assign(c);
+        let mut minus_a = a;
+        minus_a.negate();
+        let mut cb = b;
+        cb.mul_assign(c);
+        let pairs = [
+            (ca.into_affine(), b.into_affine()),
+            (minus_a.into_affine(), cb.into_affine()),
+        ];
+        assert!(E::pairing_product_is_one(&pairs));
+
+        let prepared: Vec<_> = pairs.iter().map(|&(p, q)| (p.prepare(), q.prepare())).collect();
+        let refs: Vec<_> = prepared.iter().map(|(p, q)| (p, q)).collect();
+        assert!(E::prepared_pairing_product_is_one(&refs));
+        assert!(!E::prepared_pairing_product_is_one(&refs[..1]));
+
+        // e([c]a, b) e(a, [c]b) = e(a, b)^(2c) is not one.
+        let pairs = [
+            (ca.into_affine(), b.into_affine()),
+            (a.into_affine(), cb.into_affine()),
+        ];
+        assert!(!E::pairing_product_is_one(&pairs));
+    }
+}